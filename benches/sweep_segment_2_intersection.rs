@@ -0,0 +1,97 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rcgal::{
+    algorithm::intersection::sweep_segment_2_intersection::SweepSegment2Intersection,
+    kernel::{
+        arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2, line_segment_2::LineSegment2,
+        point_2::Point2,
+    },
+};
+
+const SIZES: [usize; 5] = [1000, 2000, 4000, 8000, 16000];
+
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+// Segments of length about 1/sqrt(n) in the unit square cross O(n) times, so the time per
+// segment should only grow like log n.
+fn line_segments(n: usize) -> Vec<LineSegment2<f64>> {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let length = 2.0 / (n as f64).sqrt();
+    (0..n)
+        .map(|_| {
+            let source = Point2::new(rng.next(), rng.next());
+            let radian = rng.next() * std::f64::consts::PI * 2.0;
+            let target = Point2::new(
+                source.x() + length * radian.cos(),
+                source.y() + length * radian.sin(),
+            );
+            LineSegment2::new(source, target)
+        })
+        .collect()
+}
+
+fn arc_segments(n: usize) -> Vec<ArcSegment2<f64>> {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let radius = 1.0 / (n as f64).sqrt();
+    (0..n)
+        .map(|_| {
+            let center = Point2::new(rng.next(), rng.next());
+            let source_radian = rng.next() * std::f64::consts::PI * 2.0;
+            let target_radian = source_radian + rng.next() * std::f64::consts::PI * 2.0;
+            ArcSegment2::new(
+                CircleSegment2::new(center, radius),
+                source_radian,
+                target_radian,
+            )
+        })
+        .collect()
+}
+
+fn bench_line_segments(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sweep_line_segments");
+    group.sample_size(10);
+    for n in SIZES {
+        let segments = line_segments(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &segments, |b, segments| {
+            b.iter(|| {
+                let mut sweep = SweepSegment2Intersection::new();
+                for segment in segments {
+                    sweep.push_segment(segment);
+                }
+                black_box(sweep.intersection())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_arc_segments(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sweep_arc_segments");
+    group.sample_size(10);
+    for n in SIZES {
+        let segments = arc_segments(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &segments, |b, segments| {
+            b.iter(|| {
+                let mut sweep = SweepSegment2Intersection::new();
+                for segment in segments {
+                    sweep.push_segment(segment);
+                }
+                black_box(sweep.intersection())
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_line_segments, bench_arc_segments);
+criterion_main!(benches);
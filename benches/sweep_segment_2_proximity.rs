@@ -0,0 +1,31 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rcgal::{
+    algorithm::proximity::sweep_segment_2_proximity::SweepSegment2Proximity,
+    kernel::{line_segment_2::LineSegment2, point_2::Point2},
+};
+
+const SIZES: [usize; 5] = [1000, 2000, 4000, 8000, 16000];
+
+// Stacked horizontal segments all share one x-range but each is only near its neighbours, so
+// the time per segment should only grow like log² n.
+fn bench_stacked_segments(c: &mut Criterion) {
+    let mut group = c.benchmark_group("proximity_stacked_segments");
+    group.sample_size(10);
+    for n in SIZES {
+        let mut sweep = SweepSegment2Proximity::new();
+        for i in 0..n {
+            let y = i as f64;
+            sweep.push_segment(
+                &LineSegment2::new(Point2::new(0.0, y), Point2::new(100.0, y)).into(),
+            );
+        }
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &sweep, |b, sweep| {
+            b.iter(|| black_box(sweep.within(1.0)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_stacked_segments);
+criterion_main!(benches);
@@ -1,5 +1,11 @@
 pub mod convex_hull;
+pub mod fitting;
 pub mod intersection;
 pub mod location;
+pub mod noding;
+pub mod polygonization;
 pub mod projection;
+pub mod proximity;
+pub mod radial_sort;
+pub mod tangent;
 pub mod triangulation;
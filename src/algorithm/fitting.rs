@@ -0,0 +1,5 @@
+pub mod arc_2;
+pub mod biarc_2;
+pub mod circle_2;
+pub mod fit_2;
+pub mod line_2;
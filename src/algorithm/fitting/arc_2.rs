@@ -0,0 +1,124 @@
+use crate::kernel::{
+    arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2, number_type::NumberType,
+    point_2::Point2, segment_2::Segment2, util_enum::GeometryError,
+};
+
+use super::{
+    circle_2::{circle_residual, geometric},
+    fit_2::{ransac, Fit2, FitResiduals2},
+};
+
+/// Geometric circle fit bounded to the smallest arc covering the points.
+///
+/// The arc runs clockwise when the points, in order, turn clockwise around the center.
+pub fn fit_arc_segment_2<T: NumberType>(
+    points: &[Point2<T>],
+) -> Result<Fit2<T, ArcSegment2<T>>, GeometryError> {
+    let circle_segment = geometric(points)?;
+    arc_fit(circle_segment, points, (0..points.len()).collect())
+}
+
+/// Arc fit of the largest set of points within `threshold` of a circle through three sampled points.
+pub fn fit_arc_segment_2_ransac<T: NumberType>(
+    points: &[Point2<T>],
+    threshold: T,
+    iterations: usize,
+    seed: u64,
+) -> Result<Fit2<T, ArcSegment2<T>>, GeometryError> {
+    let inliers = ransac(
+        points,
+        3,
+        threshold,
+        iterations,
+        seed,
+        |sample| geometric(sample).ok(),
+        circle_residual,
+    )?;
+    let inlier_points: Vec<Point2<T>> = inliers.iter().map(|&i| points[i]).collect();
+    let circle_segment = geometric(&inlier_points)?;
+    arc_fit(circle_segment, points, inliers)
+}
+
+fn arc_fit<T: NumberType>(
+    circle_segment: CircleSegment2<T>,
+    points: &[Point2<T>],
+    inliers: Vec<usize>,
+) -> Result<Fit2<T, ArcSegment2<T>>, GeometryError> {
+    let center = circle_segment.center();
+    let radians: Vec<T> = inliers
+        .iter()
+        .map(|&i| {
+            let vector = points[i] - center;
+            vector.y().atan2(vector.x())
+        })
+        .collect();
+    let mut sorted = radians.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let two_pi = T::pi() * T::from_f64(2.0);
+    let mut gap = sorted[0] + two_pi - sorted[sorted.len() - 1];
+    let mut start = sorted[0];
+    for pair in sorted.windows(2) {
+        if pair[1] - pair[0] > gap {
+            gap = pair[1] - pair[0];
+            start = pair[1];
+        }
+    }
+    let sweep = two_pi - gap;
+    let turn = radians.windows(2).fold(T::zero(), |sum, pair| {
+        let mut delta = pair[1] - pair[0];
+        if delta > T::pi() {
+            delta = delta - two_pi;
+        } else if delta < -T::pi() {
+            delta = delta + two_pi;
+        }
+        sum + delta
+    });
+    let arc_segment = if turn < T::zero() {
+        ArcSegment2::from_sweep(circle_segment, start + sweep, -sweep)?
+    } else {
+        ArcSegment2::from_sweep(circle_segment, start, sweep)?
+    };
+    let residuals: Vec<T> = inliers
+        .iter()
+        .map(|&i| circle_residual(&circle_segment, &points[i]))
+        .collect();
+    Ok(Fit2::new(
+        arc_segment,
+        FitResiduals2::new(&residuals),
+        inliers,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::util_enum::Orientation;
+
+    #[test]
+    fn test_fit_arc_segment_2() {
+        let points: Vec<Point2<f64>> = (0..=20)
+            .map(|i| {
+                let radian = 3.0 - 1.5 * i as f64 / 20.0;
+                let radius = 4.0 + 0.005 * ((i % 3) as f64 - 1.0);
+                Point2::new(1.0 + radius * radian.cos(), 2.0 + radius * radian.sin())
+            })
+            .collect();
+        let fit = fit_arc_segment_2(&points).unwrap();
+        let arc_segment = fit.shape();
+        assert_eq!(arc_segment.orientation(), Orientation::Clockwise);
+        assert!(arc_segment.center().distance(&Point2::new(1.0, 2.0)) < 0.02);
+        assert!((arc_segment.radius() - 4.0).abs() < 0.01);
+        assert!((arc_segment.sweep_radian() - 1.5).abs() < 1e-2);
+        assert!((arc_segment.source_radian() - 3.0).abs() < 1e-2);
+        assert!(fit.residuals().max() < 0.01);
+
+        let mut reversed = points.clone();
+        reversed.reverse();
+        reversed.push(Point2::new(20.0, 20.0));
+        let fit = fit_arc_segment_2_ransac(&reversed, 0.02, 100, 5).unwrap();
+        assert_eq!(fit.inliers(), (0..=20).collect::<Vec<_>>());
+        assert_eq!(fit.shape().orientation(), Orientation::CounterClockwise);
+        assert!((fit.shape().source_radian() - 1.5).abs() < 1e-2);
+        assert!((fit.shape().sweep_radian() - 1.5).abs() < 1e-2);
+    }
+}
@@ -0,0 +1,495 @@
+use crate::kernel::{
+    arc_segment_2::ArcSegment2,
+    curve_2::Curve2,
+    line_arc_segment_2::LineArcSegment2,
+    line_segment_2::LineSegment2,
+    number_type::NumberType,
+    nurbs_curve_2::NurbsCurve2,
+    point_2::Point2,
+    polyline_2::Polyline2,
+    segment_2::Segment2,
+    tessellation_2::ChordTolerance,
+    util_enum::{ApproximationSide, GeometryError},
+    vector_2::Vector2,
+};
+
+const SAMPLES_PER_BEZIER_SEGMENT: usize = 64;
+const SAMPLES_BETWEEN_POINTS: usize = 8;
+
+/// Line and arc path approximating the input, with the largest distance of a sample of the input
+/// from it.
+#[derive(Debug, Clone)]
+pub struct BiarcFit2<T: NumberType> {
+    polyline: Polyline2<T>,
+    max_deviation: T,
+}
+
+impl<T: NumberType> BiarcFit2<T> {
+    pub fn polyline(&self) -> Polyline2<T> {
+        self.polyline.clone()
+    }
+
+    pub fn max_deviation(&self) -> T {
+        self.max_deviation
+    }
+}
+
+/// Fits biarcs through the points, taking as many points per biarc as `tolerance` allows.
+///
+/// Tangents at the points come from the circle through each point and its neighbours.
+/// Runs that stay within `tolerance` of their chord become single line segments.
+/// Only the points themselves are measured, as nothing is known between them.
+pub fn fit_biarc_2_points<T: NumberType>(
+    points: &[Point2<T>],
+    tolerance: T,
+) -> Result<BiarcFit2<T>, GeometryError> {
+    let between = vec![Vec::new(); points.len().saturating_sub(1)];
+    fit_samples(points, &between, tolerance)
+}
+
+/// `between[i]` holds samples of the input strictly between `points[i]` and `points[i + 1]`,
+/// which are measured along with the points.
+fn fit_samples<T: NumberType>(
+    points: &[Point2<T>],
+    between: &[Vec<Point2<T>>],
+    tolerance: T,
+) -> Result<BiarcFit2<T>, GeometryError> {
+    if tolerance < T::zero() || tolerance.equals(T::zero()) {
+        return Err(GeometryError::InvalidTolerance);
+    }
+    if points.len() < 2 {
+        return Err(GeometryError::TooFewPoints);
+    }
+    let mut unique = vec![points[0]];
+    let mut unique_between: Vec<Vec<Point2<T>>> = Vec::new();
+    for (point, samples) in points[1..].iter().zip(between) {
+        if *point == unique[unique.len() - 1] {
+            if let Some(last) = unique_between.last_mut() {
+                last.extend(samples);
+            }
+        } else {
+            unique.push(*point);
+            unique_between.push(samples.clone());
+        }
+    }
+    let (points, between) = (unique, unique_between);
+    if points.len() < 2 {
+        return Err(GeometryError::CoincidentPoints);
+    }
+    let tangents = estimate_tangents(&points);
+    let mut segments = Vec::new();
+    let mut max_deviation = T::zero();
+    let mut start = 0;
+    while start + 1 < points.len() {
+        let mut end = start + 1;
+        let mut best = fit_range(&points, &between, &tangents, start, end);
+        let mut low = end;
+        let mut high = points.len() - 1;
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            let candidate = fit_range(&points, &between, &tangents, start, mid);
+            if candidate.1 < tolerance || candidate.1.equals(tolerance) {
+                end = mid;
+                best = candidate;
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        if best.1 > max_deviation {
+            max_deviation = best.1;
+        }
+        segments.extend(best.0);
+        start = end;
+    }
+    Ok(BiarcFit2 {
+        polyline: Polyline2::from_segments(segments)?,
+        max_deviation,
+    })
+}
+
+/// Arc pieces are split within a quarter of `tolerance` first, and every segment is also
+/// measured between its split points.
+pub fn fit_biarc_2_polyline<T: NumberType>(
+    polyline: &Polyline2<T>,
+    tolerance: T,
+) -> Result<BiarcFit2<T>, GeometryError> {
+    let mut points = Vec::new();
+    let mut between = Vec::new();
+    for segment in polyline.segments() {
+        let pieces = match segment {
+            LineArcSegment2::LineSegment2(_) => 1,
+            LineArcSegment2::ArcSegment2(arc_segment) => {
+                arc_segment
+                    .tessellate(
+                        ChordTolerance::MaxDeviation(tolerance / T::from_f64(4.0)),
+                        ApproximationSide::Inner,
+                    )?
+                    .len()
+                    - 1
+            }
+        };
+        for piece in 0..pieces {
+            let at = |fraction: f64| {
+                segment.point_at(T::from_f64((piece as f64 + fraction) / pieces as f64))
+            };
+            points.push(at(0.0));
+            between.push(samples_between(at));
+        }
+    }
+    if let Some(segment) = polyline.segments().last() {
+        points.push(segment.target());
+    }
+    fit_samples(&points, &between, tolerance)
+}
+
+/// Samples every Bézier segment of the curve evenly, which covers Bézier curves and B-splines.
+pub fn fit_biarc_2_nurbs_curve<T: NumberType>(
+    curve: &NurbsCurve2<T>,
+    tolerance: T,
+) -> Result<BiarcFit2<T>, GeometryError> {
+    let mut points = vec![curve.source()];
+    let mut between = Vec::new();
+    for segment in curve.bezier_segments() {
+        let (start, end) = segment.domain();
+        for i in 0..SAMPLES_PER_BEZIER_SEGMENT {
+            let at = |fraction: f64| {
+                segment.point_at(
+                    start
+                        + (end - start)
+                            * T::from_f64(
+                                (i as f64 + fraction) / SAMPLES_PER_BEZIER_SEGMENT as f64,
+                            ),
+                )
+            };
+            points.push(at(1.0));
+            between.push(samples_between(at));
+        }
+    }
+    fit_samples(&points, &between, tolerance)
+}
+
+/// Evenly spaced samples strictly between the fractions 0 and 1.
+fn samples_between<T: NumberType>(at: impl Fn(f64) -> Point2<T>) -> Vec<Point2<T>> {
+    (1..SAMPLES_BETWEEN_POINTS)
+        .map(|i| at(i as f64 / SAMPLES_BETWEEN_POINTS as f64))
+        .collect()
+}
+
+fn estimate_tangents<T: NumberType>(points: &[Point2<T>]) -> Vec<Vector2<T>> {
+    let count = points.len();
+    if count == 2 {
+        let direction = (points[1] - points[0]).normalize();
+        return vec![direction, direction];
+    }
+    (0..count)
+        .map(|i| {
+            let middle = i.clamp(1, count - 2);
+            let (previous, current, next) =
+                (points[middle - 1], points[middle], points[middle + 1]);
+            match ArcSegment2::from_three_points(&previous, &current, &next) {
+                Ok(arc_segment) => {
+                    arc_segment.tangent_at(arc_segment.closest_parameter(&points[i]))
+                }
+                Err(_) => (next - previous).normalize(),
+            }
+        })
+        .collect()
+}
+
+/// Biarc or line between the points at `start` and `end` with the largest distance of the points
+/// and samples between.
+fn fit_range<T: NumberType>(
+    points: &[Point2<T>],
+    between: &[Vec<Point2<T>>],
+    tangents: &[Vector2<T>],
+    start: usize,
+    end: usize,
+) -> (Vec<LineArcSegment2<T>>, T) {
+    let measured: Vec<Point2<T>> = points[start + 1..end]
+        .iter()
+        .chain(between[start..end].iter().flatten())
+        .copied()
+        .collect();
+    let chord = vec![LineSegment2::new(points[start], points[end]).into()];
+    let chord_deviation = deviation(&chord, &measured);
+    let biarc = biarc(
+        &points[start],
+        &tangents[start],
+        &points[end],
+        &tangents[end],
+    );
+    match biarc {
+        Some(biarc) => {
+            let biarc_deviation = deviation(&biarc, &measured);
+            if chord_deviation < biarc_deviation || chord_deviation.equals(biarc_deviation) {
+                (chord, chord_deviation)
+            } else {
+                (biarc, biarc_deviation)
+            }
+        }
+        None => (chord, chord_deviation),
+    }
+}
+
+/// Two arcs of equal tangent lengths joined tangentially, degenerate arcs become lines.
+fn biarc<T: NumberType>(
+    source: &Point2<T>,
+    source_tangent: &Vector2<T>,
+    target: &Point2<T>,
+    target_tangent: &Vector2<T>,
+) -> Option<Vec<LineArcSegment2<T>>> {
+    let chord = *target - *source;
+    let tangent_sum = *source_tangent + *target_tangent;
+    let a = tangent_sum.dot(&tangent_sum) - T::from_f64(4.0);
+    let b = chord.dot(&tangent_sum);
+    let c = chord.dot(&chord);
+    let distance = if a.equals(T::zero()) {
+        let projection = chord.dot(target_tangent);
+        if projection.equals(T::zero()) {
+            return None;
+        }
+        c / (T::from_f64(4.0) * projection)
+    } else {
+        (b - (b * b - a * c).sqrt()) / a
+    };
+    if distance < T::zero() || distance.equals(T::zero()) {
+        return None;
+    }
+    let control_a = Point2::new(
+        source.x() + source_tangent.x() * distance,
+        source.y() + source_tangent.y() * distance,
+    );
+    let control_b = Point2::new(
+        target.x() - target_tangent.x() * distance,
+        target.y() - target_tangent.y() * distance,
+    );
+    let joint = Point2::new(
+        (control_a.x() + control_b.x()) / T::from_f64(2.0),
+        (control_a.y() + control_b.y()) / T::from_f64(2.0),
+    );
+    let joint_tangent = control_b - control_a;
+    Some(vec![
+        tangent_piece(source, source_tangent, &joint),
+        tangent_piece(&joint, &joint_tangent, target),
+    ])
+}
+
+fn tangent_piece<T: NumberType>(
+    source: &Point2<T>,
+    tangent: &Vector2<T>,
+    target: &Point2<T>,
+) -> LineArcSegment2<T> {
+    match ArcSegment2::from_source_tangent_target(source, tangent, target) {
+        Ok(arc_segment) => arc_segment.into(),
+        Err(_) => LineSegment2::new(*source, *target).into(),
+    }
+}
+
+fn deviation<T: NumberType>(segments: &[LineArcSegment2<T>], points: &[Point2<T>]) -> T {
+    points.iter().fold(T::zero(), |max, point| {
+        let distance = segments
+            .iter()
+            .map(|segment| point.distance(&segment.point_at(segment.closest_parameter(point))))
+            .fold(None, |min: Option<T>, distance| match min {
+                Some(min) if min < distance => Some(min),
+                _ => Some(distance),
+            })
+            .unwrap_or(T::zero());
+        if distance > max {
+            distance
+        } else {
+            max
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_biarc_2() {
+        let points: Vec<Point2<f64>> = (0..=40)
+            .map(|i| {
+                let radian = std::f64::consts::PI * i as f64 / 40.0;
+                Point2::new(5.0 * radian.cos(), 5.0 * radian.sin())
+            })
+            .collect();
+        let fit = fit_biarc_2_points(&points, 1e-3).unwrap();
+        assert!(fit.max_deviation() <= 1e-3);
+        assert!(fit.polyline().segments().len() <= 4);
+        assert_eq!(fit.polyline().vertices()[0], points[0]);
+        assert_eq!(*fit.polyline().vertices().last().unwrap(), points[40]);
+
+        let points: Vec<Point2<f64>> = (0..=200)
+            .map(|i| {
+                let x = i as f64 / 20.0;
+                Point2::new(x, x.sin())
+            })
+            .collect();
+        let fit = fit_biarc_2_polyline(&Polyline2::new(points.clone()), 1e-2).unwrap();
+        assert!(fit.max_deviation() <= 1e-2);
+        assert!(fit.polyline().segments().len() < 40);
+        for point in &points {
+            let distance = fit
+                .polyline()
+                .segments()
+                .iter()
+                .map(|segment| point.distance(&segment.point_at(segment.closest_parameter(point))))
+                .fold(f64::MAX, f64::min);
+            assert!(distance <= 1e-2 + 1e-9);
+        }
+
+        let line = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 0.0),
+        ];
+        let fit = fit_biarc_2_points(&line, 1e-3).unwrap();
+        assert_eq!(fit.polyline().segments().len(), 1);
+        assert_eq!(fit.max_deviation(), 0.0);
+
+        assert_eq!(
+            fit_biarc_2_points(&line, 0.0).unwrap_err(),
+            GeometryError::InvalidTolerance
+        );
+        assert_eq!(
+            fit_biarc_2_points(&[Point2::new(1.0, 1.0), Point2::new(1.0, 1.0)], 1e-3).unwrap_err(),
+            GeometryError::CoincidentPoints
+        );
+
+        let curve = NurbsCurve2::bspline(
+            3,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 2.0),
+                Point2::new(3.0, 2.0),
+                Point2::new(4.0, 0.0),
+                Point2::new(6.0, -1.0),
+            ],
+            vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let fit = fit_biarc_2_nurbs_curve(&curve, 1e-3).unwrap();
+        assert!(fit.max_deviation() <= 1e-3);
+        assert_eq!(fit.polyline().vertices()[0], Point2::new(0.0, 0.0));
+        assert_eq!(
+            *fit.polyline().vertices().last().unwrap(),
+            Point2::new(6.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn test_fit_biarc_2_degenerate() {
+        assert_eq!(
+            fit_biarc_2_points::<f64>(&[], 1e-3).unwrap_err(),
+            GeometryError::TooFewPoints
+        );
+        assert_eq!(
+            fit_biarc_2_points(&[Point2::new(1.0, 1.0)], 1e-3).unwrap_err(),
+            GeometryError::TooFewPoints
+        );
+        assert_eq!(
+            fit_biarc_2_polyline(&Polyline2::new(vec![Point2::new(0.0, 0.0)]), 1e-3).unwrap_err(),
+            GeometryError::TooFewPoints
+        );
+        let points = [Point2::new(0.0, 0.0), Point2::new(3.0, 4.0)];
+        assert_eq!(
+            fit_biarc_2_points(&points, -1.0).unwrap_err(),
+            GeometryError::InvalidTolerance
+        );
+        let fit = fit_biarc_2_points(&points, 1e-3).unwrap();
+        assert_eq!(
+            fit.polyline().segments(),
+            vec![LineSegment2::new(points[0], points[1]).into()]
+        );
+
+        // Corners are kept as vertices instead of being rounded.
+        let corner = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(2.0, 2.0),
+        ];
+        let fit = fit_biarc_2_points(&corner, 1e-3).unwrap();
+        assert_eq!(fit.max_deviation(), 0.0);
+        assert_eq!(
+            fit.polyline().vertices(),
+            vec![corner[0], corner[2], corner[4]]
+        );
+
+        // A path doubling back on itself turns at its far end.
+        let hairpin = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 0.0),
+        ];
+        let fit = fit_biarc_2_points(&hairpin, 1e-3).unwrap();
+        assert_eq!(
+            fit.polyline().vertices(),
+            vec![hairpin[0], hairpin[2], hairpin[4]]
+        );
+
+        let circle: Vec<Point2<f64>> = (0..=40)
+            .map(|i| {
+                let radian = 2.0 * std::f64::consts::PI * i as f64 / 40.0;
+                Point2::new(radian.cos(), radian.sin())
+            })
+            .collect();
+        let fit = fit_biarc_2_points(&circle, 1e-3).unwrap();
+        assert!(fit.max_deviation() <= 1e-3);
+        assert_eq!(fit.polyline().vertices()[0], circle[0]);
+        assert_eq!(*fit.polyline().vertices().last().unwrap(), circle[0]);
+
+        let line = NurbsCurve2::bspline(
+            1,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 1.0),
+                Point2::new(2.0, 2.0),
+            ],
+            vec![0.0, 0.0, 0.5, 1.0, 1.0],
+        )
+        .unwrap();
+        let fit = fit_biarc_2_nurbs_curve(&line, 1e-3).unwrap();
+        assert_eq!(fit.polyline().segments().len(), 1);
+    }
+
+    #[test]
+    fn test_fit_biarc_2_polyline_edges() {
+        let polyline = Polyline2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 1.0),
+            Point2::new(20.0, 0.0),
+        ]);
+        let distance = |fit: &BiarcFit2<f64>, point: &Point2<f64>| {
+            fit.polyline()
+                .segments()
+                .iter()
+                .map(|segment| point.distance(&segment.point_at(segment.closest_parameter(point))))
+                .fold(f64::MAX, f64::min)
+        };
+        for tolerance in [0.1, 0.3, 1.0] {
+            let fit = fit_biarc_2_polyline(&polyline, tolerance).unwrap();
+            assert!(fit.max_deviation() <= tolerance);
+            for edge in polyline.segments() {
+                for i in 0..=10 {
+                    let point = edge.point_at(i as f64 / 10.0);
+                    assert!(distance(&fit, &point) <= tolerance + 1e-9);
+                }
+            }
+        }
+        let fit = fit_biarc_2_polyline(&polyline, 0.1).unwrap();
+        assert_eq!(fit.polyline().vertices(), polyline.vertices());
+        assert_eq!(fit.max_deviation(), 0.0);
+
+        // The same vertices as bare points leave the edges free to bend.
+        let fit = fit_biarc_2_points(&polyline.vertices(), 0.1).unwrap();
+        assert!(distance(&fit, &Point2::new(5.0, 0.5)) > 0.1);
+    }
+}
@@ -0,0 +1,318 @@
+use crate::kernel::{
+    circle_segment_2::CircleSegment2, number_type::NumberType, point_2::Point2,
+    segment_2::Segment2, util_enum::GeometryError,
+};
+
+use super::fit_2::{ransac, solve_3, Fit2, FitResiduals2};
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Algebraic fit minimizing the sum of (|p - c|^2 - r^2)^2, biased towards smaller circles on short arcs.
+pub fn fit_circle_2_kasa<T: NumberType>(
+    points: &[Point2<T>],
+) -> Result<Fit2<T, CircleSegment2<T>>, GeometryError> {
+    let circle_segment = kasa(points)?;
+    Ok(circle_fit(
+        circle_segment,
+        points,
+        (0..points.len()).collect(),
+    ))
+}
+
+/// Algebraic fit with Pratt's normalization, close to the geometric fit even on short arcs.
+pub fn fit_circle_2_pratt<T: NumberType>(
+    points: &[Point2<T>],
+) -> Result<Fit2<T, CircleSegment2<T>>, GeometryError> {
+    let circle_segment = pratt(points)?;
+    Ok(circle_fit(
+        circle_segment,
+        points,
+        (0..points.len()).collect(),
+    ))
+}
+
+/// Geometric fit minimizing the sum of squared distances by Levenberg-Marquardt, started from the Pratt fit.
+pub fn fit_circle_2_geometric<T: NumberType>(
+    points: &[Point2<T>],
+) -> Result<Fit2<T, CircleSegment2<T>>, GeometryError> {
+    let circle_segment = geometric(points)?;
+    Ok(circle_fit(
+        circle_segment,
+        points,
+        (0..points.len()).collect(),
+    ))
+}
+
+/// Geometric fit of the largest set of points within `threshold` of a circle through three sampled points.
+pub fn fit_circle_2_ransac<T: NumberType>(
+    points: &[Point2<T>],
+    threshold: T,
+    iterations: usize,
+    seed: u64,
+) -> Result<Fit2<T, CircleSegment2<T>>, GeometryError> {
+    let inliers = ransac(
+        points,
+        3,
+        threshold,
+        iterations,
+        seed,
+        |sample| kasa(sample).ok(),
+        circle_residual,
+    )?;
+    let inlier_points: Vec<Point2<T>> = inliers.iter().map(|&i| points[i]).collect();
+    let circle_segment = geometric(&inlier_points)?;
+    Ok(circle_fit(circle_segment, points, inliers))
+}
+
+pub(crate) fn geometric<T: NumberType>(
+    points: &[Point2<T>],
+) -> Result<CircleSegment2<T>, GeometryError> {
+    let initial = pratt(points)?;
+    let mut parameters = [initial.center().x(), initial.center().y(), initial.radius()];
+    let mut cost = geometric_cost(points, &parameters);
+    let mut lambda = T::from_f64(1e-3);
+    for _ in 0..MAX_ITERATIONS {
+        let mut normal = [[T::zero(); 3]; 3];
+        let mut gradient = [T::zero(); 3];
+        for point in points {
+            let dx = point.x() - parameters[0];
+            let dy = point.y() - parameters[1];
+            let distance = (dx * dx + dy * dy).sqrt();
+            if distance.equals(T::zero()) {
+                continue;
+            }
+            let jacobian = [-dx / distance, -dy / distance, T::from_f64(-1.0)];
+            let residual = distance - parameters[2];
+            for i in 0..3 {
+                for j in 0..3 {
+                    normal[i][j] = normal[i][j] + jacobian[i] * jacobian[j];
+                }
+                gradient[i] = gradient[i] - jacobian[i] * residual;
+            }
+        }
+        let mut damped = normal;
+        for (i, row) in damped.iter_mut().enumerate() {
+            row[i] = row[i] + lambda * normal[i][i];
+        }
+        let Some(step) = solve_3(damped, gradient) else {
+            break;
+        };
+        let candidate = [
+            parameters[0] + step[0],
+            parameters[1] + step[1],
+            parameters[2] + step[2],
+        ];
+        let candidate_cost = geometric_cost(points, &candidate);
+        if candidate_cost < cost {
+            let converged = (cost - candidate_cost).equals(T::zero());
+            parameters = candidate;
+            cost = candidate_cost;
+            lambda = lambda / T::from_f64(10.0);
+            if converged {
+                break;
+            }
+        } else {
+            lambda = lambda * T::from_f64(10.0);
+        }
+    }
+    Ok(CircleSegment2::new(
+        Point2::new(parameters[0], parameters[1]),
+        parameters[2].abs(),
+    ))
+}
+
+pub(crate) fn circle_residual<T: NumberType>(
+    circle_segment: &CircleSegment2<T>,
+    point: &Point2<T>,
+) -> T {
+    point.distance(&circle_segment.center()) - circle_segment.radius()
+}
+
+fn circle_fit<T: NumberType>(
+    circle_segment: CircleSegment2<T>,
+    points: &[Point2<T>],
+    inliers: Vec<usize>,
+) -> Fit2<T, CircleSegment2<T>> {
+    let residuals: Vec<T> = inliers
+        .iter()
+        .map(|&i| circle_residual(&circle_segment, &points[i]))
+        .collect();
+    Fit2::new(circle_segment, FitResiduals2::new(&residuals), inliers)
+}
+
+fn geometric_cost<T: NumberType>(points: &[Point2<T>], parameters: &[T; 3]) -> T {
+    points.iter().fold(T::zero(), |sum, point| {
+        let dx = point.x() - parameters[0];
+        let dy = point.y() - parameters[1];
+        let residual = (dx * dx + dy * dy).sqrt() - parameters[2];
+        sum + residual * residual
+    })
+}
+
+fn centroid<T: NumberType>(points: &[Point2<T>]) -> Result<Point2<T>, GeometryError> {
+    if points.len() < 3 {
+        return Err(GeometryError::TooFewPoints);
+    }
+    let n = T::from_f64(points.len() as f64);
+    let (x, y) = points.iter().fold((T::zero(), T::zero()), |(x, y), point| {
+        (x + point.x(), y + point.y())
+    });
+    Ok(Point2::new(x / n, y / n))
+}
+
+fn kasa<T: NumberType>(points: &[Point2<T>]) -> Result<CircleSegment2<T>, GeometryError> {
+    let centroid = centroid(points)?;
+    let mut matrix = [[T::zero(); 3]; 3];
+    let mut rhs = [T::zero(); 3];
+    for point in points {
+        let u = point.x() - centroid.x();
+        let v = point.y() - centroid.y();
+        let row = [u, v, T::from_f64(1.0)];
+        let z = u * u + v * v;
+        for i in 0..3 {
+            for j in 0..3 {
+                matrix[i][j] = matrix[i][j] + row[i] * row[j];
+            }
+            rhs[i] = rhs[i] - row[i] * z;
+        }
+    }
+    let [d, e, f] = solve_3(matrix, rhs).ok_or(GeometryError::CollinearPoints)?;
+    let half = T::from_f64(0.5);
+    let squared_radius = (d * d + e * e) * half * half - f;
+    if squared_radius < T::zero() || squared_radius.equals(T::zero()) {
+        return Err(GeometryError::CollinearPoints);
+    }
+    Ok(CircleSegment2::new(
+        Point2::new(centroid.x() - d * half, centroid.y() - e * half),
+        squared_radius.sqrt(),
+    ))
+}
+
+/// Newton iteration on the characteristic polynomial of Pratt's generalized eigenproblem.
+fn pratt<T: NumberType>(points: &[Point2<T>]) -> Result<CircleSegment2<T>, GeometryError> {
+    let centroid = centroid(points)?;
+    let n = T::from_f64(points.len() as f64);
+    let (mut mxx, mut myy, mut mxy) = (T::zero(), T::zero(), T::zero());
+    let (mut mxz, mut myz, mut mzz) = (T::zero(), T::zero(), T::zero());
+    for point in points {
+        let x = point.x() - centroid.x();
+        let y = point.y() - centroid.y();
+        let z = x * x + y * y;
+        mxx = mxx + x * x;
+        myy = myy + y * y;
+        mxy = mxy + x * y;
+        mxz = mxz + x * z;
+        myz = myz + y * z;
+        mzz = mzz + z * z;
+    }
+    let (mxx, myy, mxy, mxz, myz, mzz) = (mxx / n, myy / n, mxy / n, mxz / n, myz / n, mzz / n);
+    let mz = mxx + myy;
+    let cov_xy = mxx * myy - mxy * mxy;
+    let four = T::from_f64(4.0);
+    let a2 = four * cov_xy - T::from_f64(3.0) * mz * mz - mzz;
+    let a1 = mzz * mz + four * cov_xy * mz - mxz * mxz - myz * myz - mz * mz * mz;
+    let a0 = mxz * mxz * myy + myz * myz * mxx - mzz * cov_xy - T::from_f64(2.0) * mxz * myz * mxy
+        + mz * mz * cov_xy;
+    let mut x = T::zero();
+    let mut y = a0;
+    for _ in 0..MAX_ITERATIONS {
+        let derivative = a1 + x * (a2 + a2 + T::from_f64(16.0) * x * x);
+        if derivative.equals(T::zero()) {
+            break;
+        }
+        let next = x - y / derivative;
+        if next < T::zero() {
+            x = T::zero();
+            break;
+        }
+        let next_y = a0 + next * (a1 + next * (a2 + four * next * next));
+        if next_y.abs() > y.abs() {
+            break;
+        }
+        let converged = (next - x).abs() < T::default_eps() * (T::from_f64(1.0) + next.abs());
+        x = next;
+        y = next_y;
+        if converged {
+            break;
+        }
+    }
+    let det = x * x - x * mz + cov_xy;
+    if det.equals(T::zero()) {
+        return Err(GeometryError::CollinearPoints);
+    }
+    let half = T::from_f64(0.5);
+    let center_x = (mxz * (myy - x) - myz * mxy) / det * half;
+    let center_y = (myz * (mxx - x) - mxz * mxy) / det * half;
+    let radius = (center_x * center_x + center_y * center_y + mz + T::from_f64(2.0) * x).sqrt();
+    Ok(CircleSegment2::new(
+        Point2::new(center_x + centroid.x(), center_y + centroid.y()),
+        radius,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noisy_circle(count: usize, sweep: f64) -> Vec<Point2<f64>> {
+        (0..count)
+            .map(|i| {
+                let radian = sweep * i as f64 / count as f64;
+                let noise = 0.01 * ((i * 7 % 5) as f64 - 2.0);
+                Point2::new(
+                    3.0 + (2.0 + noise) * radian.cos(),
+                    -1.0 + (2.0 + noise) * radian.sin(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fit_circle_2() {
+        let points = noisy_circle(50, std::f64::consts::TAU);
+        for fit in [
+            fit_circle_2_kasa(&points).unwrap(),
+            fit_circle_2_pratt(&points).unwrap(),
+            fit_circle_2_geometric(&points).unwrap(),
+        ] {
+            let circle_segment = fit.shape();
+            assert!(circle_segment.center().distance(&Point2::new(3.0, -1.0)) < 1e-2);
+            assert!((circle_segment.radius() - 2.0).abs() < 1e-2);
+            assert_eq!(fit.residuals().count(), 50);
+            assert!(fit.residuals().max() < 0.03);
+        }
+
+        let points = noisy_circle(30, 1.0);
+        let kasa = fit_circle_2_kasa(&points).unwrap();
+        let geometric = fit_circle_2_geometric(&points).unwrap();
+        assert!(geometric.residuals().rms() < kasa.residuals().rms() + 1e-12);
+        assert!((geometric.shape().radius() - 2.0).abs() < 0.1);
+
+        let mut points = noisy_circle(40, std::f64::consts::TAU);
+        points.push(Point2::new(10.0, 10.0));
+        points.push(Point2::new(-4.0, 6.0));
+        points.push(Point2::new(3.0, -1.0));
+        let fit = fit_circle_2_ransac(&points, 0.05, 200, 1).unwrap();
+        assert_eq!(fit.inliers(), (0..40).collect::<Vec<_>>());
+        assert!((fit.shape().radius() - 2.0).abs() < 1e-2);
+        assert!(fit.residuals().max() < 0.05);
+
+        let line = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(2.0, 2.0),
+        ];
+        assert_eq!(
+            fit_circle_2_kasa(&line).unwrap_err(),
+            GeometryError::CollinearPoints
+        );
+        assert_eq!(
+            fit_circle_2_geometric(&line[..2]).unwrap_err(),
+            GeometryError::TooFewPoints
+        );
+        assert_eq!(
+            fit_circle_2_ransac(&points, 0.0, 10, 1).unwrap_err(),
+            GeometryError::InvalidTolerance
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use crate::kernel::{number_type::NumberType, point_2::Point2, util_enum::GeometryError};
+
+/// Fitted shape with the statistics of the residuals of its inliers.
+///
+/// Least-squares fits treat every point as an inlier.
+#[derive(Debug, Clone)]
+pub struct Fit2<T: NumberType, S> {
+    shape: S,
+    residuals: FitResiduals2<T>,
+    inliers: Vec<usize>,
+}
+
+impl<T: NumberType, S: Clone> Fit2<T, S> {
+    pub fn new(shape: S, residuals: FitResiduals2<T>, inliers: Vec<usize>) -> Self {
+        Self {
+            shape,
+            residuals,
+            inliers,
+        }
+    }
+
+    pub fn shape(&self) -> S {
+        self.shape.clone()
+    }
+
+    pub fn residuals(&self) -> FitResiduals2<T> {
+        self.residuals
+    }
+
+    /// Indices of the points the shape was fitted to.
+    pub fn inliers(&self) -> Vec<usize> {
+        self.inliers.clone()
+    }
+}
+
+/// Statistics of signed residuals, distances measured from the fitted shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FitResiduals2<T: NumberType> {
+    count: usize,
+    mean: T,
+    rms: T,
+    std_dev: T,
+    max: T,
+}
+
+impl<T: NumberType> FitResiduals2<T> {
+    pub fn new(residuals: &[T]) -> Self {
+        let count = residuals.len();
+        if count == 0 {
+            return Self {
+                count,
+                mean: T::zero(),
+                rms: T::zero(),
+                std_dev: T::zero(),
+                max: T::zero(),
+            };
+        }
+        let n = T::from_f64(count as f64);
+        let mean = residuals.iter().fold(T::zero(), |sum, r| sum + *r) / n;
+        let squares = residuals.iter().fold(T::zero(), |sum, r| sum + *r * *r) / n;
+        let variance = residuals
+            .iter()
+            .fold(T::zero(), |sum, r| sum + (*r - mean) * (*r - mean))
+            / n;
+        let max = residuals.iter().fold(
+            T::zero(),
+            |max, r| {
+                if r.abs() > max {
+                    r.abs()
+                } else {
+                    max
+                }
+            },
+        );
+        Self {
+            count,
+            mean,
+            rms: squares.sqrt(),
+            std_dev: variance.sqrt(),
+            max,
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Mean of the signed residuals.
+    pub fn mean(&self) -> T {
+        self.mean
+    }
+
+    pub fn rms(&self) -> T {
+        self.rms
+    }
+
+    pub fn std_dev(&self) -> T {
+        self.std_dev
+    }
+
+    /// Largest absolute residual.
+    pub fn max(&self) -> T {
+        self.max
+    }
+}
+
+/// Solves the 3x3 system by Gaussian elimination with partial pivoting.
+pub(crate) fn solve_3<T: NumberType>(mut matrix: [[T; 3]; 3], mut rhs: [T; 3]) -> Option<[T; 3]> {
+    for column in 0..3 {
+        let pivot = (column..3)
+            .max_by(|&i, &j| {
+                matrix[i][column]
+                    .abs()
+                    .partial_cmp(&matrix[j][column].abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap_or(column);
+        if matrix[pivot][column].equals(T::zero()) {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+        let pivot_row = matrix[column];
+        for row in column + 1..3 {
+            let factor = matrix[row][column] / pivot_row[column];
+            for (value, pivot_value) in matrix[row].iter_mut().zip(pivot_row).skip(column) {
+                *value = *value - factor * pivot_value;
+            }
+            rhs[row] = rhs[row] - factor * rhs[column];
+        }
+    }
+    let mut solution = [T::zero(); 3];
+    for row in (0..3).rev() {
+        let mut value = rhs[row];
+        for k in row + 1..3 {
+            value = value - matrix[row][k] * solution[k];
+        }
+        solution[row] = value / matrix[row][row];
+    }
+    Some(solution)
+}
+
+/// Indices of the largest consensus set among `iterations` candidates built from random samples.
+///
+/// Ties are broken by the smaller sum of absolute residuals.
+pub(crate) fn ransac<T: NumberType, S>(
+    points: &[Point2<T>],
+    sample_size: usize,
+    threshold: T,
+    iterations: usize,
+    seed: u64,
+    candidate: impl Fn(&[Point2<T>]) -> Option<S>,
+    residual: impl Fn(&S, &Point2<T>) -> T,
+) -> Result<Vec<usize>, GeometryError> {
+    if threshold < T::zero() || threshold.equals(T::zero()) {
+        return Err(GeometryError::InvalidTolerance);
+    }
+    if points.len() < sample_size {
+        return Err(GeometryError::TooFewPoints);
+    }
+    let mut rng = SampleRng::new(seed);
+    let mut best: Option<(Vec<usize>, T)> = None;
+    for _ in 0..iterations {
+        let sample: Vec<Point2<T>> = rng
+            .sample(points.len(), sample_size)
+            .into_iter()
+            .map(|i| points[i])
+            .collect();
+        let Some(shape) = candidate(&sample) else {
+            continue;
+        };
+        let mut inliers = Vec::new();
+        let mut error = T::zero();
+        for (i, point) in points.iter().enumerate() {
+            let distance = residual(&shape, point).abs();
+            if distance < threshold || distance.equals(threshold) {
+                inliers.push(i);
+                error = error + distance;
+            }
+        }
+        let better = match &best {
+            Some((best_inliers, best_error)) => {
+                inliers.len() > best_inliers.len()
+                    || (inliers.len() == best_inliers.len() && error < *best_error)
+            }
+            None => true,
+        };
+        if better {
+            best = Some((inliers, error));
+        }
+    }
+    match best {
+        Some((inliers, _)) if inliers.len() >= sample_size => Ok(inliers),
+        _ => Err(GeometryError::NoSolution),
+    }
+}
+
+/// Xorshift generator so RANSAC runs are reproducible from a seed.
+pub(crate) struct SampleRng {
+    state: u64,
+}
+
+impl SampleRng {
+    /// The xorshift state must not be zero, so the one seed mapping to it is replaced.
+    pub(crate) fn new(seed: u64) -> Self {
+        let state = seed ^ 0x9e37_79b9_7f4a_7c15;
+        Self {
+            state: if state == 0 {
+                0x9e37_79b9_7f4a_7c15
+            } else {
+                state
+            },
+        }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// `count` distinct indices below `len`, at most `len` of them.
+    ///
+    /// A drawn index already taken moves on to the next free one, so every draw succeeds.
+    pub(crate) fn sample(&mut self, len: usize, count: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = Vec::with_capacity(count);
+        while indices.len() < count.min(len) {
+            let mut index = (self.next() % len as u64) as usize;
+            while indices.contains(&index) {
+                index = (index + 1) % len;
+            }
+            indices.push(index);
+        }
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_residuals_2() {
+        let residuals = FitResiduals2::new(&[1.0, -1.0, 3.0, -3.0]);
+        assert_eq!(residuals.count(), 4);
+        assert_eq!(residuals.mean(), 0.0);
+        assert_eq!(residuals.rms(), 5.0_f64.sqrt());
+        assert_eq!(residuals.std_dev(), 5.0_f64.sqrt());
+        assert_eq!(residuals.max(), 3.0);
+
+        let solution = solve_3(
+            [[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]],
+            [3.0, 5.0, 5.0],
+        )
+        .unwrap();
+        assert!(solution.iter().all(|value| value.equals(1.0)));
+        assert_eq!(
+            solve_3(
+                [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 4.0]],
+                [1.0, 2.0, 3.0]
+            ),
+            None
+        );
+
+        let mut rng = SampleRng::new(7);
+        let sample = rng.sample(5, 3);
+        assert_eq!(sample.len(), 3);
+        assert!(sample.iter().all(|&i| i < 5));
+        assert!(sample[0] != sample[1] && sample[1] != sample[2] && sample[0] != sample[2]);
+
+        // The seed cancelling the mixing constant still draws, and whole ranges can be taken.
+        let mut rng = SampleRng::new(0x9e37_79b9_7f4a_7c15);
+        assert_ne!(rng.next(), 0);
+        let mut sample = rng.sample(4, 4);
+        sample.sort();
+        assert_eq!(sample, vec![0, 1, 2, 3]);
+        assert_eq!(rng.sample(2, 3).len(), 2);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::kernel::{
+    line_2::Line2, number_type::NumberType, point_2::Point2, util_enum::GeometryError,
+};
+
+use super::fit_2::{ransac, Fit2, FitResiduals2};
+
+/// Total least squares fit minimizing the sum of squared perpendicular distances.
+///
+/// The fitted line has a unit normal, so residuals are signed distances.
+pub fn fit_line_2<T: NumberType>(points: &[Point2<T>]) -> Result<Fit2<T, Line2<T>>, GeometryError> {
+    let line = total_least_squares(points)?;
+    Ok(line_fit(line, points, (0..points.len()).collect()))
+}
+
+/// Total least squares fit of the largest set of points within `threshold` of a line through two sampled points.
+pub fn fit_line_2_ransac<T: NumberType>(
+    points: &[Point2<T>],
+    threshold: T,
+    iterations: usize,
+    seed: u64,
+) -> Result<Fit2<T, Line2<T>>, GeometryError> {
+    let inliers = ransac(
+        points,
+        2,
+        threshold,
+        iterations,
+        seed,
+        |sample| total_least_squares(sample).ok(),
+        line_residual,
+    )?;
+    let inlier_points: Vec<Point2<T>> = inliers.iter().map(|&i| points[i]).collect();
+    let line = total_least_squares(&inlier_points)?;
+    Ok(line_fit(line, points, inliers))
+}
+
+fn total_least_squares<T: NumberType>(points: &[Point2<T>]) -> Result<Line2<T>, GeometryError> {
+    if points.len() < 2 {
+        return Err(GeometryError::TooFewPoints);
+    }
+    let n = T::from_f64(points.len() as f64);
+    let (x, y) = points.iter().fold((T::zero(), T::zero()), |(x, y), point| {
+        (x + point.x(), y + point.y())
+    });
+    let (mean_x, mean_y) = (x / n, y / n);
+    let (mut sxx, mut syy, mut sxy) = (T::zero(), T::zero(), T::zero());
+    for point in points {
+        let dx = point.x() - mean_x;
+        let dy = point.y() - mean_y;
+        sxx = sxx + dx * dx;
+        syy = syy + dy * dy;
+        sxy = sxy + dx * dy;
+    }
+    if (sxx + syy).equals(T::zero()) {
+        return Err(GeometryError::CoincidentPoints);
+    }
+    let radian = (T::from_f64(2.0) * sxy).atan2(sxx - syy) / T::from_f64(2.0);
+    let a = -radian.sin();
+    let b = radian.cos();
+    Ok(Line2::new(a, b, -(a * mean_x + b * mean_y)))
+}
+
+fn line_residual<T: NumberType>(line: &Line2<T>, point: &Point2<T>) -> T {
+    line.a() * point.x() + line.b() * point.y() + line.c()
+}
+
+fn line_fit<T: NumberType>(
+    line: Line2<T>,
+    points: &[Point2<T>],
+    inliers: Vec<usize>,
+) -> Fit2<T, Line2<T>> {
+    let residuals: Vec<T> = inliers
+        .iter()
+        .map(|&i| line_residual(&line, &points[i]))
+        .collect();
+    Fit2::new(line, FitResiduals2::new(&residuals), inliers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_line_2() {
+        let points: Vec<Point2<f64>> = (0..20)
+            .map(|i| {
+                let t = i as f64;
+                let noise = if i % 2 == 0 { 0.01 } else { -0.01 };
+                Point2::new(t + 2.0 * noise, 2.0 * t + 1.0 - noise)
+            })
+            .collect();
+        let fit = fit_line_2(&points).unwrap();
+        let line = fit.shape();
+        assert!((line.a() / line.b() + 2.0).abs() < 1e-3);
+        assert!((line.b() + line.c()).abs() < 1e-2);
+        assert!((line.a() * line.a() + line.b() * line.b()).equals(1.0));
+        assert_eq!(fit.residuals().count(), 20);
+        assert!(fit.residuals().max() < 0.03);
+        let fit = fit_line_2_ransac(&points, 0.05, 100, 0x9e37_79b9_7f4a_7c15).unwrap();
+        assert_eq!(fit.inliers(), (0..20).collect::<Vec<_>>());
+        assert!(fit.residuals().mean().abs() < 1e-9);
+
+        let vertical = [
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 3.0),
+            Point2::new(1.0, -2.0),
+        ];
+        let fit = fit_line_2(&vertical).unwrap();
+        assert_eq!(fit.shape(), Line2::new(1.0, 0.0, -1.0));
+        assert!(fit.residuals().rms() < 1e-12);
+
+        let mut points = points;
+        points.push(Point2::new(5.0, -5.0));
+        points.push(Point2::new(0.0, 30.0));
+        let fit = fit_line_2_ransac(&points, 0.05, 100, 3).unwrap();
+        assert_eq!(fit.inliers(), (0..20).collect::<Vec<_>>());
+        assert!(fit.residuals().max() < 0.03);
+
+        assert_eq!(
+            fit_line_2(&[Point2::new(1.0, 1.0), Point2::new(1.0, 1.0)]).unwrap_err(),
+            GeometryError::CoincidentPoints
+        );
+        assert_eq!(
+            fit_line_2(&[Point2::new(1.0, 1.0)]).unwrap_err(),
+            GeometryError::TooFewPoints
+        );
+    }
+}
@@ -1,11 +1,27 @@
 mod arc_segment_2_arc_segment_2;
 mod circle_segment_2_arc_segment_2;
 mod circle_segment_2_circle_segment_2;
+pub mod clothoid_2_segment_2;
+pub mod intersection_enum;
+pub mod line_2_arc_segment_2;
+pub mod line_2_circle_segment_2;
 pub mod line_2_line_2;
+pub mod line_2_line_segment_2;
 pub mod line_2_ray_2;
 mod line_segment_2_arc_segment_2;
 mod line_segment_2_circle_segment_2;
 mod line_segment_2_line_segment_2;
+pub mod line_segment_2_polygon_2;
 pub mod line_segment_2_ray_2;
+pub mod line_segment_2_triangle_2;
+pub mod nurbs_curve_2_line_2;
+pub mod nurbs_curve_2_line_segment_2;
+pub mod nurbs_curve_2_nurbs_curve_2;
+pub mod polygon_2_polygon_2;
+pub mod ray_2_arc_segment_2;
+pub mod ray_2_circle_segment_2;
+pub mod ray_2_ray_2;
+pub mod red_blue_segment_2_intersection;
 pub mod segment_2_segment_2;
 pub mod sweep_segment_2_intersection;
+pub mod triangle_2_triangle_2;
@@ -62,7 +62,7 @@ pub fn arc_segment_2_arc_segment_2_intersection<T: NumberType>(
 mod tests {
     use std::f64::consts::PI;
 
-    use crate::kernel::arc_segment_2::ArcSegment2;
+    use crate::kernel::{arc_segment_2::ArcSegment2, util_enum::Orientation};
 
     use super::*;
 
@@ -114,5 +114,36 @@ mod tests {
             arc_segment_2_arc_segment_2_intersection(&arc_segment_a, &arc_segment_b),
             vec![Point2::new(5.0, 0.0), Point2::new(-5.0, 0.0),]
         );
+
+        let clockwise_b = ArcSegment2::with_orientation(
+            CircleSegment2::new(Point2::new(5.0, 0.0), 5.0),
+            0.0,
+            PI,
+            Orientation::Clockwise,
+        )
+        .unwrap();
+        assert!(arc_segment_2_arc_segment_2_intersection(&arc_segment_a, &clockwise_b).is_empty());
+        let clockwise_a = ArcSegment2::with_orientation(
+            CircleSegment2::new(Point2::new(0.0, 0.0), 5.0),
+            0.0,
+            PI,
+            Orientation::Clockwise,
+        )
+        .unwrap();
+        assert_eq!(
+            arc_segment_2_arc_segment_2_intersection(&clockwise_a, &clockwise_b),
+            vec![Point2::new(2.5, -2.5 * 3.0.sqrt()),]
+        );
+
+        let zero_length = ArcSegment2::new(
+            CircleSegment2::new(Point2::new(0.0, 0.0), 5.0),
+            PI / 2.0,
+            PI / 2.0,
+        );
+        assert_eq!(
+            arc_segment_2_arc_segment_2_intersection(&arc_segment_a, &zero_length),
+            vec![Point2::new(0.0, 5.0)]
+        );
+        assert!(arc_segment_2_arc_segment_2_intersection(&clockwise_a, &zero_length).is_empty());
     }
 }
@@ -0,0 +1,170 @@
+use crate::{
+    algorithm::location::{
+        point_2_arc_segment_2::is_point_2_on_arc_segment_2,
+        point_2_line_segment_2::is_point_2_on_line_segment_2,
+    },
+    kernel::{
+        clothoid_2::Clothoid2, line_segment_2::LineSegment2, number_type::NumberType,
+        point_2::Point2, segment_2::Segment2, util_enum::Segment2Type,
+    },
+};
+
+use super::segment_2_segment_2::segment_2_segment_2_intersection;
+
+const MAX_NEWTON_ITERATIONS: usize = 32;
+
+pub fn is_clothoid_2_segment_2_intersected<T: NumberType>(
+    clothoid: &Clothoid2<T>,
+    segment: &impl Segment2<T>,
+    tolerance: T,
+) -> bool {
+    !clothoid_2_segment_2_intersection(clothoid, segment, tolerance).is_empty()
+}
+
+/// Intersects the chords of a tessellation within `tolerance` with the segment,
+/// then refines every hit on the clothoid with Newton's method.
+///
+/// The result is ordered by station along the clothoid.
+pub fn clothoid_2_segment_2_intersection<T: NumberType>(
+    clothoid: &Clothoid2<T>,
+    segment: &impl Segment2<T>,
+    tolerance: T,
+) -> Vec<Point2<T>> {
+    let stations = clothoid.stations(tolerance);
+    let points: Vec<Point2<T>> = stations
+        .iter()
+        .map(|station| clothoid.point_at(*station))
+        .collect();
+    let mut hits: Vec<(T, Point2<T>)> = Vec::new();
+    for i in 0..stations.len() - 1 {
+        let chord = LineSegment2::new(points[i], points[i + 1]);
+        let chord_vector = points[i + 1] - points[i];
+        for point in segment_2_segment_2_intersection(&chord, segment).points() {
+            let fraction = chord_vector.dot(&(point - points[i])) / chord_vector.dot(&chord_vector);
+            let station = stations[i] + (stations[i + 1] - stations[i]) * fraction;
+            let hit = match refine(clothoid, segment, station) {
+                Some(refined) if (refined - station).abs() <= stations[i + 1] - stations[i] => {
+                    let refined_point = clothoid.point_at(refined);
+                    if is_point_2_on_segment_2(&refined_point, segment) {
+                        (refined, refined_point)
+                    } else {
+                        (station, point)
+                    }
+                }
+                _ => (station, point),
+            };
+            hits.push(hit);
+        }
+    }
+    hits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let mut result: Vec<Point2<T>> = Vec::new();
+    for (_, point) in hits {
+        if result
+            .last()
+            .is_none_or(|last| last.distance(&point) > tolerance)
+        {
+            result.push(point);
+        }
+    }
+    result
+}
+
+/// Signed distance from the supporting line or circle of the segment and its derivative by station.
+fn distance_with_derivative<T: NumberType>(
+    clothoid: &Clothoid2<T>,
+    segment: &impl Segment2<T>,
+    station: T,
+) -> (T, T) {
+    let point = clothoid.point_at(station);
+    let tangent = clothoid.tangent_at(station);
+    match segment.segment_type() {
+        Segment2Type::LineSegment2 => {
+            let direction = (segment.target() - segment.source()).normalize();
+            (
+                direction.cross(&(point - segment.source())),
+                direction.cross(&tangent),
+            )
+        }
+        _ => {
+            let radial = point - segment.center();
+            let length = radial.length();
+            (length - segment.radius(), radial.dot(&tangent) / length)
+        }
+    }
+}
+
+fn refine<T: NumberType>(
+    clothoid: &Clothoid2<T>,
+    segment: &impl Segment2<T>,
+    station: T,
+) -> Option<T> {
+    let mut station = station;
+    for _ in 0..MAX_NEWTON_ITERATIONS {
+        let (value, derivative) = distance_with_derivative(clothoid, segment, station);
+        if value.equals(T::zero()) {
+            return Some(station);
+        }
+        if derivative.equals(T::zero()) {
+            return None;
+        }
+        station = station - value / derivative;
+        if station < T::zero() || station > clothoid.length() {
+            return None;
+        }
+    }
+    None
+}
+
+fn is_point_2_on_segment_2<T: NumberType>(point: &Point2<T>, segment: &impl Segment2<T>) -> bool {
+    match segment.segment_type() {
+        Segment2Type::LineSegment2 => is_point_2_on_line_segment_2(point, segment),
+        Segment2Type::CircleSegment2 => true,
+        Segment2Type::ArcSegment2 => is_point_2_on_arc_segment_2(point, segment),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::kernel::{arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2};
+
+    use super::*;
+
+    #[test]
+    fn test_clothoid_2_segment_2_intersection() {
+        let circle = Clothoid2::new(Point2::new(0.0, 0.0), 0.0, 1.0, 0.0, 2.0 * PI).unwrap();
+        let line_segment = LineSegment2::new(Point2::new(-2.0, 1.0), Point2::new(2.0, 1.0));
+        assert_eq!(
+            clothoid_2_segment_2_intersection(&circle, &line_segment, 1e-6),
+            vec![Point2::new(1.0, 1.0), Point2::new(-1.0, 1.0)]
+        );
+
+        let spiral = Clothoid2::new(Point2::new(0.0, 0.0), 0.0, 0.0, 0.01, 20.0).unwrap();
+        let line_segment = LineSegment2::new(Point2::new(10.0, -5.0), Point2::new(10.0, 10.0));
+        let result = clothoid_2_segment_2_intersection(&spiral, &line_segment, 1e-6);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].x().equals(10.0));
+        let (_, offset) = spiral.locate_point(&result[0]);
+        assert!(offset.equals(0.0));
+
+        let circle_segment = CircleSegment2::new(Point2::new(20.0, 0.0), 10.0);
+        let result = clothoid_2_segment_2_intersection(&spiral, &circle_segment, 1e-6);
+        assert_eq!(result.len(), 2);
+        for point in &result {
+            assert!(point.distance(&circle_segment.center()).equals(10.0));
+        }
+
+        let arc_segment = ArcSegment2::new(circle_segment, PI / 2.0, 3.0 * PI / 2.0);
+        assert_eq!(
+            clothoid_2_segment_2_intersection(&spiral, &arc_segment, 1e-6),
+            result
+        );
+        let arc_segment = ArcSegment2::new(circle_segment, 3.0 * PI / 2.0, 5.0 * PI / 2.0);
+        assert!(!is_clothoid_2_segment_2_intersected(
+            &spiral,
+            &arc_segment,
+            1e-6
+        ));
+    }
+}
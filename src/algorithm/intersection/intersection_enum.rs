@@ -0,0 +1,106 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::kernel::{
+    arc_segment_2::ArcSegment2, line_2::Line2, line_segment_2::LineSegment2,
+    number_type::NumberType, point_2::Point2, ray_2::Ray2, segment_2::Segment2,
+};
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntersectionContact {
+    /// Transversal meeting inside both.
+    Crossing,
+    /// Meeting inside both with a common tangent.
+    Tangent,
+    /// Meeting at an endpoint of either, such as a T-junction or a chain continuing.
+    Touching,
+}
+
+/// Points, overlaps and arcs are ordered along the first segment, overlaps are directed like it.
+///
+/// Overlaps of co-circular arcs take the orientation of the first arc, or of the second when the first is a circle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment2Segment2Intersection<T: NumberType> {
+    Empty,
+    Point(Point2<T>, IntersectionContact),
+    TwoPoints(Point2<T>, Point2<T>),
+    Segment(LineSegment2<T>),
+    Arc(ArcSegment2<T>),
+    TwoArcs(ArcSegment2<T>, ArcSegment2<T>),
+    ArcAndPoint(ArcSegment2<T>, Point2<T>),
+    Coincident,
+}
+
+impl<T: NumberType> Segment2Segment2Intersection<T> {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Segment2Segment2Intersection::Empty)
+    }
+
+    /// Isolated points and the endpoints of overlaps, a coincident circle has none.
+    pub fn points(&self) -> Vec<Point2<T>> {
+        match self {
+            Segment2Segment2Intersection::Empty | Segment2Segment2Intersection::Coincident => {
+                Vec::new()
+            }
+            Segment2Segment2Intersection::Point(point, _) => vec![*point],
+            Segment2Segment2Intersection::TwoPoints(point_a, point_b) => vec![*point_a, *point_b],
+            Segment2Segment2Intersection::Segment(line_segment) => {
+                vec![line_segment.source(), line_segment.target()]
+            }
+            Segment2Segment2Intersection::Arc(arc_segment) => {
+                vec![arc_segment.source(), arc_segment.target()]
+            }
+            Segment2Segment2Intersection::TwoArcs(arc_segment_a, arc_segment_b) => vec![
+                arc_segment_a.source(),
+                arc_segment_a.target(),
+                arc_segment_b.source(),
+                arc_segment_b.target(),
+            ],
+            Segment2Segment2Intersection::ArcAndPoint(arc_segment, point) => {
+                vec![arc_segment.source(), arc_segment.target(), *point]
+            }
+        }
+    }
+}
+
+/// Intersection of a line or ray with another line, ray, segment, circle or arc.
+///
+/// Points and overlaps are ordered along the line or ray, overlaps are directed like it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Linear2Intersection<T: NumberType> {
+    Empty,
+    Point(Point2<T>, IntersectionContact),
+    TwoPoints(Point2<T>, Point2<T>),
+    Segment(LineSegment2<T>),
+    Ray(Ray2<T>),
+    Line(Line2<T>),
+}
+
+impl<T: NumberType> Linear2Intersection<T> {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Linear2Intersection::Empty)
+    }
+
+    /// Isolated points, the endpoints of segments and the origin of rays, a line has none.
+    pub fn points(&self) -> Vec<Point2<T>> {
+        match self {
+            Linear2Intersection::Empty | Linear2Intersection::Line(_) => Vec::new(),
+            Linear2Intersection::Point(point, _) => vec![*point],
+            Linear2Intersection::TwoPoints(point_a, point_b) => vec![*point_a, *point_b],
+            Linear2Intersection::Segment(line_segment) => {
+                vec![line_segment.source(), line_segment.target()]
+            }
+            Linear2Intersection::Ray(ray) => vec![ray.origin()],
+        }
+    }
+
+    /// From isolated points already ordered along the line or ray.
+    pub(crate) fn from_points(points: &[(Point2<T>, IntersectionContact)]) -> Self {
+        match points {
+            [] => Linear2Intersection::Empty,
+            [(point, contact)] => Linear2Intersection::Point(*point, *contact),
+            [(first, _), .., (last, _)] => Linear2Intersection::TwoPoints(*first, *last),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    algorithm::location::point_2_arc_segment_2::is_point_2_on_arc_segment_2,
+    kernel::{
+        arc_segment_2::ArcSegment2, line_2::Line2, number_type::NumberType, point_2::Point2,
+        segment_2::Segment2,
+    },
+};
+
+use super::{
+    intersection_enum::{IntersectionContact, Linear2Intersection},
+    line_2_circle_segment_2::line_circle_points,
+};
+
+pub fn is_line_2_arc_segment_2_intersected<T: NumberType>(
+    line: &Line2<T>,
+    arc_segment: &ArcSegment2<T>,
+) -> bool {
+    !line_2_arc_segment_2_intersection(line, arc_segment).is_empty()
+}
+
+/// Meetings at an end of the arc touch it.
+pub fn line_2_arc_segment_2_intersection<T: NumberType>(
+    line: &Line2<T>,
+    arc_segment: &ArcSegment2<T>,
+) -> Linear2Intersection<T> {
+    let points: Vec<_> = line_circle_points(&line.point(), &line.direction(), arc_segment)
+        .into_iter()
+        .filter(|(_, point, _)| is_point_2_on_arc_segment_2(point, arc_segment))
+        .map(|(_, point, contact)| (point, end_contact(arc_segment, &point, contact)))
+        .collect();
+    Linear2Intersection::from_points(&points)
+}
+
+/// Touching at the ends of the arc, otherwise `contact`.
+pub(crate) fn end_contact<T: NumberType>(
+    arc_segment: &ArcSegment2<T>,
+    point: &Point2<T>,
+    contact: IntersectionContact,
+) -> IntersectionContact {
+    if *point == arc_segment.source() || *point == arc_segment.target() {
+        IntersectionContact::Touching
+    } else {
+        contact
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::circle_segment_2::CircleSegment2;
+
+    #[test]
+    fn test_line_2_arc_segment_2_intersection() {
+        let arc_segment = ArcSegment2::new(
+            CircleSegment2::new(Point2::new(0.0, 0.0), 1.0),
+            0.0,
+            std::f64::consts::PI,
+        );
+
+        let line = Line2::from_points(&Point2::new(-2.0, 0.5), &Point2::new(2.0, 0.5)).unwrap();
+        assert_eq!(
+            line_2_arc_segment_2_intersection(&line, &arc_segment),
+            Linear2Intersection::TwoPoints(
+                Point2::new(-0.75_f64.sqrt(), 0.5),
+                Point2::new(0.75_f64.sqrt(), 0.5)
+            )
+        );
+
+        let line = Line2::from_points(&Point2::new(0.5, -2.0), &Point2::new(0.5, 2.0)).unwrap();
+        assert_eq!(
+            line_2_arc_segment_2_intersection(&line, &arc_segment),
+            Linear2Intersection::Point(
+                Point2::new(0.5, 0.75_f64.sqrt()),
+                IntersectionContact::Crossing
+            )
+        );
+
+        let line = Line2::from_points(&Point2::new(2.0, 1.0), &Point2::new(-2.0, 1.0)).unwrap();
+        assert_eq!(
+            line_2_arc_segment_2_intersection(&line, &arc_segment),
+            Linear2Intersection::Point(Point2::new(0.0, 1.0), IntersectionContact::Tangent)
+        );
+        assert!(is_line_2_arc_segment_2_intersected(&line, &arc_segment));
+
+        let line = Line2::from_points(&Point2::new(1.0, -2.0), &Point2::new(1.0, 2.0)).unwrap();
+        assert_eq!(
+            line_2_arc_segment_2_intersection(&line, &arc_segment),
+            Linear2Intersection::Point(Point2::new(1.0, 0.0), IntersectionContact::Touching)
+        );
+        let line = Line2::from_points(&Point2::new(-2.0, -2.0), &Point2::new(2.0, 2.0)).unwrap();
+        assert_eq!(
+            line_2_arc_segment_2_intersection(&line, &arc_segment),
+            Linear2Intersection::Point(
+                Point2::new(0.5_f64.sqrt(), 0.5_f64.sqrt()),
+                IntersectionContact::Crossing
+            )
+        );
+        let line = Line2::from_points(&Point2::new(-2.0, 0.0), &Point2::new(2.0, 0.0)).unwrap();
+        assert_eq!(
+            line_2_arc_segment_2_intersection(&line, &arc_segment),
+            Linear2Intersection::TwoPoints(Point2::new(-1.0, 0.0), Point2::new(1.0, 0.0))
+        );
+
+        let line = Line2::from_points(&Point2::new(-2.0, -1.0), &Point2::new(2.0, -1.0)).unwrap();
+        assert!(!is_line_2_arc_segment_2_intersected(&line, &arc_segment));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::kernel::{
+    circle_segment_2::CircleSegment2, line_2::Line2, number_type::NumberType, point_2::Point2,
+    segment_2::Segment2, vector_2::Vector2,
+};
+
+use super::intersection_enum::{IntersectionContact, Linear2Intersection};
+
+pub fn is_line_2_circle_segment_2_intersected<T: NumberType>(
+    line: &Line2<T>,
+    circle_segment: &CircleSegment2<T>,
+) -> bool {
+    !line_2_circle_segment_2_intersection(line, circle_segment).is_empty()
+}
+
+pub fn line_2_circle_segment_2_intersection<T: NumberType>(
+    line: &Line2<T>,
+    circle_segment: &CircleSegment2<T>,
+) -> Linear2Intersection<T> {
+    let points = line_circle_points(&line.point(), &line.direction(), circle_segment);
+    Linear2Intersection::from_points(
+        &points
+            .into_iter()
+            .map(|(_, point, contact)| (point, contact))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Points of the supporting circle on the line through `origin` along the unit `direction`,
+/// with their parameters along it in increasing order.
+pub(crate) fn line_circle_points<T: NumberType>(
+    origin: &Point2<T>,
+    direction: &Vector2<T>,
+    circle_segment: &impl Segment2<T>,
+) -> Vec<(T, Point2<T>, IntersectionContact)> {
+    let center = circle_segment.center();
+    let radius = circle_segment.radius();
+    let foot_parameter = direction.dot(&(center - *origin));
+    let point_at = |t: T| {
+        Point2::new(
+            origin.x() + direction.x() * t,
+            origin.y() + direction.y() * t,
+        )
+    };
+    let foot = point_at(foot_parameter);
+    let distance = center.distance(&foot);
+    if distance.equals(radius) {
+        return vec![(foot_parameter, foot, IntersectionContact::Tangent)];
+    }
+    if distance > radius {
+        return Vec::new();
+    }
+    let half_chord = (radius * radius - distance * distance).sqrt();
+    [foot_parameter - half_chord, foot_parameter + half_chord]
+        .into_iter()
+        .map(|t| (t, point_at(t), IntersectionContact::Crossing))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_2_circle_segment_2_intersection() {
+        let circle_segment = CircleSegment2::new(Point2::new(1.0, 1.0), 1.0);
+
+        let line = Line2::from_points(&Point2::new(3.0, 1.0), &Point2::new(-3.0, 1.0)).unwrap();
+        assert_eq!(
+            line_2_circle_segment_2_intersection(&line, &circle_segment),
+            Linear2Intersection::TwoPoints(Point2::new(2.0, 1.0), Point2::new(0.0, 1.0))
+        );
+        assert!(is_line_2_circle_segment_2_intersected(
+            &line,
+            &circle_segment
+        ));
+
+        let line = Line2::from_points(&Point2::new(0.0, 2.0), &Point2::new(1.0, 2.0)).unwrap();
+        assert_eq!(
+            line_2_circle_segment_2_intersection(&line, &circle_segment),
+            Linear2Intersection::Point(Point2::new(1.0, 2.0), IntersectionContact::Tangent)
+        );
+
+        let line = Line2::from_points(&Point2::new(0.0, 2.5), &Point2::new(1.0, 2.5)).unwrap();
+        assert!(line_2_circle_segment_2_intersection(&line, &circle_segment).is_empty());
+        assert!(!is_line_2_circle_segment_2_intersected(
+            &line,
+            &circle_segment
+        ));
+    }
+}
@@ -1,14 +1,17 @@
 use crate::kernel::{line_2::Line2, number_type::NumberType, point_2::Point2};
 
+use super::intersection_enum::{IntersectionContact, Linear2Intersection};
+
 pub fn is_line_2_line_2_intersected<T: NumberType>(line_a: &Line2<T>, line_b: &Line2<T>) -> bool {
     let intersection = line_2_line_2_intersection(line_a, line_b);
-    intersection.is_some()
+    !intersection.is_empty()
 }
 
+/// Coincident lines give `line_a` itself.
 pub fn line_2_line_2_intersection<T: NumberType>(
     line_a: &Line2<T>,
     line_b: &Line2<T>,
-) -> Option<Point2<T>> {
+) -> Linear2Intersection<T> {
     let a_1 = line_a.a();
     let b_1 = line_a.b();
     let c_1 = line_a.c();
@@ -17,11 +20,14 @@ pub fn line_2_line_2_intersection<T: NumberType>(
     let c_2 = line_b.c();
     let det = a_1 * b_2 - a_2 * b_1;
     if det.equals(T::zero()) {
-        return None;
+        if line_a.distance(&line_b.point()).equals(T::zero()) {
+            return Linear2Intersection::Line(*line_a);
+        }
+        return Linear2Intersection::Empty;
     }
     let x = (-b_2 * c_1 + b_1 * c_2) / det;
     let y = (a_2 * c_1 - a_1 * c_2) / det;
-    Some(Point2::new(x, y))
+    Linear2Intersection::Point(Point2::new(x, y), IntersectionContact::Crossing)
 }
 
 #[cfg(test)]
@@ -38,7 +44,7 @@ mod tests {
         let line_a = Line2::new(1.0, 1.0, 0.0);
         let line_b = Line2::new(1.0, 1.0, 0.0);
         let is_intersected = is_line_2_line_2_intersected(&line_a, &line_b);
-        assert_eq!(is_intersected, false);
+        assert_eq!(is_intersected, true);
 
         let line_a = Line2::new(1.0, 1.0, 0.0);
         let line_b = Line2::new(1.0, 1.0, 1.0);
@@ -56,21 +62,31 @@ mod tests {
         let line_a = Line2::new(1.0, 1.0, 0.0);
         let line_b = Line2::new(1.0, -1.0, 0.0);
         let intersection = line_2_line_2_intersection(&line_a, &line_b);
-        assert_eq!(intersection, Some(Point2::new(0.0, 0.0)));
+        assert_eq!(
+            intersection,
+            Linear2Intersection::Point(Point2::new(0.0, 0.0), IntersectionContact::Crossing)
+        );
 
         let line_a = Line2::new(1.0, 1.0, 0.0);
         let line_b = Line2::new(1.0, 1.0, 0.0);
         let intersection = line_2_line_2_intersection(&line_a, &line_b);
-        assert_eq!(intersection, None);
+        assert_eq!(intersection, Linear2Intersection::Line(line_a));
+
+        let line_b = Line2::new(-2.0, -2.0, 0.0);
+        let intersection = line_2_line_2_intersection(&line_a, &line_b);
+        assert_eq!(intersection, Linear2Intersection::Line(line_a));
 
         let line_a = Line2::new(1.0, 1.0, 0.0);
         let line_b = Line2::new(1.0, 1.0, 1.0);
         let intersection = line_2_line_2_intersection(&line_a, &line_b);
-        assert_eq!(intersection, None);
+        assert_eq!(intersection, Linear2Intersection::Empty);
 
         let line_a = Line2::new(-1.0, 1.0, 0.0);
         let line_b = Line2::new(1.0, 1.0, -5.0);
         let intersection = line_2_line_2_intersection(&line_a, &line_b);
-        assert_eq!(intersection, Some(Point2::new(2.5, 2.5)));
+        assert_eq!(
+            intersection,
+            Linear2Intersection::Point(Point2::new(2.5, 2.5), IntersectionContact::Crossing)
+        );
     }
 }
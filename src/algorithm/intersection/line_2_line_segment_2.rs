@@ -0,0 +1,86 @@
+use crate::kernel::{
+    line_2::Line2, line_segment_2::LineSegment2, number_type::NumberType, point_2::Point2,
+    segment_2::Segment2,
+};
+
+use super::intersection_enum::{IntersectionContact, Linear2Intersection};
+
+pub fn is_line_2_line_segment_2_intersected<T: NumberType>(
+    line: &Line2<T>,
+    line_segment: &LineSegment2<T>,
+) -> bool {
+    !line_2_line_segment_2_intersection(line, line_segment).is_empty()
+}
+
+/// A segment on the line is returned directed like the line, one ending on it touches it.
+pub fn line_2_line_segment_2_intersection<T: NumberType>(
+    line: &Line2<T>,
+    line_segment: &LineSegment2<T>,
+) -> Linear2Intersection<T> {
+    let source = line_segment.source();
+    let target = line_segment.target();
+    let source_distance = line.signed_distance(&source);
+    let target_distance = line.signed_distance(&target);
+    let source_on = source_distance.equals(T::zero());
+    let target_on = target_distance.equals(T::zero());
+    if source_on && target_on {
+        if source == target {
+            return Linear2Intersection::Point(source, IntersectionContact::Touching);
+        }
+        return if line.direction().dot(&(target - source)) > T::zero() {
+            Linear2Intersection::Segment(*line_segment)
+        } else {
+            Linear2Intersection::Segment(LineSegment2::new(target, source))
+        };
+    }
+    if source_on {
+        return Linear2Intersection::Point(source, IntersectionContact::Touching);
+    }
+    if target_on {
+        return Linear2Intersection::Point(target, IntersectionContact::Touching);
+    }
+    if (source_distance > T::zero()) == (target_distance > T::zero()) {
+        return Linear2Intersection::Empty;
+    }
+    let t = source_distance / (source_distance - target_distance);
+    let point = Point2::new(
+        source.x() + (target.x() - source.x()) * t,
+        source.y() + (target.y() - source.y()) * t,
+    );
+    Linear2Intersection::Point(point, IntersectionContact::Crossing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_2_line_segment_2_intersection() {
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 0.0)).unwrap();
+
+        let line_segment = LineSegment2::new(Point2::new(1.0, -1.0), Point2::new(3.0, 1.0));
+        assert_eq!(
+            line_2_line_segment_2_intersection(&line, &line_segment),
+            Linear2Intersection::Point(Point2::new(2.0, 0.0), IntersectionContact::Crossing)
+        );
+        assert!(is_line_2_line_segment_2_intersected(&line, &line_segment));
+
+        let line_segment = LineSegment2::new(Point2::new(1.0, 0.0), Point2::new(3.0, 1.0));
+        assert_eq!(
+            line_2_line_segment_2_intersection(&line, &line_segment),
+            Linear2Intersection::Point(Point2::new(1.0, 0.0), IntersectionContact::Touching)
+        );
+
+        let line_segment = LineSegment2::new(Point2::new(3.0, 0.0), Point2::new(-1.0, 0.0));
+        assert_eq!(
+            line_2_line_segment_2_intersection(&line, &line_segment),
+            Linear2Intersection::Segment(LineSegment2::new(
+                Point2::new(-1.0, 0.0),
+                Point2::new(3.0, 0.0)
+            ))
+        );
+
+        let line_segment = LineSegment2::new(Point2::new(1.0, 1.0), Point2::new(3.0, 2.0));
+        assert!(!is_line_2_line_segment_2_intersected(&line, &line_segment));
+    }
+}
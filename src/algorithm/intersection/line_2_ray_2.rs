@@ -1,80 +1,106 @@
 use crate::{
-    algorithm::location::{location_enum::Point2Ray2Location, point_2_ray_2::locate_point_2_ray_2},
-    kernel::{line_2::Line2, number_type::NumberType, point_2::Point2, ray_2::Ray2},
+    algorithm::location::point_2_ray_2::is_point_2_on_ray_2,
+    kernel::{line_2::Line2, number_type::NumberType, ray_2::Ray2},
 };
 
-use super::line_2_line_2::line_2_line_2_intersection;
+use super::{
+    intersection_enum::{IntersectionContact, Linear2Intersection},
+    line_2_line_2::line_2_line_2_intersection,
+};
 
 pub fn is_line_2_ray_2_intersected<T: NumberType>(line: &Line2<T>, ray: &Ray2<T>) -> bool {
-    line_2_ray_2_intersection(line, ray).is_some()
+    !line_2_ray_2_intersection(line, ray).is_empty()
 }
 
+/// A ray lying on the line is returned whole, and a ray starting on the line touches it.
 pub fn line_2_ray_2_intersection<T: NumberType>(
     line: &Line2<T>,
     ray: &Ray2<T>,
-) -> Option<Point2<T>> {
+) -> Linear2Intersection<T> {
     let origin = ray.origin();
-    let direction = ray.direction();
-    let target = direction + origin.get_vector();
-    let target = Point2::new(target.x(), target.y());
-    let line_ray = Line2::from_points(&origin, &target);
-    let intersection = line_2_line_2_intersection(&line, &line_ray);
-    match intersection {
-        Some(point) => match locate_point_2_ray_2(&point, ray) {
-            Point2Ray2Location::On => Some(point),
-            _ => None,
-        },
-        None => None,
+    let line_ray = Line2::from_ray(ray);
+    match line_2_line_2_intersection(line, &line_ray) {
+        Linear2Intersection::Point(point, _) if point == origin => {
+            Linear2Intersection::Point(origin, IntersectionContact::Touching)
+        }
+        Linear2Intersection::Point(point, contact) if is_point_2_on_ray_2(&point, ray) => {
+            Linear2Intersection::Point(point, contact)
+        }
+        Linear2Intersection::Line(_) => Linear2Intersection::Ray(*ray),
+        _ => Linear2Intersection::Empty,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::kernel::vector_2::Vector2;
+    use crate::kernel::{point_2::Point2, vector_2::Vector2};
 
     use super::*;
 
     #[test]
     fn test_line_2_ray_2_is_intersected() {
-        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0));
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
         let ray = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
-        assert_eq!(is_line_2_ray_2_intersected(&line, &ray), false);
+        assert_eq!(is_line_2_ray_2_intersected(&line, &ray), true);
 
-        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0));
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
         let ray = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(-1.0, -1.0));
-        assert_eq!(is_line_2_ray_2_intersected(&line, &ray), false);
+        assert_eq!(is_line_2_ray_2_intersected(&line, &ray), true);
 
-        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0));
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
         let ray = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
         assert_eq!(is_line_2_ray_2_intersected(&line, &ray), true);
 
-        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0));
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
         let ray = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(0.0, 1.0));
         assert_eq!(is_line_2_ray_2_intersected(&line, &ray), true);
+
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
+        let ray = Ray2::new(Point2::new(1.0, 0.0), Vector2::new(1.0, 1.0));
+        assert_eq!(is_line_2_ray_2_intersected(&line, &ray), false);
     }
 
     #[test]
     fn test_line_2_ray_2_intersection() {
-        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0));
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
         let ray = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
-        assert_eq!(line_2_ray_2_intersection(&line, &ray), None);
+        assert_eq!(
+            line_2_ray_2_intersection(&line, &ray),
+            Linear2Intersection::Ray(ray)
+        );
 
-        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0));
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
         let ray = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(-1.0, -1.0));
-        assert_eq!(line_2_ray_2_intersection(&line, &ray), None);
+        assert_eq!(
+            line_2_ray_2_intersection(&line, &ray),
+            Linear2Intersection::Ray(ray)
+        );
 
-        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0));
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
         let ray = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
         assert_eq!(
             line_2_ray_2_intersection(&line, &ray),
-            Some(Point2::new(0.0, 0.0))
+            Linear2Intersection::Point(Point2::new(0.0, 0.0), IntersectionContact::Touching)
         );
 
-        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0));
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
         let ray = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(0.0, 1.0));
         assert_eq!(
             line_2_ray_2_intersection(&line, &ray),
-            Some(Point2::new(0.0, 0.0))
+            Linear2Intersection::Point(Point2::new(0.0, 0.0), IntersectionContact::Touching)
+        );
+
+        let line = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 1.0)).unwrap();
+        let ray = Ray2::new(Point2::new(2.0, 0.0), Vector2::new(0.0, 1.0));
+        assert_eq!(
+            line_2_ray_2_intersection(&line, &ray),
+            Linear2Intersection::Point(Point2::new(2.0, 2.0), IntersectionContact::Crossing)
+        );
+
+        let ray = Ray2::new(Point2::new(2.0, 0.0), Vector2::new(0.0, -1.0));
+        assert_eq!(
+            line_2_ray_2_intersection(&line, &ray),
+            Linear2Intersection::Empty
         );
     }
 }
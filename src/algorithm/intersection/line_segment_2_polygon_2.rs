@@ -0,0 +1,159 @@
+use crate::{
+    algorithm::location::{
+        location_enum::Point2Segment2Location,
+        point_2_line_segment_2::locate_point_2_line_segment_2,
+    },
+    kernel::{
+        line_segment_2::LineSegment2, number_type::NumberType, point_2::Point2,
+        polygon_2::Polygon2, segment_2::Segment2,
+    },
+};
+
+use super::segment_2_segment_2::segment_2_segment_2_intersection;
+
+pub fn is_line_segment_2_polygon_2_intersected<T: NumberType>(
+    line_segment: &LineSegment2<T>,
+    polygon: &Polygon2<T>,
+) -> bool {
+    !line_segment_2_polygon_2_intersection(line_segment, polygon).is_empty()
+}
+
+/// Pieces of the segment inside the simple polygon or on its boundary, ordered from the source
+/// and directed like the segment. Points where the segment only touches the polygon become
+/// zero-length pieces.
+pub fn line_segment_2_polygon_2_intersection<T: NumberType>(
+    line_segment: &LineSegment2<T>,
+    polygon: &Polygon2<T>,
+) -> Vec<LineSegment2<T>> {
+    let source = line_segment.source();
+    let target = line_segment.target();
+    if source == target {
+        return if is_point_2_in_polygon_2(&source, polygon) {
+            vec![*line_segment]
+        } else {
+            Vec::new()
+        };
+    }
+    let direction = target - source;
+    let squared_length = direction.dot(&direction);
+    let mut breaks = vec![(T::zero(), source), (T::from_f64(1.0), target)];
+    for edge in polygon.edges() {
+        for point in segment_2_segment_2_intersection(line_segment, &edge).points() {
+            breaks.push(((point - source).dot(&direction) / squared_length, point));
+        }
+    }
+    breaks.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    breaks.dedup_by(|next, previous| next.1 == previous.1);
+
+    let mut pieces = Vec::new();
+    let mut start: Option<Point2<T>> = None;
+    for (index, (_, point)) in breaks.iter().enumerate() {
+        let is_next_inside = breaks.get(index + 1).is_some_and(|(_, next)| {
+            let middle = Point2::new(
+                (point.x() + next.x()) / T::from_f64(2.0),
+                (point.y() + next.y()) / T::from_f64(2.0),
+            );
+            is_point_2_in_polygon_2(&middle, polygon)
+        });
+        match start {
+            Some(first) if !is_next_inside => {
+                pieces.push(LineSegment2::new(first, *point));
+                start = None;
+            }
+            None if is_next_inside => start = Some(*point),
+            None if is_point_2_in_polygon_2(point, polygon) => {
+                pieces.push(LineSegment2::new(*point, *point));
+            }
+            _ => {}
+        }
+    }
+    pieces
+}
+
+/// Crossing-number test for simple polygons of any orientation, true on the boundary.
+pub(crate) fn is_point_2_in_polygon_2<T: NumberType>(
+    point: &Point2<T>,
+    polygon: &Polygon2<T>,
+) -> bool {
+    let mut inside = false;
+    for edge in polygon.edges() {
+        if locate_point_2_line_segment_2(point, &edge) == Point2Segment2Location::On {
+            return true;
+        }
+        let source = edge.source();
+        let target = edge.target();
+        if (source.y() > point.y()) != (target.y() > point.y()) {
+            let x = source.x()
+                + (point.y() - source.y()) * (target.x() - source.x()) / (target.y() - source.y());
+            if point.x() < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_segment_2_polygon_2_intersection() {
+        let polygon = Polygon2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(0.0, 4.0),
+        ]);
+
+        let line_segment = LineSegment2::new(Point2::new(-1.0, 2.0), Point2::new(5.0, 2.0));
+        assert_eq!(
+            line_segment_2_polygon_2_intersection(&line_segment, &polygon),
+            vec![
+                LineSegment2::new(Point2::new(0.0, 2.0), Point2::new(4.0 / 3.0, 2.0)),
+                LineSegment2::new(Point2::new(8.0 / 3.0, 2.0), Point2::new(4.0, 2.0)),
+            ]
+        );
+
+        let line_segment = LineSegment2::new(Point2::new(1.0, 1.0), Point2::new(3.0, 1.0));
+        assert_eq!(
+            line_segment_2_polygon_2_intersection(&line_segment, &polygon),
+            vec![line_segment]
+        );
+
+        let line_segment = LineSegment2::new(Point2::new(5.0, 0.0), Point2::new(-1.0, 0.0));
+        assert_eq!(
+            line_segment_2_polygon_2_intersection(&line_segment, &polygon),
+            vec![LineSegment2::new(
+                Point2::new(4.0, 0.0),
+                Point2::new(0.0, 0.0)
+            )]
+        );
+
+        // Passes the tip of the notch without leaving the polygon.
+        let line_segment = LineSegment2::new(Point2::new(1.0, 0.5), Point2::new(3.0, 1.5));
+        assert_eq!(
+            line_segment_2_polygon_2_intersection(&line_segment, &polygon),
+            vec![line_segment]
+        );
+        let line_segment = LineSegment2::new(Point2::new(1.0, 3.0), Point2::new(3.0, 3.0));
+        assert!(!is_line_segment_2_polygon_2_intersected(
+            &line_segment,
+            &polygon
+        ));
+        let line_segment = LineSegment2::new(Point2::new(5.0, 5.0), Point2::new(3.0, 5.0));
+        assert!(!is_line_segment_2_polygon_2_intersected(
+            &line_segment,
+            &polygon
+        ));
+        let line_segment = LineSegment2::new(Point2::new(3.0, 5.0), Point2::new(5.0, 3.0));
+        assert_eq!(
+            line_segment_2_polygon_2_intersection(&line_segment, &polygon),
+            vec![LineSegment2::new(
+                Point2::new(4.0, 4.0),
+                Point2::new(4.0, 4.0)
+            )]
+        );
+    }
+}
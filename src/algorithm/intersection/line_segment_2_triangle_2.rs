@@ -0,0 +1,77 @@
+use crate::kernel::{
+    line_segment_2::LineSegment2, number_type::NumberType, polygon_2::Polygon2,
+    triangle_2::Triangle2,
+};
+
+use super::line_segment_2_polygon_2::line_segment_2_polygon_2_intersection;
+
+pub fn is_line_segment_2_triangle_2_intersected<T: NumberType>(
+    line_segment: &LineSegment2<T>,
+    triangle: &Triangle2<T>,
+) -> bool {
+    line_segment_2_triangle_2_intersection(line_segment, triangle).is_some()
+}
+
+/// Piece of the segment inside the triangle or on its boundary, directed like the segment and of
+/// zero length where the segment only touches the triangle.
+pub fn line_segment_2_triangle_2_intersection<T: NumberType>(
+    line_segment: &LineSegment2<T>,
+    triangle: &Triangle2<T>,
+) -> Option<LineSegment2<T>> {
+    let polygon = Polygon2::new(triangle.vertices().to_vec());
+    line_segment_2_polygon_2_intersection(line_segment, &polygon)
+        .into_iter()
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::point_2::Point2;
+
+    #[test]
+    fn test_line_segment_2_triangle_2_intersection() {
+        let triangle = Triangle2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 4.0),
+        );
+
+        let line_segment = LineSegment2::new(Point2::new(-1.0, 1.0), Point2::new(5.0, 1.0));
+        assert_eq!(
+            line_segment_2_triangle_2_intersection(&line_segment, &triangle),
+            Some(LineSegment2::new(
+                Point2::new(0.0, 1.0),
+                Point2::new(3.0, 1.0)
+            ))
+        );
+
+        let line_segment = LineSegment2::new(Point2::new(2.0, 5.0), Point2::new(2.0, 1.0));
+        assert_eq!(
+            line_segment_2_triangle_2_intersection(&line_segment, &triangle),
+            Some(LineSegment2::new(
+                Point2::new(2.0, 2.0),
+                Point2::new(2.0, 1.0)
+            ))
+        );
+
+        let line_segment = LineSegment2::new(Point2::new(3.0, 5.0), Point2::new(5.0, 3.0));
+        assert_eq!(
+            line_segment_2_triangle_2_intersection(&line_segment, &triangle),
+            None
+        );
+
+        let line_segment = LineSegment2::new(Point2::new(-1.0, 3.0), Point2::new(1.0, 5.0));
+        assert_eq!(
+            line_segment_2_triangle_2_intersection(&line_segment, &triangle),
+            Some(LineSegment2::new(
+                Point2::new(0.0, 4.0),
+                Point2::new(0.0, 4.0)
+            ))
+        );
+        assert!(is_line_segment_2_triangle_2_intersected(
+            &line_segment,
+            &triangle
+        ));
+    }
+}
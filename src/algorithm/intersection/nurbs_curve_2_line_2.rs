@@ -0,0 +1,106 @@
+use crate::kernel::{
+    line_2::Line2, line_segment_2::LineSegment2, number_type::NumberType,
+    nurbs_curve_2::NurbsCurve2, point_2::Point2,
+};
+
+use super::nurbs_curve_2_line_segment_2::nurbs_curve_2_line_segment_2_intersection;
+
+pub fn is_nurbs_curve_2_line_2_intersected<T: NumberType>(
+    curve: &NurbsCurve2<T>,
+    line: &Line2<T>,
+    tolerance: T,
+) -> bool {
+    !nurbs_curve_2_line_2_intersection(curve, line, tolerance).is_empty()
+}
+
+/// The line is clipped to the control polygon bounds, which contain the curve. A degenerate
+/// line with a = b = 0 meets nothing.
+pub fn nurbs_curve_2_line_2_intersection<T: NumberType>(
+    curve: &NurbsCurve2<T>,
+    line: &Line2<T>,
+    tolerance: T,
+) -> Vec<Point2<T>> {
+    let (min, max) = curve.control_bounds();
+    let a = line.a();
+    let b = line.b();
+    if a.equals(T::zero()) && b.equals(T::zero()) {
+        return Vec::new();
+    }
+    let norm = a * a + b * b;
+    let origin = Point2::new(-a * line.c() / norm, -b * line.c() / norm);
+    let direction = (b, -a);
+    let mut t_min = None;
+    let mut t_max = None;
+    for (start, delta, low, high) in [
+        (
+            origin.x(),
+            direction.0,
+            min.x() - tolerance,
+            max.x() + tolerance,
+        ),
+        (
+            origin.y(),
+            direction.1,
+            min.y() - tolerance,
+            max.y() + tolerance,
+        ),
+    ] {
+        if delta.equals(T::zero()) {
+            if start < low || start > high {
+                return Vec::new();
+            }
+            continue;
+        }
+        let mut t_low = (low - start) / delta;
+        let mut t_high = (high - start) / delta;
+        if t_low > t_high {
+            std::mem::swap(&mut t_low, &mut t_high);
+        }
+        t_min = Some(t_min.map_or(t_low, |t: T| if t_low > t { t_low } else { t }));
+        t_max = Some(t_max.map_or(t_high, |t: T| if t_high < t { t_high } else { t }));
+    }
+    let (t_min, t_max) = (t_min.unwrap(), t_max.unwrap());
+    if t_min > t_max {
+        return Vec::new();
+    }
+    let source = Point2::new(
+        origin.x() + direction.0 * t_min,
+        origin.y() + direction.1 * t_min,
+    );
+    let target = Point2::new(
+        origin.x() + direction.0 * t_max,
+        origin.y() + direction.1 * t_max,
+    );
+    nurbs_curve_2_line_segment_2_intersection(curve, &LineSegment2::new(source, target), tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::circle_segment_2::CircleSegment2;
+
+    use super::*;
+
+    #[test]
+    fn test_nurbs_curve_2_line_2_intersection() {
+        let circle =
+            NurbsCurve2::from_circle_segment(&CircleSegment2::new(Point2::new(0.0, 0.0), 5.0));
+        let line = Line2::new(0.0, 1.0, -3.0);
+        let result = nurbs_curve_2_line_2_intersection(&circle, &line, 1e-9);
+        assert_eq!(result.len(), 2);
+        assert!(result[0].distance(&Point2::new(4.0, 3.0)) < 1e-8);
+        assert!(result[1].distance(&Point2::new(-4.0, 3.0)) < 1e-8);
+
+        let line = Line2::new(1.0, 0.0, -5.0);
+        let result = nurbs_curve_2_line_2_intersection(&circle, &line, 1e-9);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].distance(&Point2::new(5.0, 0.0)) < 1e-4);
+
+        let line = Line2::new(1.0, 0.0, -6.0);
+        assert!(!is_nurbs_curve_2_line_2_intersected(&circle, &line, 1e-9));
+
+        let line = Line2::new(0.0, 0.0, 0.0);
+        assert!(nurbs_curve_2_line_2_intersection(&circle, &line, 1e-9).is_empty());
+        let line = Line2::new(0.0, 0.0, 1.0);
+        assert!(!is_nurbs_curve_2_line_2_intersected(&circle, &line, 1e-9));
+    }
+}
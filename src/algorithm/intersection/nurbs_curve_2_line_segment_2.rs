@@ -0,0 +1,61 @@
+use crate::kernel::{
+    line_segment_2::LineSegment2, number_type::NumberType, nurbs_curve_2::NurbsCurve2,
+    point_2::Point2, segment_2::Segment2,
+};
+
+use super::nurbs_curve_2_nurbs_curve_2::nurbs_curve_2_nurbs_curve_2_intersection;
+
+pub fn is_nurbs_curve_2_line_segment_2_intersected<T: NumberType>(
+    curve: &NurbsCurve2<T>,
+    line_segment: &LineSegment2<T>,
+    tolerance: T,
+) -> bool {
+    !nurbs_curve_2_line_segment_2_intersection(curve, line_segment, tolerance).is_empty()
+}
+
+pub fn nurbs_curve_2_line_segment_2_intersection<T: NumberType>(
+    curve: &NurbsCurve2<T>,
+    line_segment: &LineSegment2<T>,
+    tolerance: T,
+) -> Vec<Point2<T>> {
+    let line_curve = NurbsCurve2::bspline(
+        1,
+        vec![line_segment.source(), line_segment.target()],
+        vec![T::zero(), T::zero(), T::from_f64(1.0), T::from_f64(1.0)],
+    )
+    .unwrap();
+    nurbs_curve_2_nurbs_curve_2_intersection(curve, &line_curve, tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::kernel::{arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2};
+
+    use super::*;
+
+    #[test]
+    fn test_nurbs_curve_2_line_segment_2_intersection() {
+        let arc = NurbsCurve2::from_arc_segment(&ArcSegment2::new(
+            CircleSegment2::new(Point2::new(0.0, 0.0), 5.0),
+            0.0,
+            PI,
+        ));
+        let segment = LineSegment2::new(Point2::new(-10.0, 3.0), Point2::new(10.0, 3.0));
+        let result = nurbs_curve_2_line_segment_2_intersection(&arc, &segment, 1e-9);
+        assert_eq!(result.len(), 2);
+        assert!(result[0].distance(&Point2::new(4.0, 3.0)) < 1e-8);
+        assert!(result[1].distance(&Point2::new(-4.0, 3.0)) < 1e-8);
+
+        let segment = LineSegment2::new(Point2::new(0.0, 3.0), Point2::new(10.0, 3.0));
+        let result = nurbs_curve_2_line_segment_2_intersection(&arc, &segment, 1e-9);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].distance(&Point2::new(4.0, 3.0)) < 1e-8);
+
+        let segment = LineSegment2::new(Point2::new(-10.0, -3.0), Point2::new(10.0, -3.0));
+        assert!(!is_nurbs_curve_2_line_segment_2_intersected(
+            &arc, &segment, 1e-9
+        ));
+    }
+}
@@ -0,0 +1,301 @@
+use crate::kernel::{
+    line_segment_2::LineSegment2, number_type::NumberType, nurbs_curve_2::NurbsCurve2,
+    point_2::Point2, segment_2::Segment2,
+};
+
+use super::segment_2_segment_2::segment_2_segment_2_intersection;
+
+const MAX_SUBDIVISION_DEPTH: usize = 48;
+const CONTACT_SAMPLES: usize = 8;
+const CONTACT_SLACK: f64 = 8.0;
+
+#[derive(Debug, Clone, Copy)]
+struct Hit<T: NumberType> {
+    point: Point2<T>,
+    distance: T,
+    parameter_a: T,
+    parameter_b: T,
+}
+
+pub fn is_nurbs_curve_2_nurbs_curve_2_intersected<T: NumberType>(
+    curve_a: &NurbsCurve2<T>,
+    curve_b: &NurbsCurve2<T>,
+    tolerance: T,
+) -> bool {
+    !nurbs_curve_2_nurbs_curve_2_intersection(curve_a, curve_b, tolerance).is_empty()
+}
+
+/// Subdivides both curves until the pieces are flat within `tolerance` and intersects their chords.
+///
+/// Hits whose curves stay within `tolerance` of each other in between (e.g. a tangency) are merged, the result is ordered along `curve_a`.
+pub fn nurbs_curve_2_nurbs_curve_2_intersection<T: NumberType>(
+    curve_a: &NurbsCurve2<T>,
+    curve_b: &NurbsCurve2<T>,
+    tolerance: T,
+) -> Vec<Point2<T>> {
+    let segments_b = curve_b.bezier_segments();
+    let mut hits = Vec::new();
+    for segment_a in curve_a.bezier_segments() {
+        for segment_b in &segments_b {
+            subdivide(&segment_a, segment_b, tolerance, 0, &mut hits);
+        }
+    }
+    merge_hits(curve_a, curve_b, hits, tolerance)
+}
+
+fn subdivide<T: NumberType>(
+    curve_a: &NurbsCurve2<T>,
+    curve_b: &NurbsCurve2<T>,
+    tolerance: T,
+    depth: usize,
+    hits: &mut Vec<Hit<T>>,
+) {
+    let (min_a, max_a) = curve_a.control_bounds();
+    let (min_b, max_b) = curve_b.control_bounds();
+    if min_a.x() > max_b.x() + tolerance
+        || min_b.x() > max_a.x() + tolerance
+        || min_a.y() > max_b.y() + tolerance
+        || min_b.y() > max_a.y() + tolerance
+    {
+        return;
+    }
+    let flat_a = curve_a.is_flat(tolerance);
+    let flat_b = curve_b.is_flat(tolerance);
+    if (flat_a && flat_b) || depth >= MAX_SUBDIVISION_DEPTH {
+        intersect_chords(curve_a, curve_b, tolerance, hits);
+        return;
+    }
+    let split_a = !flat_a && (flat_b || min_a.distance(&max_a) >= min_b.distance(&max_b));
+    if split_a {
+        let (left, right) = halve(curve_a);
+        subdivide(&left, curve_b, tolerance, depth + 1, hits);
+        subdivide(&right, curve_b, tolerance, depth + 1, hits);
+    } else {
+        let (left, right) = halve(curve_b);
+        subdivide(curve_a, &left, tolerance, depth + 1, hits);
+        subdivide(curve_a, &right, tolerance, depth + 1, hits);
+    }
+}
+
+fn halve<T: NumberType>(curve: &NurbsCurve2<T>) -> (NurbsCurve2<T>, NurbsCurve2<T>) {
+    let (start, end) = curve.domain();
+    curve.split_at((start + end) / T::from_f64(2.0)).unwrap()
+}
+
+fn intersect_chords<T: NumberType>(
+    curve_a: &NurbsCurve2<T>,
+    curve_b: &NurbsCurve2<T>,
+    tolerance: T,
+    hits: &mut Vec<Hit<T>>,
+) {
+    let chord_a = LineSegment2::new(curve_a.source(), curve_a.target());
+    let chord_b = LineSegment2::new(curve_b.source(), curve_b.target());
+    let points = segment_2_segment_2_intersection(&chord_a, &chord_b).points();
+    if !points.is_empty() {
+        for point in points {
+            hits.push(Hit {
+                point,
+                distance: T::zero(),
+                parameter_a: chord_parameter(curve_a, &point),
+                parameter_b: chord_parameter(curve_b, &point),
+            });
+        }
+        return;
+    }
+    let candidates = [
+        (curve_a.source(), closest_point(&chord_b, &curve_a.source())),
+        (curve_a.target(), closest_point(&chord_b, &curve_a.target())),
+        (closest_point(&chord_a, &curve_b.source()), curve_b.source()),
+        (closest_point(&chord_a, &curve_b.target()), curve_b.target()),
+    ];
+    let mut nearest = candidates[0];
+    for candidate in candidates.iter().skip(1) {
+        if candidate.0.distance(&candidate.1) < nearest.0.distance(&nearest.1) {
+            nearest = *candidate;
+        }
+    }
+    let distance = nearest.0.distance(&nearest.1);
+    if distance <= tolerance {
+        let point = (nearest.0 + nearest.1) * T::from_f64(0.5);
+        let point = Point2::new(point.x(), point.y());
+        hits.push(Hit {
+            point,
+            distance,
+            parameter_a: chord_parameter(curve_a, &point),
+            parameter_b: chord_parameter(curve_b, &point),
+        });
+    }
+}
+
+fn chord_fraction<T: NumberType>(chord: &LineSegment2<T>, point: &Point2<T>) -> T {
+    let source = chord.source();
+    let direction = chord.target() - source;
+    let length = direction.dot(&direction);
+    if length.equals(T::zero()) {
+        return T::zero();
+    }
+    let t = direction.dot(&(point.get_vector() - source.get_vector())) / length;
+    if t < T::zero() {
+        T::zero()
+    } else if t > T::from_f64(1.0) {
+        T::from_f64(1.0)
+    } else {
+        t
+    }
+}
+
+fn closest_point<T: NumberType>(chord: &LineSegment2<T>, point: &Point2<T>) -> Point2<T> {
+    let t = chord_fraction(chord, point);
+    let closest = chord.source().get_vector() + (chord.target() - chord.source()) * t;
+    Point2::new(closest.x(), closest.y())
+}
+
+fn chord_parameter<T: NumberType>(curve: &NurbsCurve2<T>, point: &Point2<T>) -> T {
+    let (start, end) = curve.domain();
+    let chord = LineSegment2::new(curve.source(), curve.target());
+    start + (end - start) * chord_fraction(&chord, point)
+}
+
+fn is_same_contact<T: NumberType>(
+    curve_a: &NurbsCurve2<T>,
+    curve_b: &NurbsCurve2<T>,
+    hit_i: &Hit<T>,
+    hit_j: &Hit<T>,
+    tolerance: T,
+) -> bool {
+    if hit_i.point.distance(&hit_j.point) <= tolerance {
+        return true;
+    }
+    let chord = LineSegment2::new(hit_i.point, hit_j.point);
+    let limit = tolerance * T::from_f64(CONTACT_SLACK);
+    (1..CONTACT_SAMPLES).all(|k| {
+        let alpha = T::from_f64(k as f64 / CONTACT_SAMPLES as f64);
+        let point_a = curve_a.point_at(interpolate_parameter(
+            curve_a,
+            hit_i.parameter_a,
+            hit_j.parameter_a,
+            alpha,
+        ));
+        let point_b = curve_b.point_at(interpolate_parameter(
+            curve_b,
+            hit_i.parameter_b,
+            hit_j.parameter_b,
+            alpha,
+        ));
+        closest_point(&chord, &point_a).distance(&point_a) <= limit
+            && closest_point(&chord, &point_b).distance(&point_b) <= limit
+    })
+}
+
+/// On a closed curve the shorter way around the seam is taken.
+fn interpolate_parameter<T: NumberType>(curve: &NurbsCurve2<T>, from: T, to: T, alpha: T) -> T {
+    let (start, end) = curve.domain();
+    let period = end - start;
+    let mut to = to;
+    if curve.source() == curve.target() && (to - from).abs() > period / T::from_f64(2.0) {
+        to = if to > from { to - period } else { to + period };
+    }
+    let mut parameter = from + (to - from) * alpha;
+    if parameter < start {
+        parameter = parameter + period;
+    } else if parameter > end {
+        parameter = parameter - period;
+    }
+    parameter
+}
+
+fn merge_hits<T: NumberType>(
+    curve_a: &NurbsCurve2<T>,
+    curve_b: &NurbsCurve2<T>,
+    hits: Vec<Hit<T>>,
+    tolerance: T,
+) -> Vec<Point2<T>> {
+    let mut parents: Vec<usize> = (0..hits.len()).collect();
+    fn find(parents: &mut [usize], index: usize) -> usize {
+        let mut root = index;
+        while parents[root] != root {
+            root = parents[root];
+        }
+        parents[index] = root;
+        root
+    }
+    for i in 0..hits.len() {
+        for j in i + 1..hits.len() {
+            let root_i = find(&mut parents, i);
+            let root_j = find(&mut parents, j);
+            if root_i != root_j && is_same_contact(curve_a, curve_b, &hits[i], &hits[j], tolerance)
+            {
+                parents[root_j] = root_i;
+            }
+        }
+    }
+    let mut best: Vec<Option<Hit<T>>> = vec![None; hits.len()];
+    for (index, hit) in hits.iter().enumerate() {
+        let root = find(&mut parents, index);
+        match best[root] {
+            Some(current) if current.distance <= hit.distance => {}
+            _ => best[root] = Some(*hit),
+        }
+    }
+    let mut result: Vec<Hit<T>> = best.into_iter().flatten().collect();
+    result.sort_by(|a, b| a.parameter_a.partial_cmp(&b.parameter_a).unwrap());
+    result.into_iter().map(|hit| hit.point).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::circle_segment_2::CircleSegment2;
+
+    use super::*;
+
+    #[test]
+    fn test_nurbs_curve_2_nurbs_curve_2_intersection() {
+        let circle_a =
+            NurbsCurve2::from_circle_segment(&CircleSegment2::new(Point2::new(0.0, 0.0), 5.0));
+        let circle_b =
+            NurbsCurve2::from_circle_segment(&CircleSegment2::new(Point2::new(6.0, 0.0), 4.0));
+        let result = nurbs_curve_2_nurbs_curve_2_intersection(&circle_a, &circle_b, 1e-9);
+        assert_eq!(result.len(), 2);
+        assert!(result[0].distance(&Point2::new(3.75, 3.307189138830738)) < 1e-8);
+        assert!(result[1].distance(&Point2::new(3.75, -3.307189138830738)) < 1e-8);
+
+        let circle_b =
+            NurbsCurve2::from_circle_segment(&CircleSegment2::new(Point2::new(8.0, 0.0), 3.0));
+        let result = nurbs_curve_2_nurbs_curve_2_intersection(&circle_a, &circle_b, 1e-9);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].distance(&Point2::new(5.0, 0.0)) < 1e-4);
+
+        let circle_b =
+            NurbsCurve2::from_circle_segment(&CircleSegment2::new(Point2::new(20.0, 0.0), 3.0));
+        assert!(!is_nurbs_curve_2_nurbs_curve_2_intersected(
+            &circle_a, &circle_b, 1e-9
+        ));
+
+        let wave = NurbsCurve2::bspline(
+            1,
+            vec![
+                Point2::new(0.0, -1.0),
+                Point2::new(1.0, 1.0),
+                Point2::new(2.0, -1.0),
+                Point2::new(3.0, 1.0),
+            ],
+            vec![0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let axis = NurbsCurve2::bspline(
+            1,
+            vec![Point2::new(-1.0, 0.0), Point2::new(4.0, 0.0)],
+            vec![0.0, 0.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let result = nurbs_curve_2_nurbs_curve_2_intersection(&wave, &axis, 1e-9);
+        assert_eq!(
+            result,
+            vec![
+                Point2::new(0.5, 0.0),
+                Point2::new(1.5, 0.0),
+                Point2::new(2.5, 0.0)
+            ]
+        );
+    }
+}
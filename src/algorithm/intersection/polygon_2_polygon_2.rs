@@ -0,0 +1,190 @@
+use crate::kernel::{
+    bbox_2::BBox2, number_type::NumberType, point_2::Point2, polygon_2::Polygon2, vector_2::Vector2,
+};
+
+use super::{
+    line_segment_2_polygon_2::is_point_2_in_polygon_2,
+    red_blue_segment_2_intersection::RedBlueSegment2Intersection,
+};
+
+/// Separating-axis test for convex polygons; polygons touching at the boundary intersect.
+pub fn is_convex_polygon_2_convex_polygon_2_intersected<T: NumberType>(
+    polygon_a: &Polygon2<T>,
+    polygon_b: &Polygon2<T>,
+) -> bool {
+    !has_separating_axis(&polygon_a.vertices(), &polygon_b.vertices())
+}
+
+/// Simple polygons of any shape intersect when their edges meet or one lies inside the other.
+pub fn is_polygon_2_polygon_2_intersected<T: NumberType>(
+    polygon_a: &Polygon2<T>,
+    polygon_b: &Polygon2<T>,
+) -> bool {
+    let vertices_a = polygon_a.vertices();
+    let vertices_b = polygon_b.vertices();
+    match (
+        BBox2::from_points(&vertices_a),
+        BBox2::from_points(&vertices_b),
+    ) {
+        (Some(bbox_a), Some(bbox_b)) if bbox_a.intersects(&bbox_b) => {}
+        _ => return false,
+    }
+    let mut sweep = RedBlueSegment2Intersection::new();
+    for edge in polygon_a.edges() {
+        sweep.push_red(&edge);
+    }
+    for edge in polygon_b.edges() {
+        sweep.push_blue(&edge);
+    }
+    sweep.has_intersection()
+        || is_point_2_in_polygon_2(&vertices_a[0], polygon_b)
+        || is_point_2_in_polygon_2(&vertices_b[0], polygon_a)
+}
+
+/// Whether the convex hulls of the two point sets project to disjoint intervals on the x or y
+/// axis or on the normal of an edge of either.
+pub(crate) fn has_separating_axis<T: NumberType>(
+    vertices_a: &[Point2<T>],
+    vertices_b: &[Point2<T>],
+) -> bool {
+    let mut axes = vec![
+        Vector2::new(T::from_f64(1.0), T::zero()),
+        Vector2::new(T::zero(), T::from_f64(1.0)),
+    ];
+    for vertices in [vertices_a, vertices_b] {
+        for (source, target) in vertices.iter().zip(vertices.iter().cycle().skip(1)) {
+            let direction = *target - *source;
+            if !direction.length().equals(T::zero()) {
+                let direction = direction.normalize();
+                axes.push(Vector2::new(-direction.y(), direction.x()));
+            }
+        }
+    }
+    axes.iter().any(|axis| {
+        let (min_a, max_a) = project(vertices_a, axis);
+        let (min_b, max_b) = project(vertices_b, axis);
+        max_a < min_b - T::default_eps() || max_b < min_a - T::default_eps()
+    })
+}
+
+fn project<T: NumberType>(vertices: &[Point2<T>], axis: &Vector2<T>) -> (T, T) {
+    let first = vertices[0].get_vector().dot(axis);
+    vertices[1..]
+        .iter()
+        .map(|vertex| vertex.get_vector().dot(axis))
+        .fold((first, first), |(min, max), value| {
+            (
+                if value < min { value } else { min },
+                if value > max { value } else { max },
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon2<f64> {
+        Polygon2::new(vec![
+            Point2::new(x, y),
+            Point2::new(x + size, y),
+            Point2::new(x + size, y + size),
+            Point2::new(x, y + size),
+        ])
+    }
+
+    #[test]
+    fn test_is_convex_polygon_2_convex_polygon_2_intersected() {
+        let polygon = square(0.0, 0.0, 2.0);
+        assert!(is_convex_polygon_2_convex_polygon_2_intersected(
+            &polygon,
+            &square(1.0, 1.0, 2.0)
+        ));
+        assert!(is_convex_polygon_2_convex_polygon_2_intersected(
+            &polygon,
+            &square(2.0, 0.5, 1.0)
+        ));
+        assert!(is_convex_polygon_2_convex_polygon_2_intersected(
+            &polygon,
+            &square(0.5, 0.5, 1.0)
+        ));
+        assert!(!is_convex_polygon_2_convex_polygon_2_intersected(
+            &polygon,
+            &square(2.5, 0.0, 1.0)
+        ));
+
+        // Only the diagonal edge separates them.
+        let diamond = Polygon2::new(vec![
+            Point2::new(2.5, 1.0),
+            Point2::new(3.5, 2.0),
+            Point2::new(2.5, 3.0),
+            Point2::new(1.5, 2.0),
+        ]);
+        let triangle = Polygon2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(0.0, 2.0),
+        ]);
+        assert!(is_convex_polygon_2_convex_polygon_2_intersected(
+            &polygon, &diamond
+        ));
+        assert!(!is_convex_polygon_2_convex_polygon_2_intersected(
+            &triangle, &diamond
+        ));
+    }
+
+    #[test]
+    fn test_is_polygon_2_polygon_2_intersected() {
+        let comb = Polygon2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(6.0, 0.0),
+            Point2::new(6.0, 4.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(4.0, 1.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(2.0, 4.0),
+            Point2::new(0.0, 4.0),
+        ]);
+        let in_gap = square(2.5, 2.0, 1.0);
+        assert!(!is_polygon_2_polygon_2_intersected(&comb, &in_gap));
+        assert!(is_convex_polygon_2_convex_polygon_2_intersected(
+            &square(0.0, 0.0, 6.0),
+            &in_gap
+        ));
+        assert!(is_polygon_2_polygon_2_intersected(
+            &comb,
+            &square(2.5, 0.5, 1.0)
+        ));
+        assert!(is_polygon_2_polygon_2_intersected(
+            &comb,
+            &square(2.0, 2.0, 1.0)
+        ));
+        assert!(is_polygon_2_polygon_2_intersected(
+            &comb,
+            &square(0.5, 2.0, 1.0)
+        ));
+        assert!(is_polygon_2_polygon_2_intersected(
+            &square(-1.0, -1.0, 8.0),
+            &comb
+        ));
+        assert!(!is_polygon_2_polygon_2_intersected(
+            &comb,
+            &square(7.0, 0.0, 1.0)
+        ));
+
+        let bowtie = Polygon2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 4.0),
+        ]);
+        assert!(is_polygon_2_polygon_2_intersected(
+            &bowtie,
+            &square(3.5, 1.5, 1.0)
+        ));
+        assert!(!is_polygon_2_polygon_2_intersected(
+            &bowtie,
+            &square(1.8, 0.2, 0.4)
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    algorithm::location::point_2_arc_segment_2::is_point_2_on_arc_segment_2,
+    kernel::{arc_segment_2::ArcSegment2, number_type::NumberType, ray_2::Ray2},
+};
+
+use super::{
+    intersection_enum::Linear2Intersection, line_2_arc_segment_2::end_contact,
+    ray_2_circle_segment_2::ray_circle_points,
+};
+
+pub fn is_ray_2_arc_segment_2_intersected<T: NumberType>(
+    ray: &Ray2<T>,
+    arc_segment: &ArcSegment2<T>,
+) -> bool {
+    !ray_2_arc_segment_2_intersection(ray, arc_segment).is_empty()
+}
+
+pub fn ray_2_arc_segment_2_intersection<T: NumberType>(
+    ray: &Ray2<T>,
+    arc_segment: &ArcSegment2<T>,
+) -> Linear2Intersection<T> {
+    let points: Vec<_> = ray_circle_points(ray, arc_segment)
+        .into_iter()
+        .filter(|(point, _)| is_point_2_on_arc_segment_2(point, arc_segment))
+        .map(|(point, contact)| (point, end_contact(arc_segment, &point, contact)))
+        .collect();
+    Linear2Intersection::from_points(&points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::intersection::intersection_enum::IntersectionContact,
+        kernel::{circle_segment_2::CircleSegment2, point_2::Point2, vector_2::Vector2},
+    };
+
+    #[test]
+    fn test_ray_2_arc_segment_2_intersection() {
+        let arc_segment = ArcSegment2::new(
+            CircleSegment2::new(Point2::new(0.0, 0.0), 1.0),
+            std::f64::consts::PI / 2.0,
+            std::f64::consts::PI * 1.5,
+        );
+
+        let ray = Ray2::new(Point2::new(2.0, 0.0), Vector2::new(-1.0, 0.0));
+        assert_eq!(
+            ray_2_arc_segment_2_intersection(&ray, &arc_segment),
+            Linear2Intersection::Point(Point2::new(-1.0, 0.0), IntersectionContact::Crossing)
+        );
+
+        let ray = Ray2::new(Point2::new(-2.0, 0.5), Vector2::new(1.0, 0.0));
+        assert_eq!(
+            ray_2_arc_segment_2_intersection(&ray, &arc_segment),
+            Linear2Intersection::Point(
+                Point2::new(-0.75_f64.sqrt(), 0.5),
+                IntersectionContact::Crossing
+            )
+        );
+
+        let ray = Ray2::new(Point2::new(-1.0, 2.0), Vector2::new(0.0, -1.0));
+        assert_eq!(
+            ray_2_arc_segment_2_intersection(&ray, &arc_segment),
+            Linear2Intersection::Point(Point2::new(-1.0, 0.0), IntersectionContact::Tangent)
+        );
+        assert!(is_ray_2_arc_segment_2_intersected(&ray, &arc_segment));
+
+        let ray = Ray2::new(Point2::new(2.0, 1.0), Vector2::new(-1.0, 0.0));
+        assert_eq!(
+            ray_2_arc_segment_2_intersection(&ray, &arc_segment),
+            Linear2Intersection::Point(Point2::new(0.0, 1.0), IntersectionContact::Touching)
+        );
+
+        let ray = Ray2::new(Point2::new(-0.5, 0.0), Vector2::new(1.0, 0.0));
+        assert!(!is_ray_2_arc_segment_2_intersected(&ray, &arc_segment));
+    }
+}
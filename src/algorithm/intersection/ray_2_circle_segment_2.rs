@@ -0,0 +1,86 @@
+use crate::kernel::{
+    circle_segment_2::CircleSegment2, number_type::NumberType, point_2::Point2, ray_2::Ray2,
+    segment_2::Segment2,
+};
+
+use super::{
+    intersection_enum::{IntersectionContact, Linear2Intersection},
+    line_2_circle_segment_2::line_circle_points,
+};
+
+pub fn is_ray_2_circle_segment_2_intersected<T: NumberType>(
+    ray: &Ray2<T>,
+    circle_segment: &CircleSegment2<T>,
+) -> bool {
+    !ray_2_circle_segment_2_intersection(ray, circle_segment).is_empty()
+}
+
+pub fn ray_2_circle_segment_2_intersection<T: NumberType>(
+    ray: &Ray2<T>,
+    circle_segment: &CircleSegment2<T>,
+) -> Linear2Intersection<T> {
+    Linear2Intersection::from_points(&ray_circle_points(ray, circle_segment))
+}
+
+/// Points of the line through the ray that the ray reaches, touching at its origin.
+pub(crate) fn ray_circle_points<T: NumberType>(
+    ray: &Ray2<T>,
+    circle_segment: &impl Segment2<T>,
+) -> Vec<(Point2<T>, IntersectionContact)> {
+    line_circle_points(&ray.origin(), &ray.direction(), circle_segment)
+        .into_iter()
+        .filter(|(t, point, _)| *t > T::zero() || *point == ray.origin())
+        .map(|(_, point, contact)| {
+            if point == ray.origin() {
+                (point, IntersectionContact::Touching)
+            } else {
+                (point, contact)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::vector_2::Vector2;
+
+    #[test]
+    fn test_ray_2_circle_segment_2_intersection() {
+        let circle_segment = CircleSegment2::new(Point2::new(0.0, 0.0), 1.0);
+
+        let ray = Ray2::new(Point2::new(-2.0, 0.0), Vector2::new(1.0, 0.0));
+        assert_eq!(
+            ray_2_circle_segment_2_intersection(&ray, &circle_segment),
+            Linear2Intersection::TwoPoints(Point2::new(-1.0, 0.0), Point2::new(1.0, 0.0))
+        );
+
+        let ray = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(0.0, -1.0));
+        assert_eq!(
+            ray_2_circle_segment_2_intersection(&ray, &circle_segment),
+            Linear2Intersection::Point(Point2::new(0.0, -1.0), IntersectionContact::Crossing)
+        );
+
+        let ray = Ray2::new(Point2::new(-1.0, 1.0), Vector2::new(1.0, 0.0));
+        assert_eq!(
+            ray_2_circle_segment_2_intersection(&ray, &circle_segment),
+            Linear2Intersection::Point(Point2::new(0.0, 1.0), IntersectionContact::Tangent)
+        );
+        assert!(is_ray_2_circle_segment_2_intersected(&ray, &circle_segment));
+
+        let ray = Ray2::new(Point2::new(1.0, 1.0), Vector2::new(1.0, 0.0));
+        assert!(!is_ray_2_circle_segment_2_intersected(
+            &ray,
+            &circle_segment
+        ));
+
+        let ray = Ray2::new(Point2::new(1.0, 0.0), Vector2::new(1.0, 0.0));
+        assert_eq!(
+            ray_2_circle_segment_2_intersection(&ray, &circle_segment),
+            Linear2Intersection::Point(Point2::new(1.0, 0.0), IntersectionContact::Touching)
+        );
+
+        let ray = Ray2::new(Point2::new(2.0, 0.0), Vector2::new(1.0, 0.0));
+        assert!(ray_2_circle_segment_2_intersection(&ray, &circle_segment).is_empty());
+    }
+}
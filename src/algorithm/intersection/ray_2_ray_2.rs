@@ -0,0 +1,114 @@
+use crate::{
+    algorithm::location::point_2_ray_2::is_point_2_on_ray_2,
+    kernel::{line_segment_2::LineSegment2, number_type::NumberType, point_2::Point2, ray_2::Ray2},
+};
+
+use super::intersection_enum::{IntersectionContact, Linear2Intersection};
+
+pub fn is_ray_2_ray_2_intersected<T: NumberType>(ray_a: &Ray2<T>, ray_b: &Ray2<T>) -> bool {
+    !ray_2_ray_2_intersection(ray_a, ray_b).is_empty()
+}
+
+/// Collinear rays overlap in the ray ahead when they point the same way, and in the segment
+/// between their origins, directed like `ray_a`, when they face each other.
+pub fn ray_2_ray_2_intersection<T: NumberType>(
+    ray_a: &Ray2<T>,
+    ray_b: &Ray2<T>,
+) -> Linear2Intersection<T> {
+    let origin_a = ray_a.origin();
+    let origin_b = ray_b.origin();
+    let direction_a = ray_a.direction();
+    let direction_b = ray_b.direction();
+    let det = direction_a.cross(&direction_b);
+    if det.equals(T::zero()) {
+        if !direction_a.cross(&(origin_b - origin_a)).equals(T::zero()) {
+            return Linear2Intersection::Empty;
+        }
+        if direction_a.dot(&direction_b) > T::zero() {
+            return if is_point_2_on_ray_2(&origin_b, ray_a) {
+                Linear2Intersection::Ray(*ray_b)
+            } else {
+                Linear2Intersection::Ray(*ray_a)
+            };
+        }
+        return if origin_a == origin_b {
+            Linear2Intersection::Point(origin_a, IntersectionContact::Touching)
+        } else if is_point_2_on_ray_2(&origin_b, ray_a) {
+            Linear2Intersection::Segment(LineSegment2::new(origin_a, origin_b))
+        } else {
+            Linear2Intersection::Empty
+        };
+    }
+    let t = (origin_b - origin_a).cross(&direction_b) / det;
+    let point = Point2::new(
+        origin_a.x() + direction_a.x() * t,
+        origin_a.y() + direction_a.y() * t,
+    );
+    if !is_point_2_on_ray_2(&point, ray_a) || !is_point_2_on_ray_2(&point, ray_b) {
+        return Linear2Intersection::Empty;
+    }
+    let contact = if point == origin_a || point == origin_b {
+        IntersectionContact::Touching
+    } else {
+        IntersectionContact::Crossing
+    };
+    Linear2Intersection::Point(point, contact)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::vector_2::Vector2;
+
+    #[test]
+    fn test_ray_2_ray_2_intersection() {
+        let ray_a = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+
+        let ray_b = Ray2::new(Point2::new(2.0, 0.0), Vector2::new(-1.0, 1.0));
+        assert_eq!(
+            ray_2_ray_2_intersection(&ray_a, &ray_b),
+            Linear2Intersection::Point(Point2::new(1.0, 1.0), IntersectionContact::Crossing)
+        );
+        assert!(is_ray_2_ray_2_intersected(&ray_a, &ray_b));
+
+        let ray_b = Ray2::new(Point2::new(2.0, 0.0), Vector2::new(1.0, -1.0));
+        assert!(!is_ray_2_ray_2_intersected(&ray_a, &ray_b));
+
+        let ray_b = Ray2::new(Point2::new(2.0, 2.0), Vector2::new(2.0, 2.0));
+        assert_eq!(
+            ray_2_ray_2_intersection(&ray_a, &ray_b),
+            Linear2Intersection::Ray(ray_b)
+        );
+        assert_eq!(
+            ray_2_ray_2_intersection(&ray_b, &ray_a),
+            Linear2Intersection::Ray(ray_b)
+        );
+
+        let ray_b = Ray2::new(Point2::new(2.0, 2.0), Vector2::new(-1.0, -1.0));
+        assert_eq!(
+            ray_2_ray_2_intersection(&ray_a, &ray_b),
+            Linear2Intersection::Segment(LineSegment2::new(
+                Point2::new(0.0, 0.0),
+                Point2::new(2.0, 2.0)
+            ))
+        );
+
+        let ray_b = Ray2::new(Point2::new(0.0, 0.0), Vector2::new(-1.0, -1.0));
+        assert_eq!(
+            ray_2_ray_2_intersection(&ray_a, &ray_b),
+            Linear2Intersection::Point(Point2::new(0.0, 0.0), IntersectionContact::Touching)
+        );
+
+        let ray_b = Ray2::new(Point2::new(1.0, 1.0), Vector2::new(1.0, -1.0));
+        assert_eq!(
+            ray_2_ray_2_intersection(&ray_a, &ray_b),
+            Linear2Intersection::Point(Point2::new(1.0, 1.0), IntersectionContact::Touching)
+        );
+
+        let ray_b = Ray2::new(Point2::new(-1.0, -1.0), Vector2::new(-1.0, -1.0));
+        assert!(!is_ray_2_ray_2_intersected(&ray_a, &ray_b));
+
+        let ray_b = Ray2::new(Point2::new(0.0, 1.0), Vector2::new(1.0, 1.0));
+        assert!(!is_ray_2_ray_2_intersected(&ray_a, &ray_b));
+    }
+}
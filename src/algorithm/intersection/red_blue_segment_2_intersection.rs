@@ -0,0 +1,240 @@
+use crate::kernel::{number_type::NumberType, point_2::Point2, segment_2::Segment2};
+
+use super::sweep_segment_2_intersection::SweepSegment2Intersection;
+
+/// Point where red and blue segments meet, with the indices, in push order within each set, of
+/// every red and every blue segment through it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedBlueIntersection2<T: NumberType> {
+    point: Point2<T>,
+    red_indices: Vec<usize>,
+    blue_indices: Vec<usize>,
+}
+
+impl<T: NumberType> RedBlueIntersection2<T> {
+    pub fn point(&self) -> Point2<T> {
+        self.point
+    }
+
+    /// Sorted and distinct.
+    pub fn red_indices(&self) -> Vec<usize> {
+        self.red_indices.clone()
+    }
+
+    /// Sorted and distinct.
+    pub fn blue_indices(&self) -> Vec<usize> {
+        self.blue_indices.clone()
+    }
+
+    /// Every `(red, blue)` index pair meeting at the point.
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        self.red_indices
+            .iter()
+            .flat_map(|red| self.blue_indices.iter().map(move |blue| (*red, *blue)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Color {
+    Red(usize),
+    Blue(usize),
+}
+
+/// Sweep between a red and a blue set of line, circle and arc segments that reports only
+/// meetings across the sets. Meetings within one set still move the sweep along but are never
+/// stored, so when neither set crosses itself, as with walls or polygon edges that only share
+/// endpoints, the sweep runs in O((n + k) log n) for k red–blue points; every meeting within a
+/// set, such as pipes crossing pipes, adds O(log n).
+pub struct RedBlueSegment2Intersection<T: NumberType> {
+    sweep: SweepSegment2Intersection<T>,
+    colors: Vec<Color>,
+    red_count: usize,
+    blue_count: usize,
+}
+
+impl<T: NumberType> RedBlueSegment2Intersection<T> {
+    pub fn new() -> Self {
+        Self {
+            sweep: SweepSegment2Intersection::new(),
+            colors: Vec::new(),
+            red_count: 0,
+            blue_count: 0,
+        }
+    }
+
+    pub fn push_red(&mut self, segment: &impl Segment2<T>) {
+        self.colors.push(Color::Red(self.red_count));
+        self.red_count += 1;
+        self.sweep.push_segment(segment);
+    }
+
+    pub fn push_blue(&mut self, segment: &impl Segment2<T>) {
+        self.colors.push(Color::Blue(self.blue_count));
+        self.blue_count += 1;
+        self.sweep.push_segment(segment);
+    }
+
+    pub fn intersection(&mut self) -> Vec<RedBlueIntersection2<T>> {
+        let colors = &self.colors;
+        self.sweep
+            .intersection_with_segments_where(|indices| is_red_blue(colors, indices))
+            .into_iter()
+            .map(|intersection| {
+                let mut red_indices = Vec::new();
+                let mut blue_indices = Vec::new();
+                for index in intersection.segment_indices() {
+                    match colors[index] {
+                        Color::Red(red) => red_indices.push(red),
+                        Color::Blue(blue) => blue_indices.push(blue),
+                    }
+                }
+                RedBlueIntersection2 {
+                    point: intersection.point(),
+                    red_indices,
+                    blue_indices,
+                }
+            })
+            .collect()
+    }
+
+    /// Stops at the first point where a red and a blue segment meet.
+    pub fn has_intersection(&mut self) -> bool {
+        let colors = &self.colors;
+        self.sweep
+            .has_intersection_where(|indices| is_red_blue(colors, indices))
+    }
+}
+
+impl<T: NumberType> Default for RedBlueSegment2Intersection<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_red_blue(colors: &[Color], indices: &[usize]) -> bool {
+    let red = indices
+        .iter()
+        .any(|index| matches!(colors[*index], Color::Red(_)));
+    let blue = indices
+        .iter()
+        .any(|index| matches!(colors[*index], Color::Blue(_)));
+    red && blue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::{
+        arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2, line_segment_2::LineSegment2,
+    };
+
+    #[test]
+    fn test_red_blue_segment_2_intersection() {
+        let mut red_blue = RedBlueSegment2Intersection::new();
+        red_blue.push_red(&LineSegment2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 4.0),
+        ));
+        red_blue.push_red(&LineSegment2::new(
+            Point2::new(0.0, 4.0),
+            Point2::new(4.0, 0.0),
+        ));
+        red_blue.push_blue(&LineSegment2::new(
+            Point2::new(0.0, 1.0),
+            Point2::new(4.0, 1.0),
+        ));
+        red_blue.push_blue(&CircleSegment2::new(Point2::new(2.0, 2.0), 1.0));
+        red_blue.push_blue(&ArcSegment2::new(
+            CircleSegment2::new(Point2::new(2.0, 6.0), 1.0),
+            0.0,
+            std::f64::consts::PI,
+        ));
+        let result = red_blue.intersection();
+        let diagonal = 0.5_f64.sqrt();
+        let expected = vec![
+            (Point2::new(3.0, 1.0), vec![1], vec![0]),
+            (
+                Point2::new(2.0 + diagonal, 2.0 + diagonal),
+                vec![0],
+                vec![1],
+            ),
+            (
+                Point2::new(2.0 + diagonal, 2.0 - diagonal),
+                vec![1],
+                vec![1],
+            ),
+            (
+                Point2::new(2.0 - diagonal, 2.0 + diagonal),
+                vec![1],
+                vec![1],
+            ),
+            (
+                Point2::new(2.0 - diagonal, 2.0 - diagonal),
+                vec![0],
+                vec![1],
+            ),
+            (Point2::new(1.0, 1.0), vec![0], vec![0]),
+        ];
+        assert_eq!(
+            result
+                .iter()
+                .map(|intersection| (
+                    intersection.point(),
+                    intersection.red_indices(),
+                    intersection.blue_indices()
+                ))
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(result[0].pairs(), vec![(1, 0)]);
+        assert!(red_blue.has_intersection());
+
+        let mut red_blue = RedBlueSegment2Intersection::new();
+        red_blue.push_red(&LineSegment2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 4.0),
+        ));
+        red_blue.push_red(&LineSegment2::new(
+            Point2::new(0.0, 4.0),
+            Point2::new(4.0, 0.0),
+        ));
+        red_blue.push_blue(&LineSegment2::new(
+            Point2::new(0.0, 1.0),
+            Point2::new(0.5, 1.0),
+        ));
+        red_blue.push_blue(&LineSegment2::new(
+            Point2::new(0.25, 0.75),
+            Point2::new(0.25, 2.0),
+        ));
+        assert!(!red_blue.has_intersection());
+        assert!(red_blue.intersection().is_empty());
+
+        // Crossings within the blue set are skipped, not reported.
+        let mut red_blue = RedBlueSegment2Intersection::new();
+        red_blue.push_red(&LineSegment2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+        ));
+        red_blue.push_blue(&LineSegment2::new(
+            Point2::new(1.0, -1.0),
+            Point2::new(3.0, 3.0),
+        ));
+        red_blue.push_blue(&LineSegment2::new(
+            Point2::new(3.0, -1.0),
+            Point2::new(1.0, 3.0),
+        ));
+        let result = red_blue.intersection();
+        assert_eq!(
+            result
+                .iter()
+                .map(|intersection| (intersection.point(), intersection.pairs()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Point2::new(2.5, 0.0), vec![(0, 1)]),
+                (Point2::new(1.5, 0.0), vec![(0, 0)]),
+            ]
+        );
+        assert!(red_blue.has_intersection());
+    }
+}
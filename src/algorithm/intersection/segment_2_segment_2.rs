@@ -1,7 +1,15 @@
 use crate::{
     algorithm::intersection::line_segment_2_circle_segment_2::is_line_segment_2_circle_segment_2_intersected,
     kernel::{
-        number_type::NumberType, point_2::Point2, segment_2::Segment2, util_enum::Segment2Type,
+        arc_segment_2::{normalize_radian, ArcSegment2},
+        circle_segment_2::CircleSegment2,
+        line_2::Line2,
+        line_segment_2::LineSegment2,
+        number_type::NumberType,
+        point_2::Point2,
+        segment_2::Segment2,
+        util_enum::{Orientation, Segment2Type},
+        vector_2::Vector2,
     },
 };
 
@@ -16,6 +24,7 @@ use super::{
         circle_segment_2_circle_segment_2_intersection,
         is_circle_segment_2_circle_segment_2_intersected,
     },
+    intersection_enum::{IntersectionContact, Segment2Segment2Intersection},
     line_segment_2_arc_segment_2::{
         is_line_segment_2_arc_segment_2_intersected, line_segment_2_arc_segment_2_intersection,
     },
@@ -67,9 +76,34 @@ pub fn is_segment_2_segment_2_intersected<T: NumberType>(
     }
 }
 
+/// Collinear segments and co-circular arcs report their overlap, every isolated point whether the curves cross or touch.
 pub fn segment_2_segment_2_intersection<T: NumberType>(
     segment_a: &impl Segment2<T>,
     segment_b: &impl Segment2<T>,
+) -> Segment2Segment2Intersection<T> {
+    if let Some(overlap) = overlap_intersection(segment_a, segment_b) {
+        return overlap;
+    }
+    let mut points = intersection_points(segment_a, segment_b);
+    points.sort_by(|a, b| {
+        position_along(segment_a, a)
+            .partial_cmp(&position_along(segment_a, b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    points.dedup();
+    match points.len() {
+        0 => Segment2Segment2Intersection::Empty,
+        1 => Segment2Segment2Intersection::Point(
+            points[0],
+            contact_at(segment_a, segment_b, &points[0]),
+        ),
+        _ => Segment2Segment2Intersection::TwoPoints(points[0], points[points.len() - 1]),
+    }
+}
+
+fn intersection_points<T: NumberType>(
+    segment_a: &impl Segment2<T>,
+    segment_b: &impl Segment2<T>,
 ) -> Vec<Point2<T>> {
     let segment_a_type = segment_a.segment_type();
     let segment_b_type = segment_b.segment_type();
@@ -108,3 +142,426 @@ pub fn segment_2_segment_2_intersection<T: NumberType>(
         }
     }
 }
+
+fn overlap_intersection<T: NumberType>(
+    segment_a: &impl Segment2<T>,
+    segment_b: &impl Segment2<T>,
+) -> Option<Segment2Segment2Intersection<T>> {
+    match (segment_a.segment_type(), segment_b.segment_type()) {
+        (Segment2Type::LineSegment2, Segment2Type::LineSegment2) => {
+            line_overlap(segment_a, segment_b)
+        }
+        (Segment2Type::LineSegment2, _) | (_, Segment2Type::LineSegment2) => None,
+        _ => circular_overlap(segment_a, segment_b),
+    }
+}
+
+fn line_overlap<T: NumberType>(
+    segment_a: &impl Segment2<T>,
+    segment_b: &impl Segment2<T>,
+) -> Option<Segment2Segment2Intersection<T>> {
+    let source = segment_a.source();
+    let direction = segment_a.target() - source;
+    let squared_length = direction.dot(&direction);
+    if squared_length.equals(T::zero()) {
+        return None;
+    }
+    let Ok(line) = Line2::from_points(&source, &segment_a.target()) else {
+        return None;
+    };
+    if !line.distance(&segment_b.source()).equals(T::zero())
+        || !line.distance(&segment_b.target()).equals(T::zero())
+    {
+        return None;
+    }
+    let parameter_source = direction.dot(&(segment_b.source() - source)) / squared_length;
+    let parameter_target = direction.dot(&(segment_b.target() - source)) / squared_length;
+    let (low, high) = if parameter_source < parameter_target {
+        (parameter_source, parameter_target)
+    } else {
+        (parameter_target, parameter_source)
+    };
+    let low = if low > T::zero() { low } else { T::zero() };
+    let high = if high < T::from_f64(1.0) {
+        high
+    } else {
+        T::from_f64(1.0)
+    };
+    let point_at = |t: T| {
+        Point2::new(
+            source.x() + direction.x() * t,
+            source.y() + direction.y() * t,
+        )
+    };
+    let (low_point, high_point) = (point_at(low), point_at(high));
+    if low_point == high_point {
+        Some(Segment2Segment2Intersection::Point(
+            low_point,
+            IntersectionContact::Touching,
+        ))
+    } else if low < high {
+        Some(Segment2Segment2Intersection::Segment(LineSegment2::new(
+            low_point, high_point,
+        )))
+    } else {
+        Some(Segment2Segment2Intersection::Empty)
+    }
+}
+
+fn circular_overlap<T: NumberType>(
+    segment_a: &impl Segment2<T>,
+    segment_b: &impl Segment2<T>,
+) -> Option<Segment2Segment2Intersection<T>> {
+    if segment_a.center() != segment_b.center() || !segment_a.radius().equals(segment_b.radius()) {
+        return None;
+    }
+    let support = CircleSegment2::new(segment_a.center(), segment_a.radius());
+    let circle_a = segment_a.segment_type() == Segment2Type::CircleSegment2;
+    let circle_b = segment_b.segment_type() == Segment2Type::CircleSegment2;
+    if circle_a && circle_b {
+        return Some(Segment2Segment2Intersection::Coincident);
+    }
+    let two_pi = T::pi() * T::from_f64(2.0);
+    let orientation = if circle_a {
+        segment_b.orientation()
+    } else {
+        segment_a.orientation()
+    };
+    if circle_b || segment_b.sweep_radian().equals(two_pi) {
+        let (start, sweep) = if circle_a {
+            counter_clockwise_interval(segment_b)
+        } else {
+            counter_clockwise_interval(segment_a)
+        };
+        return Some(Segment2Segment2Intersection::Arc(arc_of(
+            &support,
+            start,
+            sweep,
+            orientation,
+        )));
+    }
+    if circle_a || segment_a.sweep_radian().equals(two_pi) {
+        let (start, sweep) = counter_clockwise_interval(segment_b);
+        return Some(Segment2Segment2Intersection::Arc(arc_of(
+            &support,
+            start,
+            sweep,
+            orientation,
+        )));
+    }
+    let (start_a, sweep_a) = counter_clockwise_interval(segment_a);
+    let (start_b, sweep_b) = counter_clockwise_interval(segment_b);
+    let offset = normalize_radian(start_b - start_a);
+    let mut pieces: Vec<(T, T)> = Vec::new();
+    for start in [offset, offset - two_pi] {
+        let low = if start > T::zero() { start } else { T::zero() };
+        let end = start + sweep_b;
+        let high = if end < sweep_a { end } else { sweep_a };
+        if low < high || low.equals(high) {
+            pieces.push((low, high));
+        }
+    }
+    let mut arcs = Vec::new();
+    let mut points: Vec<Point2<T>> = Vec::new();
+    for (low, high) in pieces {
+        if low.equals(high) {
+            let point = support_point(&support, start_a + low);
+            if !points.contains(&point) {
+                points.push(point);
+            }
+        } else {
+            arcs.push(arc_of(&support, start_a + low, high - low, orientation));
+        }
+    }
+    points.retain(|point| {
+        !arcs
+            .iter()
+            .any(|arc_segment| *point == arc_segment.source() || *point == arc_segment.target())
+    });
+    let by_position = |a: &Point2<T>, b: &Point2<T>| {
+        position_along(segment_a, a)
+            .partial_cmp(&position_along(segment_a, b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    };
+    arcs.sort_by(|a, b| by_position(&a.source(), &b.source()));
+    points.sort_by(by_position);
+    let result = match (arcs.len(), points.len()) {
+        (0, 0) => Segment2Segment2Intersection::Empty,
+        (0, 1) => Segment2Segment2Intersection::Point(points[0], IntersectionContact::Touching),
+        (0, _) => Segment2Segment2Intersection::TwoPoints(points[0], points[1]),
+        (1, 0) => Segment2Segment2Intersection::Arc(arcs[0]),
+        (1, _) => Segment2Segment2Intersection::ArcAndPoint(arcs[0], points[0]),
+        _ => Segment2Segment2Intersection::TwoArcs(arcs[0], arcs[1]),
+    };
+    Some(result)
+}
+
+fn arc_of<T: NumberType>(
+    support: &CircleSegment2<T>,
+    start: T,
+    sweep: T,
+    orientation: Orientation,
+) -> ArcSegment2<T> {
+    let arc_segment = match orientation {
+        Orientation::CounterClockwise => ArcSegment2::from_sweep(*support, start, sweep),
+        Orientation::Clockwise => ArcSegment2::from_sweep(*support, start + sweep, -sweep),
+    };
+    arc_segment.expect("sweep within an existing arc is valid")
+}
+
+/// Counterclockwise start and sweep of the set of points covered by the arc.
+fn counter_clockwise_interval<T: NumberType>(segment: &impl Segment2<T>) -> (T, T) {
+    let sweep = segment.sweep_radian();
+    match segment.orientation() {
+        Orientation::CounterClockwise => (segment.source_radian(), sweep),
+        Orientation::Clockwise => (normalize_radian(segment.source_radian() - sweep), sweep),
+    }
+}
+
+fn support_point<T: NumberType>(support: &CircleSegment2<T>, radian: T) -> Point2<T> {
+    let center = support.center();
+    Point2::new(
+        center.x() + support.radius() * radian.cos(),
+        center.y() + support.radius() * radian.sin(),
+    )
+}
+
+/// Increases from the source of lines and arcs and counterclockwise from the positive x axis on circles.
+fn position_along<T: NumberType>(segment: &impl Segment2<T>, point: &Point2<T>) -> T {
+    match segment.segment_type() {
+        Segment2Type::LineSegment2 => {
+            (segment.target() - segment.source()).dot(&(*point - segment.source()))
+        }
+        Segment2Type::CircleSegment2 => {
+            let vector = *point - segment.center();
+            normalize_radian(vector.y().atan2(vector.x()))
+        }
+        Segment2Type::ArcSegment2 => {
+            let vector = *point - segment.center();
+            let radian = vector.y().atan2(vector.x());
+            let offset = match segment.orientation() {
+                Orientation::CounterClockwise => radian - segment.source_radian(),
+                Orientation::Clockwise => segment.source_radian() - radian,
+            };
+            if *point == segment.source() {
+                T::zero()
+            } else {
+                normalize_radian(offset)
+            }
+        }
+    }
+}
+
+fn contact_at<T: NumberType>(
+    segment_a: &impl Segment2<T>,
+    segment_b: &impl Segment2<T>,
+    point: &Point2<T>,
+) -> IntersectionContact {
+    if is_endpoint(segment_a, point) || is_endpoint(segment_b, point) {
+        return IntersectionContact::Touching;
+    }
+    let tangent_a = tangent_direction(segment_a, point);
+    let tangent_b = tangent_direction(segment_b, point);
+    if tangent_a.cross(&tangent_b).equals(T::zero()) {
+        IntersectionContact::Tangent
+    } else {
+        IntersectionContact::Crossing
+    }
+}
+
+fn is_endpoint<T: NumberType>(segment: &impl Segment2<T>, point: &Point2<T>) -> bool {
+    segment.segment_type() != Segment2Type::CircleSegment2
+        && (*point == segment.source() || *point == segment.target())
+}
+
+fn tangent_direction<T: NumberType>(segment: &impl Segment2<T>, point: &Point2<T>) -> Vector2<T> {
+    match segment.segment_type() {
+        Segment2Type::LineSegment2 => (segment.target() - segment.source()).normalize(),
+        _ => {
+            let radial = (*point - segment.center()).normalize();
+            Vector2::new(-radial.y(), radial.x())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn test_segment_2_segment_2_intersection_lines() {
+        let line_segment =
+            |ax, ay, bx, by| LineSegment2::new(Point2::new(ax, ay), Point2::new(bx, by));
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &line_segment(0.0, 0.0, 2.0, 2.0),
+                &line_segment(0.0, 2.0, 2.0, 0.0)
+            ),
+            Segment2Segment2Intersection::Point(
+                Point2::new(1.0, 1.0),
+                IntersectionContact::Crossing
+            )
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &line_segment(0.0, 0.0, 4.0, 0.0),
+                &line_segment(5.0, 0.0, 2.0, 0.0)
+            ),
+            Segment2Segment2Intersection::Segment(line_segment(2.0, 0.0, 4.0, 0.0))
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &line_segment(4.0, 0.0, 0.0, 0.0),
+                &line_segment(5.0, 0.0, 2.0, 0.0)
+            ),
+            Segment2Segment2Intersection::Segment(line_segment(4.0, 0.0, 2.0, 0.0))
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &line_segment(0.0, 0.0, 2.0, 0.0),
+                &line_segment(2.0, 0.0, 3.0, 0.0)
+            ),
+            Segment2Segment2Intersection::Point(
+                Point2::new(2.0, 0.0),
+                IntersectionContact::Touching
+            )
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &line_segment(0.0, 0.0, 2.0, 0.0),
+                &line_segment(1.0, 0.0, 1.0, 2.0)
+            ),
+            Segment2Segment2Intersection::Point(
+                Point2::new(1.0, 0.0),
+                IntersectionContact::Touching
+            )
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &line_segment(0.0, 0.0, 2.0, 2.0),
+                &line_segment(2.0, 2.0, 3.0, 0.0)
+            ),
+            Segment2Segment2Intersection::Point(
+                Point2::new(2.0, 2.0),
+                IntersectionContact::Touching
+            )
+        );
+        assert!(segment_2_segment_2_intersection(
+            &line_segment(0.0, 0.0, 2.0, 0.0),
+            &line_segment(3.0, 0.0, 4.0, 0.0)
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_segment_2_segment_2_intersection_circles() {
+        let circle_segment = CircleSegment2::new(Point2::new(0.0, 0.0), 1.0);
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &LineSegment2::new(Point2::new(-2.0, 1.0), Point2::new(2.0, 1.0)),
+                &circle_segment
+            ),
+            Segment2Segment2Intersection::Point(
+                Point2::new(0.0, 1.0),
+                IntersectionContact::Tangent
+            )
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &LineSegment2::new(Point2::new(2.0, 0.0), Point2::new(-2.0, 0.0)),
+                &circle_segment
+            ),
+            Segment2Segment2Intersection::TwoPoints(Point2::new(1.0, 0.0), Point2::new(-1.0, 0.0))
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &circle_segment,
+                &CircleSegment2::new(Point2::new(2.0, 0.0), 1.0)
+            ),
+            Segment2Segment2Intersection::Point(
+                Point2::new(1.0, 0.0),
+                IntersectionContact::Tangent
+            )
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(&circle_segment, &circle_segment),
+            Segment2Segment2Intersection::Coincident
+        );
+        let clockwise =
+            ArcSegment2::with_orientation(circle_segment, PI, 0.0, Orientation::Clockwise).unwrap();
+        assert_eq!(
+            segment_2_segment_2_intersection(&circle_segment, &clockwise),
+            Segment2Segment2Intersection::Arc(clockwise)
+        );
+    }
+
+    #[test]
+    fn test_segment_2_segment_2_intersection_arcs() {
+        let support = CircleSegment2::new(Point2::new(0.0, 0.0), 1.0);
+        let arc = |source, sweep| ArcSegment2::from_sweep(support, source, sweep).unwrap();
+        assert_eq!(
+            segment_2_segment_2_intersection(&arc(0.0, PI), &arc(PI / 2.0, PI)),
+            Segment2Segment2Intersection::Arc(arc(PI / 2.0, PI / 2.0))
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(&arc(PI, -PI), &arc(PI / 2.0, PI)),
+            Segment2Segment2Intersection::Arc(arc(PI, -PI / 2.0))
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(&arc(0.0, 1.5 * PI), &arc(PI, 1.5 * PI)),
+            Segment2Segment2Intersection::TwoArcs(arc(0.0, PI / 2.0), arc(PI, PI / 2.0))
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(&arc(0.0, 1.5 * PI), &arc(PI, PI)),
+            Segment2Segment2Intersection::ArcAndPoint(arc(PI, PI / 2.0), Point2::new(1.0, 0.0))
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(&arc(0.0, 1.5 * PI), &arc(1.5 * PI, PI / 2.0)),
+            Segment2Segment2Intersection::TwoPoints(Point2::new(1.0, 0.0), Point2::new(0.0, -1.0))
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(&arc(0.0, PI / 2.0), &arc(PI / 2.0, PI / 2.0)),
+            Segment2Segment2Intersection::Point(
+                Point2::new(0.0, 1.0),
+                IntersectionContact::Touching
+            )
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(0.0, 2.0)),
+                &arc(0.0, PI)
+            ),
+            Segment2Segment2Intersection::Point(
+                Point2::new(0.0, 1.0),
+                IntersectionContact::Crossing
+            )
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &LineSegment2::new(Point2::new(1.0, -1.0), Point2::new(1.0, 1.0)),
+                &arc(0.0, PI)
+            ),
+            Segment2Segment2Intersection::Point(
+                Point2::new(1.0, 0.0),
+                IntersectionContact::Touching
+            )
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(&arc(0.0, PI / 2.0), &arc(PI, PI / 2.0)),
+            Segment2Segment2Intersection::Empty
+        );
+        assert_eq!(
+            segment_2_segment_2_intersection(
+                &arc(0.0, PI),
+                &ArcSegment2::from_sweep(CircleSegment2::new(Point2::new(0.0, 2.0), 1.0), 0.0, -PI)
+                    .unwrap()
+            ),
+            Segment2Segment2Intersection::Point(
+                Point2::new(0.0, 1.0),
+                IntersectionContact::Tangent
+            )
+        );
+    }
+}
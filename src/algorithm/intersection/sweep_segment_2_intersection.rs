@@ -1,14 +1,16 @@
-use crate::algorithm::location::{
-    point_2_arc_segment_2::is_point_2_on_arc_segment_2,
-    point_2_line_segment_2::is_point_2_on_line_segment_2,
-};
-use crate::data_structure::{
-    avl_tree::{AVLTree, AVLTreeOption},
-    priority_queue::PriorityQueue,
-};
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use crate::algorithm::location::point_2_arc_segment_2::is_point_2_on_arc_segment_2;
+use crate::data_structure::treap::Treap;
 use crate::kernel::{
-    arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2, line_segment_2::LineSegment2,
-    number_type::NumberType, point_2::Point2, segment_2::Segment2, util_enum::Segment2Type,
+    arc_segment_2::ArcSegment2,
+    circle_segment_2::CircleSegment2,
+    line_segment_2::LineSegment2,
+    number_type::NumberType,
+    point_2::Point2,
+    segment_2::Segment2,
+    util_enum::{Orientation, Segment2Type},
+    vector_2::Vector2,
 };
 
 use super::segment_2_segment_2::segment_2_segment_2_intersection;
@@ -19,776 +21,524 @@ enum StatusNodeSegment<T: NumberType> {
     ArcSegment2(ArcSegment2<T>),
 }
 
+/// A pushed segment. Arcs are split into x-monotone pieces the first time the sweep reaches
+/// one of their start points.
+#[derive(Debug, Clone)]
+struct Curve<T: NumberType> {
+    segment: StatusNodeSegment<T>,
+    origin: usize,
+    starts: Vec<Point2<T>>,
+    monotone: Option<Vec<ArcSegment2<T>>>,
+}
+
+/// An x-monotone piece of a curve, running from `left` to `right` in sweep order.
 #[derive(Debug, Clone, Copy)]
-struct StatusNode<T: NumberType> {
-    value: T,
-    point: Point2<T>,
+struct Piece<T: NumberType> {
     segment: StatusNodeSegment<T>,
+    origin: usize,
+    left: Point2<T>,
+    right: Point2<T>,
+    upper: bool,
 }
 
+#[derive(Debug, Clone, Default)]
+struct Event {
+    curves: Vec<usize>,
+    ends: Vec<usize>,
+    crossings: Vec<usize>,
+}
+
+/// Intersection point with the indices, in `push_segment` order, of every segment through it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentIntersection2<T: NumberType> {
+    point: Point2<T>,
+    segment_indices: Vec<usize>,
+}
+
+impl<T: NumberType> SegmentIntersection2<T> {
+    pub fn point(&self) -> Point2<T> {
+        self.point
+    }
+
+    /// Sorted and distinct.
+    pub fn segment_indices(&self) -> Vec<usize> {
+        self.segment_indices.clone()
+    }
+}
+
+/// Bentley–Ottmann sweep over line and arc segments in O((n + k) log n) for n segments and
+/// k intersection points.
 pub struct SweepSegment2Intersection<T: NumberType> {
-    origin_segments: Vec<StatusNodeSegment<T>>,
-    segments: Vec<StatusNodeSegment<T>>,
-    event_queue: PriorityQueue<Point2<T>>,
-    status_tree: AVLTree<StatusNode<T>>,
-    intersection_points: AVLTree<Point2<T>>,
-    last_event_point: Option<Point2<T>>,
+    segment_count: usize,
+    curves: Vec<Curve<T>>,
+    events: BTreeMap<Point2<T>, Event>,
+    pieces: Vec<Piece<T>>,
+    handles: Vec<usize>,
+    status: Treap<usize>,
+    intersections: Vec<SegmentIntersection2<T>>,
 }
 
 impl<T: NumberType> SweepSegment2Intersection<T> {
+    /// Zero-length arcs have no extent and are ignored, but still take an index.
     pub fn push_segment(&mut self, segment: &impl Segment2<T>) {
-        match segment.segment_type() {
+        let origin = self.segment_count;
+        self.segment_count += 1;
+        let segment = match segment.segment_type() {
             Segment2Type::LineSegment2 => {
                 let source = segment.source();
                 let target = segment.target();
-                self.origin_segments
-                    .push(StatusNodeSegment::LineSegment2(LineSegment2::new(
-                        source, target,
-                    )));
                 if source > target {
-                    self.segments
-                        .push(StatusNodeSegment::LineSegment2(LineSegment2::new(
-                            source, target,
-                        )));
+                    StatusNodeSegment::LineSegment2(LineSegment2::new(source, target))
                 } else {
-                    self.segments
-                        .push(StatusNodeSegment::LineSegment2(LineSegment2::new(
-                            target, source,
-                        )));
+                    StatusNodeSegment::LineSegment2(LineSegment2::new(target, source))
                 }
             }
             Segment2Type::CircleSegment2 => {
                 let circle_segment = CircleSegment2::new(segment.center(), segment.radius());
-                self.origin_segments
-                    .push(StatusNodeSegment::ArcSegment2(ArcSegment2::new(
-                        circle_segment.clone(),
-                        T::zero(),
-                        T::pi() * T::from_f64(2.0),
-                    )));
-                self.segments
-                    .push(StatusNodeSegment::ArcSegment2(ArcSegment2::new(
-                        circle_segment.clone(),
-                        T::zero(),
-                        T::pi(),
-                    )));
-                self.segments
-                    .push(StatusNodeSegment::ArcSegment2(ArcSegment2::new(
-                        circle_segment.clone(),
-                        T::pi(),
-                        T::pi() * T::from_f64(2.0),
-                    )));
+                StatusNodeSegment::ArcSegment2(
+                    ArcSegment2::from_sweep(circle_segment, T::zero(), T::pi() * T::from_f64(2.0))
+                        .unwrap(),
+                )
             }
             Segment2Type::ArcSegment2 => {
-                let center = segment.center();
-                let radius = segment.radius();
-                let circle_segment = CircleSegment2::new(center, radius);
-
-                let arc_segment = ArcSegment2::new(
-                    circle_segment.clone(),
-                    segment.source_radian(),
-                    segment.target_radian(),
-                );
-                self.origin_segments
-                    .push(StatusNodeSegment::ArcSegment2(ArcSegment2::new(
-                        circle_segment.clone(),
-                        segment.source_radian(),
-                        segment.target_radian(),
-                    )));
-                let arc_segments = arc_segment.monotone();
-                for arc_segment in arc_segments {
-                    self.segments
-                        .push(StatusNodeSegment::ArcSegment2(arc_segment));
+                let circle_segment = CircleSegment2::new(segment.center(), segment.radius());
+                let sweep_radian = match segment.orientation() {
+                    Orientation::CounterClockwise => segment.sweep_radian(),
+                    Orientation::Clockwise => -segment.sweep_radian(),
+                };
+                let arc_segment =
+                    ArcSegment2::from_sweep(circle_segment, segment.source_radian(), sweep_radian)
+                        .unwrap();
+                if arc_segment.is_zero_length() {
+                    return;
+                }
+                StatusNodeSegment::ArcSegment2(arc_segment)
+            }
+        };
+        let mut starts = Vec::new();
+        match segment {
+            StatusNodeSegment::LineSegment2(line_segment) => starts.push(line_segment.source()),
+            StatusNodeSegment::ArcSegment2(arc_segment) => {
+                let center = arc_segment.center();
+                let leftmost = Point2::new(center.x() - arc_segment.radius(), center.y());
+                for point in [arc_segment.source(), arc_segment.target(), leftmost] {
+                    if !starts.contains(&point) && is_point_2_on_arc_segment_2(&point, &arc_segment)
+                    {
+                        starts.push(point);
+                    }
                 }
             }
         }
+        self.curves.push(Curve {
+            segment,
+            origin,
+            starts,
+            monotone: None,
+        });
     }
 
     pub fn new() -> Self {
         Self {
-            origin_segments: Vec::new(),
-            segments: Vec::new(),
-            event_queue: PriorityQueue::new(),
-            status_tree: AVLTree::new(AVLTreeOption::SameNodeInsertRight),
-            intersection_points: AVLTree::new(AVLTreeOption::DisableSameNode),
-            last_event_point: None,
+            segment_count: 0,
+            curves: Vec::new(),
+            events: BTreeMap::new(),
+            pieces: Vec::new(),
+            handles: Vec::new(),
+            status: Treap::new(),
+            intersections: Vec::new(),
         }
     }
 
     pub fn intersection(&mut self) -> Vec<Point2<T>> {
-        self.event_queue.clear();
-        self.status_tree.clear();
-        self.intersection_points.clear();
-        let mut event_points = AVLTree::new(AVLTreeOption::DisableSameNode);
-        for segment in &self.segments {
-            match segment {
-                StatusNodeSegment::LineSegment2(segment) => {
-                    event_points.insert(segment.source());
-                    event_points.insert(segment.target());
-                }
-                StatusNodeSegment::ArcSegment2(segment) => {
-                    event_points.insert(segment.source());
-                    event_points.insert(segment.target());
-                }
-            }
-        }
-        let event_points = event_points.mid_order_traversal();
-        for event_point in event_points {
-            self.event_queue.push(event_point);
-        }
-        while !self.event_queue.is_empty() {
-            let event_point = self.event_queue.pop().unwrap();
-            self.handle_event_point(&event_point);
-            self.last_event_point = Some(event_point);
-        }
-        let points = self.intersection_points.mid_order_traversal();
-        self.filter_intersection_points(points)
+        self.intersection_with_segments()
+            .into_iter()
+            .map(|intersection| intersection.point)
+            .collect()
     }
 
-    fn filter_intersection_points(&self, points: Vec<Point2<T>>) -> Vec<Point2<T>> {
-        let mut result = Vec::new();
-        for point in points {
-            let mut sum = 0;
-            for segment in &self.origin_segments {
-                match segment {
-                    StatusNodeSegment::LineSegment2(line_segment) => {
-                        if is_point_2_on_line_segment_2(&point, line_segment) {
-                            sum += 1;
-                        }
-                    }
-                    StatusNodeSegment::ArcSegment2(arc_segment) => {
-                        if is_point_2_on_arc_segment_2(&point, arc_segment) {
-                            sum += 1;
-                        }
-                    }
-                }
-                if sum > 1 {
-                    result.push(point);
-                    break;
-                }
-            }
-        }
-        result
+    /// Points where two or more pushed segments meet, with the segments meeting there.
+    pub fn intersection_with_segments(&mut self) -> Vec<SegmentIntersection2<T>> {
+        self.intersection_with_segments_where(|_| true)
     }
 
-    fn handle_event_point(&mut self, event_point: &Point2<T>) {
-        let source_is_p = self.get_segment_with_source(event_point);
-        let target_is_p = self.get_active_segment_with_target(event_point);
-        let contain_p = self.get_active_segment_containing_point(event_point);
-        if source_is_p.is_empty() && target_is_p.is_empty() && contain_p.is_empty() {
-            return;
-        }
-        if source_is_p.len() + target_is_p.len() + contain_p.len() > 1 {
-            self.intersection_points.insert(event_point.clone());
-        }
-        for segment in &target_is_p {
-            self.status_tree.delete(StatusNode {
-                value: match self.last_event_point {
-                    Some(point) => match segment {
-                        StatusNodeSegment::LineSegment2(line_segment) => {
-                            calculate_segment_value(line_segment, &point)
-                        }
-                        StatusNodeSegment::ArcSegment2(arc_segment) => {
-                            calculate_segment_value(arc_segment, &point)
-                        }
-                    },
-                    None => match segment {
-                        StatusNodeSegment::LineSegment2(line_segment) => line_segment.source().y(),
-                        StatusNodeSegment::ArcSegment2(arc_segment) => arc_segment.source().y(),
-                    },
-                },
-                point: event_point.clone(),
-                segment: segment.clone(),
-            })
-        }
-        for segment in &contain_p {
-            self.status_tree.delete(StatusNode {
-                value: match self.last_event_point {
-                    Some(point) => match segment {
-                        StatusNodeSegment::LineSegment2(line_segment) => {
-                            calculate_segment_value(line_segment, &point)
-                        }
-                        StatusNodeSegment::ArcSegment2(arc_segment) => {
-                            calculate_segment_value(arc_segment, &point)
-                        }
-                    },
-                    None => match segment {
-                        StatusNodeSegment::LineSegment2(line_segment) => line_segment.source().y(),
-                        StatusNodeSegment::ArcSegment2(arc_segment) => arc_segment.source().y(),
-                    },
-                },
-                point: event_point.clone(),
-                segment: segment.clone(),
-            })
-        }
-        let source_is_p_empty = source_is_p.is_empty();
-        let contain_p_empty = contain_p.is_empty();
-        let old_status_nodes = self.status_tree.mid_order_traversal();
-        self.status_tree.clear();
-        let mut reinserted_segments = Vec::new();
-        for status_node in old_status_nodes {
-            reinserted_segments.push(status_node.segment.clone());
-        }
-        for segment in &source_is_p {
-            reinserted_segments.push(segment.clone());
-        }
-        for segment in &contain_p {
-            reinserted_segments.push(segment.clone());
-        }
-        reinserted_segments.sort_by(|a, b| match a {
-            StatusNodeSegment::LineSegment2(segment) => match b {
-                StatusNodeSegment::LineSegment2(other_segment) => {
-                    compare_segments(segment, other_segment, &event_point.clone())
-                }
-                StatusNodeSegment::ArcSegment2(other_segment) => {
-                    compare_segments(segment, other_segment, &event_point.clone())
-                }
-            },
-            StatusNodeSegment::ArcSegment2(segment) => match b {
-                StatusNodeSegment::LineSegment2(other_segment) => {
-                    compare_segments(segment, other_segment, &event_point.clone())
-                }
-                StatusNodeSegment::ArcSegment2(other_segment) => {
-                    compare_segments(segment, other_segment, &event_point.clone())
-                }
-            },
-        });
-        for segment in reinserted_segments {
-            match segment {
-                StatusNodeSegment::LineSegment2(line_segment) => {
-                    self.status_tree.insert(StatusNode {
-                        value: calculate_segment_value(&line_segment, event_point),
-                        point: event_point.clone(),
-                        segment,
-                    })
-                }
-                StatusNodeSegment::ArcSegment2(arc_segment) => {
-                    self.status_tree.insert(StatusNode {
-                        value: calculate_segment_value(&arc_segment, event_point),
-                        point: event_point.clone(),
-                        segment,
-                    })
-                }
-            }
-        }
-        let mid_order_traversal = self.status_tree.mid_order_traversal();
-        if source_is_p_empty && contain_p_empty {
-            let neighbors = self.get_neighbors_with_point(event_point);
-            match neighbors {
-                Some((segment_left, segment_right)) => {
-                    self.find_intersection_points(&segment_left, &segment_right, event_point);
-                }
-                None => {}
-            }
-        } else {
-            let segment_left = self
-                .get_left_right_in_u_c(&source_is_p, &contain_p, event_point)
-                .0;
-            let segment_left_left = self.get_left_of_segment(&segment_left, &mid_order_traversal);
-            match segment_left_left {
-                Some(segment) => {
-                    self.find_intersection_points(&segment_left, &segment, event_point);
-                }
-                _ => {}
-            }
-            let segment_right = self
-                .get_left_right_in_u_c(&source_is_p, &contain_p, event_point)
-                .1;
-            let segment_right_right =
-                self.get_right_of_segment(&segment_right, &mid_order_traversal);
-            match segment_right_right {
-                Some(segment) => {
-                    self.find_intersection_points(&segment_right, &segment, event_point);
-                }
-                _ => {}
-            }
-        }
+    /// Stops at the first point where two pushed segments meet.
+    pub fn has_intersection(&mut self) -> bool {
+        self.has_intersection_where(|_| true)
     }
 
-    fn get_segment_with_source(&self, event_point: &Point2<T>) -> Vec<StatusNodeSegment<T>> {
-        let mut result = Vec::new();
-        for segment in &self.segments {
-            match segment {
-                StatusNodeSegment::LineSegment2(line_segment) => {
-                    if line_segment.source().equals(event_point) {
-                        result.push(segment.clone());
-                    }
-                }
-                StatusNodeSegment::ArcSegment2(arc_segment) => {
-                    if (arc_segment.is_top() && arc_segment.target().equals(event_point))
-                        || (!arc_segment.is_top() && arc_segment.source().equals(event_point))
-                    {
-                        result.push(segment.clone());
-                    }
-                }
+    /// Only meetings whose sorted segment indices pass `accept` are kept.
+    pub(crate) fn intersection_with_segments_where(
+        &mut self,
+        accept: impl Fn(&[usize]) -> bool,
+    ) -> Vec<SegmentIntersection2<T>> {
+        self.sweep(false, &accept);
+        let mut intersections = std::mem::take(&mut self.intersections);
+        intersections.sort_by_key(|intersection| intersection.point);
+        let mut result: Vec<SegmentIntersection2<T>> = Vec::new();
+        for intersection in intersections {
+            match result.last_mut() {
+                Some(last) if last.point == intersection.point => {
+                    last.segment_indices.extend(intersection.segment_indices);
+                    last.segment_indices.sort();
+                    last.segment_indices.dedup();
+                }
+                _ => result.push(intersection),
             }
         }
         result
     }
 
-    fn get_active_segment_with_target(&self, target: &Point2<T>) -> Vec<StatusNodeSegment<T>> {
-        let mut result = Vec::new();
-        let status_nodes = self.status_tree.mid_order_traversal();
-        for status_node in status_nodes {
-            match status_node.segment {
-                StatusNodeSegment::LineSegment2(line_segment) => {
-                    if line_segment.target().equals(target) {
-                        result.push(status_node.segment);
-                    }
-                }
-                StatusNodeSegment::ArcSegment2(arc_segment) => {
-                    if (arc_segment.is_top() && arc_segment.source().equals(target))
-                        || (!arc_segment.is_top() && arc_segment.target().equals(target))
-                    {
-                        result.push(status_node.segment);
-                    }
-                }
+    pub(crate) fn has_intersection_where(&mut self, accept: impl Fn(&[usize]) -> bool) -> bool {
+        self.sweep(true, &accept);
+        let found = !self.intersections.is_empty();
+        self.intersections.clear();
+        found
+    }
+
+    fn sweep(&mut self, stop_at_first: bool, accept: &impl Fn(&[usize]) -> bool) {
+        self.events.clear();
+        self.pieces.clear();
+        self.handles.clear();
+        self.status.clear();
+        self.intersections.clear();
+        for (index, curve) in self.curves.iter().enumerate() {
+            for start in &curve.starts {
+                self.events.entry(*start).or_default().curves.push(index);
+            }
+        }
+        // Point2 orders the leftmost, then lowest, point last.
+        while let Some((event_point, event)) = self.events.pop_last() {
+            self.handle_event_point(&event_point, event, accept);
+            if stop_at_first && !self.intersections.is_empty() {
+                return;
             }
         }
-        result
     }
 
-    fn get_active_segment_containing_point(&self, point: &Point2<T>) -> Vec<StatusNodeSegment<T>> {
-        let mut result = Vec::new();
-        let status_nodes = self.status_tree.mid_order_traversal();
-        for status_node in status_nodes {
-            match status_node.segment {
-                StatusNodeSegment::LineSegment2(line_segment) => {
-                    let source = line_segment.source();
-                    let target = line_segment.target();
-                    if source.equals(point) || target.equals(point) {
-                        continue;
-                    }
-                    if is_point_2_on_line_segment_2(point, &line_segment) {
-                        result.push(status_node.segment);
-                    }
-                }
-                StatusNodeSegment::ArcSegment2(arc_segment) => {
-                    let source = arc_segment.source();
-                    let target = arc_segment.target();
-                    if source.equals(point) || target.equals(point) {
-                        continue;
-                    }
-                    if is_point_2_on_arc_segment_2(point, &arc_segment) {
-                        result.push(status_node.segment);
-                    }
+    fn handle_event_point(
+        &mut self,
+        event_point: &Point2<T>,
+        mut event: Event,
+        accept: &impl Fn(&[usize]) -> bool,
+    ) {
+        let mut starting = Vec::new();
+        let mut touching = Vec::new();
+        event.curves.sort();
+        event.curves.dedup();
+        for curve in event.curves {
+            for piece in self.pieces_starting_at(curve, event_point) {
+                let id = self.pieces.len();
+                self.pieces.push(piece);
+                self.handles.push(usize::MAX);
+                if piece.left == piece.right {
+                    touching.push(id);
+                } else {
+                    self.events.entry(piece.right).or_default().ends.push(id);
+                    starting.push(id);
                 }
             }
         }
-        result
-    }
-
-    fn get_neighbors_with_point(
-        &self,
-        point: &Point2<T>,
-    ) -> Option<(StatusNodeSegment<T>, StatusNodeSegment<T>)> {
-        let status_nodes = self.status_tree.mid_order_traversal();
-        let mut index = 0;
-        let mut flag = false;
-        for (status_index, status_node) in status_nodes.iter().enumerate() {
-            if status_node.value.equals(point.y()) || status_node.value > point.y() {
-                index = status_index;
-                flag = true;
+        let mut passing = event.ends;
+        passing.extend(
+            event
+                .crossings
+                .into_iter()
+                .filter(|id| self.handles[*id] != usize::MAX),
+        );
+        let mut cursor = self.status.lower_bound_by(|id| {
+            let y = y_at(&self.pieces[*id], event_point.x(), event_point.y());
+            y.equals(event_point.y()) || y > event_point.y()
+        });
+        while let Some(handle) = cursor {
+            let id = self.status.get(handle);
+            if !y_at(&self.pieces[id], event_point.x(), event_point.y()).equals(event_point.y()) {
                 break;
             }
+            passing.push(id);
+            cursor = self.status.next(handle);
         }
-        if flag {
-            if index == 0 {
-                return None;
-            } else {
-                return Some((
-                    status_nodes[index - 1].segment.clone(),
-                    status_nodes[index].segment.clone(),
-                ));
-            }
-        } else {
-            return None;
+        passing.sort();
+        passing.dedup();
+        let mut segment_indices: Vec<usize> = passing
+            .iter()
+            .chain(&starting)
+            .chain(&touching)
+            .map(|id| self.pieces[*id].origin)
+            .collect();
+        segment_indices.sort();
+        segment_indices.dedup();
+        if segment_indices.len() > 1 && accept(&segment_indices) {
+            self.intersections.push(SegmentIntersection2 {
+                point: *event_point,
+                segment_indices,
+            });
         }
-    }
-
-    fn get_left_right_in_u_c(
-        &self,
-        source_is_p: &Vec<StatusNodeSegment<T>>,
-        contain_p: &Vec<StatusNodeSegment<T>>,
-        event_point: &Point2<T>,
-    ) -> (StatusNodeSegment<T>, StatusNodeSegment<T>) {
-        let mut segments = Vec::new();
-        for segment in source_is_p {
-            segments.push(segment.clone());
+        // Neighbours of the removed pieces are taken from the status itself, as rounding can
+        // leave a piece through the event just off its y.
+        let mut neighbours = Vec::new();
+        for id in &passing {
+            let handle = self.handles[*id];
+            neighbours.extend(
+                self.status
+                    .prev(handle)
+                    .map(|handle| self.status.get(handle)),
+            );
+            neighbours.extend(
+                self.status
+                    .next(handle)
+                    .map(|handle| self.status.get(handle)),
+            );
         }
-        for segment in contain_p {
-            segments.push(segment.clone());
+        neighbours.retain(|id| passing.binary_search(id).is_err());
+        for id in &passing {
+            self.status.remove(self.handles[*id]);
+            self.handles[*id] = usize::MAX;
         }
-        segments.sort_by(|a, b| match a {
-            StatusNodeSegment::LineSegment2(segment) => match b {
-                StatusNodeSegment::LineSegment2(other_segment) => {
-                    compare_segments(segment, other_segment, &event_point.clone())
-                }
-                StatusNodeSegment::ArcSegment2(other_segment) => {
-                    compare_segments(segment, other_segment, &event_point.clone())
-                }
-            },
-            StatusNodeSegment::ArcSegment2(segment) => match b {
-                StatusNodeSegment::LineSegment2(other_segment) => {
-                    compare_segments(segment, other_segment, &event_point.clone())
-                }
-                StatusNodeSegment::ArcSegment2(other_segment) => {
-                    compare_segments(segment, other_segment, &event_point.clone())
-                }
-            },
-        });
-        let left = segments[0].clone();
-        let right = segments[segments.len() - 1].clone();
-        (left, right)
-    }
-
-    fn get_left_of_segment(
-        &self,
-        segment: &StatusNodeSegment<T>,
-        mid_order_traversal: &Vec<StatusNode<T>>,
-    ) -> Option<StatusNodeSegment<T>> {
-        for (index, status_node) in mid_order_traversal.iter().enumerate() {
-            let mut status_node_segment = status_node.segment.clone();
-            match status_node_segment {
-                StatusNodeSegment::LineSegment2(line_segment) => match segment {
-                    StatusNodeSegment::LineSegment2(segment) => {
-                        if line_segment.source().equals(&segment.source())
-                            && line_segment.target().equals(&segment.target())
-                        {
-                            if index == 0 {
-                                return None;
-                            }
-                            status_node_segment = mid_order_traversal[index - 1].segment.clone();
-                            return Some(status_node_segment);
-                        }
-                    }
-                    _ => {}
-                },
-                StatusNodeSegment::ArcSegment2(arc_segment) => match segment {
-                    StatusNodeSegment::ArcSegment2(segment) => {
-                        if arc_segment.center().equals(&segment.center())
-                            && arc_segment.radius().equals(segment.radius())
-                            && arc_segment.source().equals(&segment.source())
-                            && arc_segment.target().equals(&segment.target())
-                        {
-                            if index == 0 {
-                                return None;
-                            }
-                            status_node_segment = mid_order_traversal[index - 1].segment.clone();
-                            return Some(status_node_segment);
-                        }
-                    }
-                    _ => {}
-                },
+        let mut inserted = starting;
+        inserted.extend(
+            passing
+                .into_iter()
+                .filter(|id| self.pieces[*id].right != *event_point),
+        );
+        inserted.sort_by(|a, b| compare_pieces(&self.pieces, *a, *b, event_point));
+        for id in &inserted {
+            let pieces = &self.pieces;
+            self.handles[*id] = self
+                .status
+                .insert_by(*id, |a, b| compare_pieces(pieces, *a, *b, event_point));
+        }
+        // Unlike lines, an arc leaving the event can meet its neighbour there again.
+        for pair in inserted.windows(2) {
+            let curved = pair
+                .iter()
+                .any(|id| matches!(self.pieces[*id].segment, StatusNodeSegment::ArcSegment2(_)));
+            if curved {
+                self.find_new_event(self.handles[pair[0]], self.handles[pair[1]], event_point);
+            }
+        }
+        if let (Some(lowest), Some(highest)) = (inserted.first(), inserted.last()) {
+            let lowest = self.handles[*lowest];
+            let highest = self.handles[*highest];
+            if let Some(below) = self.status.prev(lowest) {
+                self.find_new_event(below, lowest, event_point);
+            }
+            if let Some(above) = self.status.next(highest) {
+                self.find_new_event(highest, above, event_point);
+            }
+        }
+        neighbours.sort();
+        neighbours.dedup();
+        for id in neighbours {
+            let handle = self.handles[id];
+            if handle == usize::MAX {
+                continue;
+            }
+            if let Some(below) = self.status.prev(handle) {
+                self.find_new_event(below, handle, event_point);
+            }
+            if let Some(above) = self.status.next(handle) {
+                self.find_new_event(handle, above, event_point);
             }
         }
-        None
     }
 
-    fn get_right_of_segment(
-        &self,
-        segment: &StatusNodeSegment<T>,
-        mid_order_traversal: &Vec<StatusNode<T>>,
-    ) -> Option<StatusNodeSegment<T>> {
-        for (index, status_node) in mid_order_traversal.iter().enumerate() {
-            let mut status_node_segment = status_node.segment.clone();
-            match status_node_segment {
-                StatusNodeSegment::LineSegment2(line_segment) => match segment {
-                    StatusNodeSegment::LineSegment2(segment) => {
-                        if line_segment.source().equals(&segment.source())
-                            && line_segment.target().equals(&segment.target())
-                        {
-                            if index == mid_order_traversal.len() - 1 {
-                                return None;
-                            }
-                            status_node_segment = mid_order_traversal[index + 1].segment.clone();
-                            return Some(status_node_segment);
-                        }
-                    }
-                    _ => {}
-                },
-                StatusNodeSegment::ArcSegment2(arc_segment) => match segment {
-                    StatusNodeSegment::ArcSegment2(segment) => {
-                        if arc_segment.center().equals(&segment.center())
-                            && arc_segment.radius().equals(segment.radius())
-                            && arc_segment.source().equals(&segment.source())
-                            && arc_segment.target().equals(&segment.target())
-                        {
-                            if index == mid_order_traversal.len() - 1 {
-                                return None;
-                            }
-                            status_node_segment = mid_order_traversal[index + 1].segment.clone();
-                            return Some(status_node_segment);
+    fn pieces_starting_at(&mut self, curve: usize, event_point: &Point2<T>) -> Vec<Piece<T>> {
+        let curve = &mut self.curves[curve];
+        match curve.segment {
+            StatusNodeSegment::LineSegment2(line_segment) => vec![Piece {
+                segment: curve.segment,
+                origin: curve.origin,
+                left: line_segment.source(),
+                right: line_segment.target(),
+                upper: false,
+            }],
+            StatusNodeSegment::ArcSegment2(arc_segment) => {
+                let origin = curve.origin;
+                curve
+                    .monotone
+                    .get_or_insert_with(|| arc_segment.monotone())
+                    .iter()
+                    .filter_map(|piece| {
+                        let upper = piece.is_top();
+                        let (left, right) = if upper {
+                            (piece.target(), piece.source())
+                        } else {
+                            (piece.source(), piece.target())
+                        };
+                        if left != *event_point {
+                            return None;
                         }
-                    }
-                    _ => {}
-                },
+                        Some(Piece {
+                            segment: StatusNodeSegment::ArcSegment2(*piece),
+                            origin,
+                            left,
+                            right,
+                            upper,
+                        })
+                    })
+                    .collect()
             }
         }
-        None
     }
 
-    fn find_intersection_points(
-        &mut self,
-        segment_a: &StatusNodeSegment<T>,
-        segment_b: &StatusNodeSegment<T>,
-        event_point: &Point2<T>,
-    ) {
-        let points = match segment_a {
-            StatusNodeSegment::LineSegment2(segment_a) => match segment_b {
-                StatusNodeSegment::LineSegment2(segment_b) => {
-                    segment_2_segment_2_intersection(segment_a, segment_b)
-                }
-                StatusNodeSegment::ArcSegment2(segment_b) => {
-                    segment_2_segment_2_intersection(segment_a, segment_b)
-                }
-            },
-            StatusNodeSegment::ArcSegment2(segment_a) => match segment_b {
-                StatusNodeSegment::LineSegment2(segment_b) => {
-                    segment_2_segment_2_intersection(segment_a, segment_b)
-                }
-                StatusNodeSegment::ArcSegment2(segment_b) => {
-                    segment_2_segment_2_intersection(segment_a, segment_b)
-                }
-            },
-        };
+    /// Remembers the pieces found crossing at each new event, since rounding may place the
+    /// point slightly off one of them.
+    fn find_new_event(&mut self, below: usize, above: usize, event_point: &Point2<T>) {
+        let below = self.status.get(below);
+        let above = self.status.get(above);
+        let points = intersection_points(&self.pieces[below].segment, &self.pieces[above].segment);
         for point in points {
-            if point.x() > event_point.x()
-                || (point.x().equals(event_point.x()) && point.y() > event_point.y())
-            {
-                self.event_queue.push(point);
+            if point < *event_point {
+                let event = self.events.entry(point).or_default();
+                event.crossings.push(below);
+                event.crossings.push(above);
             }
         }
     }
 }
 
-fn calculate_slope<T: NumberType>(source: &Point2<T>, target: &Point2<T>) -> Option<T> {
-    let x = target.x() - source.x();
-    let y = target.y() - source.y();
-    if x.equals(T::zero()) {
-        return None;
+impl<T: NumberType> Default for SweepSegment2Intersection<T> {
+    fn default() -> Self {
+        Self::new()
     }
-    Some(y / x)
 }
 
-fn calculate_tangent_slope<T: NumberType>(center: &Point2<T>, point: &Point2<T>) -> Option<T> {
-    let x = point.x() - center.x();
-    let y = point.y() - center.y();
-    if y.equals(T::zero()) {
-        return None;
-    }
-    Some(-x / y)
+fn intersection_points<T: NumberType>(
+    segment_a: &StatusNodeSegment<T>,
+    segment_b: &StatusNodeSegment<T>,
+) -> Vec<Point2<T>> {
+    let intersection = match (segment_a, segment_b) {
+        (StatusNodeSegment::LineSegment2(a), StatusNodeSegment::LineSegment2(b)) => {
+            segment_2_segment_2_intersection(a, b)
+        }
+        (StatusNodeSegment::LineSegment2(a), StatusNodeSegment::ArcSegment2(b)) => {
+            segment_2_segment_2_intersection(a, b)
+        }
+        (StatusNodeSegment::ArcSegment2(a), StatusNodeSegment::LineSegment2(b)) => {
+            segment_2_segment_2_intersection(a, b)
+        }
+        (StatusNodeSegment::ArcSegment2(a), StatusNodeSegment::ArcSegment2(b)) => {
+            segment_2_segment_2_intersection(a, b)
+        }
+    };
+    intersection.points()
 }
 
-fn calculate_segment_value<T: NumberType>(segment: &impl Segment2<T>, point: &Point2<T>) -> T {
-    match segment.segment_type() {
-        Segment2Type::LineSegment2 => {
-            let source = segment.source();
-            let target = segment.target();
-            if source.x().equals(target.x()) {
-                return point.y();
+/// Height of the piece at `x`; a vertical line piece answers `y` clamped to its extent.
+fn y_at<T: NumberType>(piece: &Piece<T>, x: T, y: T) -> T {
+    match piece.segment {
+        StatusNodeSegment::LineSegment2(_) => {
+            let (left, right) = (piece.left, piece.right);
+            if left.x().equals(right.x()) {
+                if y < left.y() {
+                    left.y()
+                } else if y > right.y() {
+                    right.y()
+                } else {
+                    y
+                }
+            } else {
+                left.y() + (x - left.x()) * (right.y() - left.y()) / (right.x() - left.x())
             }
-            let y = source.y()
-                + (point.x() - source.x()) * (target.y() - source.y()) / (target.x() - source.x());
-            y
         }
-        _ => {
-            let radius = segment.radius();
-            let center = segment.center();
-            let y = radius * radius - (point.x() - center.x()) * (point.x() - center.x());
-            let y = y.sqrt();
-            let y_a = center.y() + y;
-            let y_b = center.y() - y;
-            let p_a = Point2::new(point.x(), y_a);
-            let p_b = Point2::new(point.x(), y_b);
-            if is_point_2_on_arc_segment_2(&p_a, segment) {
-                y_a
-            } else if is_point_2_on_arc_segment_2(&p_b, segment) {
-                y_b
+        StatusNodeSegment::ArcSegment2(arc_segment) => {
+            let center = arc_segment.center();
+            let radius = arc_segment.radius();
+            let dx = x - center.x();
+            let square = radius * radius - dx * dx;
+            let dy = if square > T::zero() {
+                square.sqrt()
             } else {
-                point.y()
+                T::zero()
+            };
+            if piece.upper {
+                center.y() + dy
+            } else {
+                center.y() - dy
             }
         }
     }
 }
 
-fn get_target_of_segment<T: NumberType>(segment: &impl Segment2<T>) -> Point2<T> {
-    match segment.segment_type() {
-        Segment2Type::LineSegment2 => segment.target(),
-        _ => {
-            let arc_segment = ArcSegment2::new(
-                CircleSegment2::new(segment.center(), segment.radius()),
-                segment.source_radian(),
-                segment.target_radian(),
-            );
-            if arc_segment.is_top() {
-                arc_segment.source()
+/// Unit tangent at `(x, y)` pointing along the sweep.
+fn tangent<T: NumberType>(piece: &Piece<T>, x: T, y: T) -> Vector2<T> {
+    match piece.segment {
+        StatusNodeSegment::LineSegment2(_) => (piece.right - piece.left).normalize(),
+        StatusNodeSegment::ArcSegment2(arc_segment) => {
+            let center = arc_segment.center();
+            let (dx, dy) = (x - center.x(), y - center.y());
+            if piece.upper {
+                Vector2::new(dy, -dx).normalize()
             } else {
-                arc_segment.target()
+                Vector2::new(-dy, dx).normalize()
             }
         }
     }
 }
 
-fn calculate_mid_value<T: NumberType>(
-    segment_a: &impl Segment2<T>,
-    segment_b: &impl Segment2<T>,
-    event_point: &Point2<T>,
-) -> (T, T) {
-    let target_a = get_target_of_segment(segment_a);
-    let target_b = get_target_of_segment(segment_b);
-    let target = if target_a < target_b {
-        target_a
-    } else {
-        target_b
-    };
-    let mid = (*event_point + target) * T::from_f64(0.5);
-    let mid = Point2::new(mid.x(), mid.y());
-    (
-        calculate_segment_value(segment_a, &mid),
-        calculate_segment_value(segment_b, &mid),
-    )
+fn is_vertical<T: NumberType>(piece: &Piece<T>) -> bool {
+    matches!(piece.segment, StatusNodeSegment::LineSegment2(_))
+        && piece.left.x().equals(piece.right.x())
 }
 
-fn compare_segments_same_slope<T: NumberType>(
-    segment_a: &impl Segment2<T>,
-    segment_b: &impl Segment2<T>,
+/// Bottom-to-top order of the pieces just after the sweep line passes `event_point`.
+fn compare_pieces<T: NumberType>(
+    pieces: &[Piece<T>],
+    a: usize,
+    b: usize,
     event_point: &Point2<T>,
-) -> std::cmp::Ordering {
-    let (mid_a_value, mid_b_value) = calculate_mid_value(segment_a, segment_b, event_point);
-    if mid_a_value.equals(mid_b_value) {
-        return std::cmp::Ordering::Equal;
-    } else if mid_a_value < mid_b_value {
-        return std::cmp::Ordering::Less;
-    } else {
-        return std::cmp::Ordering::Greater;
-    }
-}
-
-fn compare_segments<T: NumberType>(
-    segment_a: &impl Segment2<T>,
-    segment_b: &impl Segment2<T>,
-    event_point: &Point2<T>,
-) -> std::cmp::Ordering {
-    let segment_a_value = calculate_segment_value(segment_a, event_point);
-    let segment_b_value = calculate_segment_value(segment_b, event_point);
-    if segment_a_value.equals(segment_b_value) {
-        let segment_a_slope = match segment_a.segment_type() {
-            Segment2Type::LineSegment2 => calculate_slope(&segment_a.source(), &segment_a.target()),
-            _ => calculate_tangent_slope(&segment_a.center(), event_point),
-        };
-        let segment_b_slope = match segment_b.segment_type() {
-            Segment2Type::LineSegment2 => calculate_slope(&segment_b.source(), &segment_b.target()),
-            _ => calculate_tangent_slope(&segment_b.center(), event_point),
+) -> Ordering {
+    let (piece_a, piece_b) = (&pieces[a], &pieces[b]);
+    let (x, y) = (event_point.x(), event_point.y());
+    let y_a = y_at(piece_a, x, y);
+    let y_b = y_at(piece_b, x, y);
+    if !y_a.equals(y_b) {
+        return if y_a < y_b {
+            Ordering::Less
+        } else {
+            Ordering::Greater
         };
-        match segment_a_slope {
-            Some(a_slope) => match segment_b_slope {
-                Some(b_slope) => {
-                    if a_slope.equals(b_slope) {
-                        compare_segments_same_slope(segment_a, segment_b, event_point)
-                    } else if a_slope < b_slope {
-                        std::cmp::Ordering::Less
-                    } else {
-                        std::cmp::Ordering::Greater
-                    }
-                }
-                None => std::cmp::Ordering::Less,
-            },
-            None => match segment_b_slope {
-                Some(_) => std::cmp::Ordering::Greater,
-                None => compare_segments_same_slope(segment_a, segment_b, event_point),
-            },
-        }
-    } else if segment_a_value < segment_b_value {
-        std::cmp::Ordering::Less
-    } else {
-        std::cmp::Ordering::Greater
     }
-}
-
-impl<T: NumberType> Eq for StatusNode<T> {}
-
-impl<T: NumberType> PartialEq for StatusNode<T> {
-    fn eq(&self, other: &Self) -> bool {
-        match self.segment {
-            StatusNodeSegment::LineSegment2(segment) => match other.segment {
-                StatusNodeSegment::LineSegment2(other_segment) => {
-                    segment.source().equals(&other_segment.source())
-                        && segment.target().equals(&other_segment.target())
-                }
-                _ => false,
-            },
-            StatusNodeSegment::ArcSegment2(segment) => match other.segment {
-                StatusNodeSegment::ArcSegment2(other_segment) => {
-                    segment.center().equals(&other_segment.center())
-                        && segment.radius().equals(other_segment.radius())
-                        && segment.source().equals(&other_segment.source())
-                        && segment.target().equals(&other_segment.target())
-                }
-                _ => false,
-            },
-        }
+    // A vertical piece covers the whole column above the event, so it goes above anything
+    // that leaves it rightwards, even along a vertical tangent.
+    match (is_vertical(piece_a), is_vertical(piece_b)) {
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        _ => {}
     }
-}
-
-impl<T: NumberType> Ord for StatusNode<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let self_value = self.value;
-        let other_value = other.value;
-        if self.value.equals(other.value) {
-            let mut point = self.point.clone();
-            if point > other.point {
-                point = other.point.clone();
-            }
-            match self.segment {
-                StatusNodeSegment::LineSegment2(segment) => match other.segment {
-                    StatusNodeSegment::LineSegment2(other_segment) => {
-                        if segment.source().equals(&other_segment.source())
-                            && segment.target().equals(&other_segment.target())
-                        {
-                            std::cmp::Ordering::Equal
-                        } else {
-                            compare_segments(&segment, &other_segment, &point)
-                        }
-                    }
-                    StatusNodeSegment::ArcSegment2(other_segment) => {
-                        compare_segments(&segment, &other_segment, &point)
-                    }
-                },
-                StatusNodeSegment::ArcSegment2(segment) => match other.segment {
-                    StatusNodeSegment::LineSegment2(other_segment) => {
-                        compare_segments(&segment, &other_segment, &point)
-                    }
-                    StatusNodeSegment::ArcSegment2(other_segment) => {
-                        if segment.center().equals(&other_segment.center())
-                            && segment.radius().equals(other_segment.radius())
-                            && segment.source().equals(&other_segment.source())
-                            && segment.target().equals(&other_segment.target())
-                        {
-                            std::cmp::Ordering::Equal
-                        } else {
-                            compare_segments(&segment, &other_segment, &point)
-                        }
-                    }
-                },
-            }
-        } else if self_value < other_value {
-            std::cmp::Ordering::Less
+    let cross = tangent(piece_a, x, y_a).cross(&tangent(piece_b, x, y_b));
+    if !cross.equals(T::zero()) {
+        return if cross > T::zero() {
+            Ordering::Less
         } else {
-            std::cmp::Ordering::Greater
-        }
+            Ordering::Greater
+        };
     }
-}
-
-impl<T: NumberType> PartialOrd for StatusNode<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+    let nearer = if piece_a.right > piece_b.right {
+        piece_a.right
+    } else {
+        piece_b.right
+    };
+    let mid_x = (x + nearer.x()) * T::from_f64(0.5);
+    if !mid_x.equals(x) {
+        let y_a = y_at(piece_a, mid_x, y);
+        let y_b = y_at(piece_b, mid_x, y);
+        if !y_a.equals(y_b) {
+            return if y_a < y_b {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
     }
+    (piece_a.origin, a).cmp(&(piece_b.origin, b))
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::algorithm::intersection::segment_2_segment_2::is_segment_2_segment_2_intersected;
 
     #[test]
     fn test_sweep_line_segment_2_intersection() {
@@ -911,5 +661,315 @@ mod tests {
                 Point2::new(-1.7015621187164243, 1.7015621187164243),
             ]
         );
+
+        let circle_segment = CircleSegment2::new(Point2::new(0.0, 0.0), 5.0);
+        let mut sweep = SweepSegment2Intersection::new();
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(-5.0, 5.0),
+            Point2::new(5.0, -5.0),
+        ));
+        sweep.push_segment(
+            &ArcSegment2::with_orientation(
+                circle_segment,
+                0.0,
+                std::f64::consts::PI,
+                Orientation::Clockwise,
+            )
+            .unwrap(),
+        );
+        sweep.push_segment(&ArcSegment2::new(
+            circle_segment,
+            std::f64::consts::PI,
+            std::f64::consts::PI,
+        ));
+        let result = sweep.intersection();
+        assert_eq!(
+            result,
+            vec![Point2::new(3.5355339059327373, -3.5355339059327373)]
+        );
+    }
+
+    #[test]
+    fn test_sweep_segment_2_intersection_with_segments() {
+        let mut sweep = SweepSegment2Intersection::new();
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 4.0),
+        ));
+        sweep.push_segment(&ArcSegment2::new(
+            CircleSegment2::new(Point2::new(9.0, 9.0), 1.0),
+            0.0,
+            0.0,
+        ));
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(0.0, 4.0),
+            Point2::new(4.0, 0.0),
+        ));
+        sweep.push_segment(&CircleSegment2::new(Point2::new(2.0, 2.0), 1.0));
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 4.0),
+        ));
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(10.0, 0.0),
+            Point2::new(11.0, 0.0),
+        ));
+        let result = sweep.intersection_with_segments();
+        let expected: Vec<(Point2<f64>, Vec<usize>)> = vec![
+            (Point2::new(2.0, 3.0), vec![3, 4]),
+            (Point2::new(2.0, 2.0), vec![0, 2, 4]),
+            (Point2::new(2.0, 1.0), vec![3, 4]),
+        ];
+        let diagonal = 2.0 - 0.5_f64.sqrt();
+        assert_eq!(result.len(), 7);
+        for (point, segment_indices) in expected {
+            let intersection = result
+                .iter()
+                .find(|intersection| intersection.point() == point)
+                .unwrap();
+            assert_eq!(intersection.segment_indices(), segment_indices);
+        }
+        let intersection = result
+            .iter()
+            .find(|intersection| intersection.point() == Point2::new(diagonal, diagonal))
+            .unwrap();
+        assert_eq!(intersection.segment_indices(), vec![0, 3]);
+        assert_eq!(
+            sweep.intersection(),
+            result
+                .iter()
+                .map(|intersection| intersection.point())
+                .collect::<Vec<_>>()
+        );
+        assert!(sweep.has_intersection());
+
+        let mut sweep = SweepSegment2Intersection::new();
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+        ));
+        sweep.push_segment(&CircleSegment2::new(Point2::new(2.0, 3.0), 1.0));
+        assert!(!sweep.has_intersection());
+        assert!(sweep.intersection_with_segments().is_empty());
+    }
+
+    #[test]
+    fn test_sweep_segment_2_intersection_with_segments_degenerate() {
+        let mut sweep = SweepSegment2Intersection::<f64>::new();
+        assert!(!sweep.has_intersection());
+        assert!(sweep.intersection_with_segments().is_empty());
+
+        // Segments sharing an endpoint meet once there, whatever their number.
+        let mut sweep = SweepSegment2Intersection::new();
+        for i in 0..6 {
+            let radian = i as f64;
+            sweep.push_segment(&LineSegment2::new(
+                Point2::new(0.0, 0.0),
+                Point2::new(radian.cos(), radian.sin()),
+            ));
+        }
+        let result = sweep.intersection_with_segments();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point(), Point2::new(0.0, 0.0));
+        assert_eq!(result[0].segment_indices(), vec![0, 1, 2, 3, 4, 5]);
+
+        // Overlapping and vertical segments meet at the ends of the overlap and where crossed.
+        let mut sweep = SweepSegment2Intersection::new();
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 4.0),
+        ));
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(1.0, 6.0),
+            Point2::new(1.0, 2.0),
+        ));
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(0.0, 3.0),
+            Point2::new(2.0, 3.0),
+        ));
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(1.0, 6.0),
+            Point2::new(3.0, 6.0),
+        ));
+        let result: Vec<(Point2<f64>, Vec<usize>)> = sweep
+            .intersection_with_segments()
+            .iter()
+            .map(|intersection| (intersection.point(), intersection.segment_indices()))
+            .collect();
+        assert_eq!(
+            result,
+            vec![
+                (Point2::new(1.0, 6.0), vec![1, 3]),
+                (Point2::new(1.0, 4.0), vec![0, 1]),
+                (Point2::new(1.0, 3.0), vec![0, 1, 2]),
+                (Point2::new(1.0, 2.0), vec![0, 1]),
+            ]
+        );
+
+        // The early exit leaves the sweep ready for a full run.
+        assert!(sweep.has_intersection());
+        assert_eq!(sweep.intersection_with_segments().len(), 4);
+
+        let mut sweep = SweepSegment2Intersection::new();
+        sweep.push_segment(&CircleSegment2::new(Point2::new(0.0, 0.0), 1.0));
+        sweep.push_segment(&CircleSegment2::new(Point2::new(2.0, 0.0), 1.0));
+        let result = sweep.intersection_with_segments();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].point(), Point2::new(1.0, 0.0));
+        assert_eq!(result[0].segment_indices(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_sweep_segment_2_intersection_arc_meets_again() {
+        let mut sweep = SweepSegment2Intersection::new();
+        sweep.push_segment(&ArcSegment2::new(
+            CircleSegment2::new(Point2::new(3.0, 5.0), 2.0),
+            0.0,
+            5.0,
+        ));
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(5.0, 2.0),
+            Point2::new(1.0, 4.0),
+        ));
+        sweep.push_segment(&LineSegment2::new(
+            Point2::new(1.0, 5.0),
+            Point2::new(2.0, 1.0),
+        ));
+        let result: Vec<(Point2<f64>, Vec<usize>)> = sweep
+            .intersection_with_segments()
+            .iter()
+            .map(|intersection| (intersection.point(), intersection.segment_indices()))
+            .collect();
+        assert_eq!(
+            result,
+            vec![
+                (Point2::new(3.0, 3.0), vec![0, 1]),
+                (Point2::new(1.4, 3.8), vec![0, 1]),
+                (Point2::new(9.0 / 7.0, 27.0 / 7.0), vec![1, 2]),
+                (Point2::new(21.0 / 17.0, 69.0 / 17.0), vec![0, 2]),
+                (Point2::new(1.0, 5.0), vec![0, 2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sweep_segment_2_intersection_many_segments() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let line_segments: Vec<LineSegment2<f64>> = (0..200)
+            .map(|_| {
+                let source = Point2::new(random() * 10.0, random() * 10.0);
+                let radian = random() * std::f64::consts::PI * 2.0;
+                LineSegment2::new(
+                    source,
+                    Point2::new(source.x() + radian.cos(), source.y() + radian.sin()),
+                )
+            })
+            .collect();
+        let arc_segments: Vec<ArcSegment2<f64>> = (0..100)
+            .map(|_| {
+                let source_radian = random() * std::f64::consts::PI * 2.0;
+                ArcSegment2::new(
+                    CircleSegment2::new(Point2::new(random() * 10.0, random() * 10.0), 0.5),
+                    source_radian,
+                    source_radian + random() * std::f64::consts::PI * 2.0,
+                )
+            })
+            .collect();
+        let mut sweep = SweepSegment2Intersection::new();
+        line_segments
+            .iter()
+            .for_each(|segment| sweep.push_segment(segment));
+        arc_segments
+            .iter()
+            .for_each(|segment| sweep.push_segment(segment));
+        let mut pairs: Vec<(usize, usize)> = sweep
+            .intersection_with_segments()
+            .iter()
+            .flat_map(|intersection| {
+                let indices = intersection.segment_indices();
+                (0..indices.len())
+                    .flat_map(|i| (i + 1..indices.len()).map(move |j| (i, j)))
+                    .map(|(i, j)| (indices[i], indices[j]))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        pairs.sort();
+        pairs.dedup();
+
+        let count = line_segments.len();
+        let mut expected = Vec::new();
+        for i in 0..count + arc_segments.len() {
+            for j in i + 1..count + arc_segments.len() {
+                let intersected = match (i < count, j < count) {
+                    (true, true) => {
+                        is_segment_2_segment_2_intersected(&line_segments[i], &line_segments[j])
+                    }
+                    (true, false) => is_segment_2_segment_2_intersected(
+                        &line_segments[i],
+                        &arc_segments[j - count],
+                    ),
+                    _ => is_segment_2_segment_2_intersected(
+                        &arc_segments[i - count],
+                        &arc_segments[j - count],
+                    ),
+                };
+                if intersected {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert!(expected.len() > 100);
+        assert_eq!(pairs, expected);
+    }
+
+    #[test]
+    fn test_sweep_segment_2_intersection_grid_and_chain() {
+        let mut sweep = SweepSegment2Intersection::new();
+        for i in 0..20 {
+            let offset = i as f64;
+            sweep.push_segment(&LineSegment2::new(
+                Point2::new(-1.0, offset),
+                Point2::new(20.0, offset),
+            ));
+            sweep.push_segment(&LineSegment2::new(
+                Point2::new(offset, -1.0),
+                Point2::new(offset, 20.0),
+            ));
+        }
+        let result = sweep.intersection_with_segments();
+        assert_eq!(result.len(), 400);
+        for intersection in &result {
+            let point = intersection.point();
+            let mut expected = vec![2 * point.y() as usize, 2 * point.x() as usize + 1];
+            expected.sort();
+            assert_eq!(intersection.segment_indices(), expected);
+        }
+
+        // A zig-zag chain only meets itself at the shared endpoints.
+        let mut sweep = SweepSegment2Intersection::new();
+        let vertices: Vec<Point2<f64>> = (0..=200)
+            .map(|i| Point2::new(i as f64, (i % 2) as f64))
+            .collect();
+        for pair in vertices.windows(2) {
+            sweep.push_segment(&LineSegment2::new(pair[0], pair[1]));
+        }
+        let mut result: Vec<(Point2<f64>, Vec<usize>)> = sweep
+            .intersection_with_segments()
+            .iter()
+            .map(|intersection| (intersection.point(), intersection.segment_indices()))
+            .collect();
+        result.reverse();
+        assert_eq!(
+            result,
+            (1..200)
+                .map(|i| (vertices[i], vec![i - 1, i]))
+                .collect::<Vec<_>>()
+        );
     }
 }
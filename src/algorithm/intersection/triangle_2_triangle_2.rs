@@ -0,0 +1,64 @@
+use crate::kernel::{number_type::NumberType, triangle_2::Triangle2};
+
+use super::polygon_2_polygon_2::has_separating_axis;
+
+/// Separating-axis test; triangles touching at the boundary intersect.
+pub fn is_triangle_2_triangle_2_intersected<T: NumberType>(
+    triangle_a: &Triangle2<T>,
+    triangle_b: &Triangle2<T>,
+) -> bool {
+    !has_separating_axis(&triangle_a.vertices(), &triangle_b.vertices())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::point_2::Point2;
+
+    #[test]
+    fn test_is_triangle_2_triangle_2_intersected() {
+        let triangle = Triangle2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 4.0),
+        );
+        let other = Triangle2::new(
+            Point2::new(1.0, 1.0),
+            Point2::new(5.0, 1.0),
+            Point2::new(1.0, 5.0),
+        );
+        assert!(is_triangle_2_triangle_2_intersected(&triangle, &other));
+
+        let other = Triangle2::new(
+            Point2::new(2.0, 2.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(2.0, 5.0),
+        );
+        assert!(is_triangle_2_triangle_2_intersected(&triangle, &other));
+
+        let other = Triangle2::new(
+            Point2::new(3.0, 3.0),
+            Point2::new(4.0, 3.0),
+            Point2::new(3.0, 4.0),
+        );
+        assert!(!is_triangle_2_triangle_2_intersected(&triangle, &other));
+
+        let other = Triangle2::new(
+            Point2::new(1.0, 1.0),
+            Point2::new(0.5, 0.5),
+            Point2::new(1.0, 0.5),
+        );
+        assert!(is_triangle_2_triangle_2_intersected(&triangle, &other));
+        assert!(is_triangle_2_triangle_2_intersected(&other, &triangle));
+
+        let degenerate = Triangle2::new(
+            Point2::new(5.0, 0.0),
+            Point2::new(6.0, 0.0),
+            Point2::new(7.0, 0.0),
+        );
+        assert!(!is_triangle_2_triangle_2_intersected(
+            &triangle,
+            &degenerate
+        ));
+    }
+}
@@ -47,12 +47,23 @@ pub enum Point2Ray2Location {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Point2ArcSegment2Location {
     On,
-    NotOn,
+    Left,
+    Right,
+    Cocircular,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Point2Sector2Location {
+    On,
+    Inside,
+    Outside,
 }
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Point2Line2Location {
     On,
-    NotOn,
+    Left,
+    Right,
 }
@@ -1,6 +1,9 @@
-use crate::kernel::{number_type::NumberType, point_2::Point2, segment_2::Segment2};
+use crate::kernel::{
+    arc_segment_2::normalize_radian, number_type::NumberType, point_2::Point2, segment_2::Segment2,
+    util_enum::Orientation,
+};
 
-use super::location_enum::Point2ArcSegment2Location;
+use super::location_enum::{Point2ArcSegment2Location, Point2Sector2Location};
 
 pub fn is_point_2_on_arc_segment_2<T: NumberType>(
     point: &Point2<T>,
@@ -13,37 +16,181 @@ pub fn is_point_2_on_arc_segment_2<T: NumberType>(
     }
 }
 
+/// Left and right of the supporting circle directed along the arc, so left is inside for counterclockwise arcs.
+///
+/// Points on the supporting circle but off the arc are cocircular. Circles are counterclockwise.
 pub fn locate_point_2_arc_segment_2<T: NumberType>(
     point: &Point2<T>,
     arc_segment: &impl Segment2<T>,
 ) -> Point2ArcSegment2Location {
-    let center = arc_segment.center();
     let radius = arc_segment.radius();
-    let distance = center.distance(point);
+    let distance = arc_segment.center().distance(point);
     if distance.equals(radius) {
-        let vector = *point - center;
-        let source = arc_segment.source() - center;
-        let target = arc_segment.target() - center;
-        let mut radian = source.radian_to(&target);
-        if source == target {
-            radian = T::pi() * T::from_f64(2.0);
-        }
-        let radian_source = vector.radian_to(&source);
-        let radian_target = vector.radian_to(&target);
-        let source_radian = source.radian_to(&vector);
-        let target_radian = target.radian_to(&vector);
-        if radian_source.equals(T::zero())
-            || radian_target.equals(T::zero())
-            || source_radian.equals(T::zero())
-            || target_radian.equals(T::zero())
-        {
-            Point2ArcSegment2Location::On
-        } else if (source_radian + radian_target).equals(radian) {
+        return if is_on_supported_arc(point, arc_segment) {
             Point2ArcSegment2Location::On
         } else {
-            Point2ArcSegment2Location::NotOn
+            Point2ArcSegment2Location::Cocircular
+        };
+    }
+    let inside = distance < radius;
+    match (inside, arc_segment.orientation()) {
+        (true, Orientation::CounterClockwise) | (false, Orientation::Clockwise) => {
+            Point2ArcSegment2Location::Left
         }
+        _ => Point2ArcSegment2Location::Right,
+    }
+}
+
+/// The sector is the unbounded wedge at the center swept by the arc, bounded by the rays through its endpoints.
+///
+/// The wedge of a full circle covers the plane, the wedge of a zero-length arc is the ray through its source.
+pub fn locate_point_2_arc_segment_2_sector<T: NumberType>(
+    point: &Point2<T>,
+    arc_segment: &impl Segment2<T>,
+) -> Point2Sector2Location {
+    let sweep = arc_segment.sweep_radian();
+    if sweep.equals(T::from_f64(2.0) * T::pi()) {
+        return Point2Sector2Location::Inside;
+    }
+    let center = arc_segment.center();
+    if point.equals(&center) {
+        return Point2Sector2Location::On;
+    }
+    let offset = sector_offset(point, arc_segment);
+    if offset.equals(T::zero()) || offset.equals(sweep) || offset.equals(T::from_f64(2.0) * T::pi())
+    {
+        Point2Sector2Location::On
+    } else if offset < sweep {
+        Point2Sector2Location::Inside
     } else {
-        Point2ArcSegment2Location::NotOn
+        Point2Sector2Location::Outside
+    }
+}
+
+/// `point` is assumed on the supporting circle; a zero-length arc covers only its source.
+fn is_on_supported_arc<T: NumberType>(point: &Point2<T>, arc_segment: &impl Segment2<T>) -> bool {
+    let sweep = arc_segment.sweep_radian();
+    if sweep.equals(T::from_f64(2.0) * T::pi()) {
+        return true;
+    }
+    if *point == arc_segment.source() || *point == arc_segment.target() {
+        return true;
+    }
+    if sweep.equals(T::zero()) {
+        return false;
+    }
+    let offset = sector_offset(point, arc_segment);
+    offset < sweep || offset.equals(sweep)
+}
+
+/// Angle from the source ray to the ray through `point`, measured along the orientation in [0, 2pi).
+fn sector_offset<T: NumberType>(point: &Point2<T>, arc_segment: &impl Segment2<T>) -> T {
+    let vector = *point - arc_segment.center();
+    let radian = vector.y().atan2(vector.x());
+    match arc_segment.orientation() {
+        Orientation::CounterClockwise => normalize_radian(radian - arc_segment.source_radian()),
+        Orientation::Clockwise => normalize_radian(arc_segment.source_radian() - radian),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+    use crate::kernel::{arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2};
+
+    #[test]
+    fn test_locate_point_2_arc_segment_2() {
+        let support = CircleSegment2::new(Point2::new(0.0, 0.0), 2.0);
+        let arc_segment = ArcSegment2::new(support, 0.0, PI / 2.0);
+        let reversed =
+            ArcSegment2::with_orientation(support, PI / 2.0, 0.0, Orientation::Clockwise).unwrap();
+
+        let point = Point2::new(2.0_f64.sqrt(), 2.0_f64.sqrt());
+        assert_eq!(
+            locate_point_2_arc_segment_2(&point, &arc_segment),
+            Point2ArcSegment2Location::On
+        );
+        assert!(is_point_2_on_arc_segment_2(&point, &reversed));
+        let point = Point2::new(0.0, -2.0);
+        assert_eq!(
+            locate_point_2_arc_segment_2(&point, &arc_segment),
+            Point2ArcSegment2Location::Cocircular
+        );
+        let point = Point2::new(1.0, 1.0);
+        assert_eq!(
+            locate_point_2_arc_segment_2(&point, &arc_segment),
+            Point2ArcSegment2Location::Left
+        );
+        assert_eq!(
+            locate_point_2_arc_segment_2(&point, &reversed),
+            Point2ArcSegment2Location::Right
+        );
+        let point = Point2::new(-3.0, 0.0);
+        assert_eq!(
+            locate_point_2_arc_segment_2(&point, &arc_segment),
+            Point2ArcSegment2Location::Right
+        );
+        assert_eq!(
+            locate_point_2_arc_segment_2(&point, &reversed),
+            Point2ArcSegment2Location::Left
+        );
+        assert_eq!(
+            locate_point_2_arc_segment_2(&Point2::new(0.0, 0.0), &support),
+            Point2ArcSegment2Location::Left
+        );
+        assert_eq!(
+            locate_point_2_arc_segment_2(&Point2::new(0.0, -2.0), &support),
+            Point2ArcSegment2Location::On
+        );
+
+        let zero_length = ArcSegment2::new(support, PI, PI);
+        assert_eq!(
+            locate_point_2_arc_segment_2(&Point2::new(-2.0, 0.0), &zero_length),
+            Point2ArcSegment2Location::On
+        );
+        assert_eq!(
+            locate_point_2_arc_segment_2(&Point2::new(2.0, 0.0), &zero_length),
+            Point2ArcSegment2Location::Cocircular
+        );
+    }
+
+    #[test]
+    fn test_locate_point_2_arc_segment_2_sector() {
+        let support = CircleSegment2::new(Point2::new(0.0, 0.0), 2.0);
+        let arc_segment = ArcSegment2::new(support, 0.0, PI / 2.0);
+        let reversed =
+            ArcSegment2::with_orientation(support, PI / 2.0, 0.0, Orientation::Clockwise).unwrap();
+        for arc_segment in [arc_segment, reversed] {
+            assert_eq!(
+                locate_point_2_arc_segment_2_sector(&Point2::new(5.0, 1.0), &arc_segment),
+                Point2Sector2Location::Inside
+            );
+            assert_eq!(
+                locate_point_2_arc_segment_2_sector(&Point2::new(5.0, 0.0), &arc_segment),
+                Point2Sector2Location::On
+            );
+            assert_eq!(
+                locate_point_2_arc_segment_2_sector(&Point2::new(0.0, 0.5), &arc_segment),
+                Point2Sector2Location::On
+            );
+            assert_eq!(
+                locate_point_2_arc_segment_2_sector(&Point2::new(0.0, 0.0), &arc_segment),
+                Point2Sector2Location::On
+            );
+            assert_eq!(
+                locate_point_2_arc_segment_2_sector(&Point2::new(-1.0, 1.0), &arc_segment),
+                Point2Sector2Location::Outside
+            );
+            assert_eq!(
+                locate_point_2_arc_segment_2_sector(&Point2::new(1.0, -1e-3), &arc_segment),
+                Point2Sector2Location::Outside
+            );
+        }
+        assert_eq!(
+            locate_point_2_arc_segment_2_sector(&Point2::new(1.0, -1e-3), &support),
+            Point2Sector2Location::Inside
+        );
     }
 }
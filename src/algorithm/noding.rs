@@ -0,0 +1,2 @@
+pub mod segment_2;
+pub mod snap_rounding_2;
@@ -0,0 +1,295 @@
+use crate::algorithm::intersection::sweep_segment_2_intersection::SweepSegment2Intersection;
+use crate::kernel::{
+    arc_segment_2::ArcSegment2, curve_2::Curve2, line_arc_segment_2::LineArcSegment2,
+    line_segment_2::LineSegment2, number_type::NumberType, point_2::Point2, segment_2::Segment2,
+};
+
+/// Piece of a noded arrangement, running in the direction of its parent segment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodedSegment2<T: NumberType> {
+    segment: LineArcSegment2<T>,
+    parent: usize,
+    coincident_parents: Vec<usize>,
+}
+
+impl<T: NumberType> NodedSegment2<T> {
+    pub fn segment(&self) -> LineArcSegment2<T> {
+        self.segment
+    }
+
+    /// Index of the input segment the piece was cut from.
+    pub fn parent(&self) -> usize {
+        self.parent
+    }
+
+    /// Later input segments overlapping the piece exactly, whose own copies were dropped.
+    pub fn coincident_parents(&self) -> Vec<usize> {
+        self.coincident_parents.clone()
+    }
+}
+
+/// Splits the segments at every point where they meet, so the pieces only touch at shared
+/// endpoints. Overlapping pieces are kept once, on the lowest parent; zero-length input is
+/// dropped.
+///
+/// Pieces come out grouped by parent, in input order and from source to target within a parent.
+pub fn node_segment_2<T: NumberType>(segments: &[LineArcSegment2<T>]) -> Vec<NodedSegment2<T>> {
+    let mut sweep = SweepSegment2Intersection::new();
+    for segment in segments {
+        sweep.push_segment(segment);
+    }
+    let mut cuts: Vec<Vec<Point2<T>>> = vec![Vec::new(); segments.len()];
+    for intersection in sweep.intersection_with_segments() {
+        for index in intersection.segment_indices() {
+            cuts[index].push(intersection.point());
+        }
+    }
+    let mut pieces: Vec<NodedSegment2<T>> = Vec::new();
+    for (parent, (segment, cuts)) in segments.iter().zip(cuts).enumerate() {
+        if is_zero_length(segment) {
+            continue;
+        }
+        if cuts.is_empty() {
+            pieces.push(NodedSegment2 {
+                segment: *segment,
+                parent,
+                coincident_parents: Vec::new(),
+            });
+            continue;
+        }
+        let mut source = segment.source();
+        let mut target = segment.target();
+        let mut interior: Vec<(T, Point2<T>)> = Vec::new();
+        // Cut points are shared by every parent meeting there, so they replace the endpoints.
+        for cut in cuts {
+            if cut == source || cut == target {
+                if cut == source {
+                    source = cut;
+                }
+                if cut == target {
+                    target = cut;
+                }
+            } else {
+                interior.push((segment.closest_parameter(&cut), cut));
+            }
+        }
+        interior.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let mut points = vec![source];
+        for (_, point) in interior {
+            if *points.last().unwrap() != point {
+                points.push(point);
+            }
+        }
+        points.push(target);
+        // A closed arc cut only at its endpoint stays whole.
+        if points.len() == 2 && points[0] == points[1] {
+            pieces.push(NodedSegment2 {
+                segment: *segment,
+                parent,
+                coincident_parents: Vec::new(),
+            });
+            continue;
+        }
+        for pair in points.windows(2) {
+            if let Some(piece) = piece_between(segment, &pair[0], &pair[1]) {
+                pieces.push(NodedSegment2 {
+                    segment: piece,
+                    parent,
+                    coincident_parents: Vec::new(),
+                });
+            }
+        }
+    }
+    merge_coincident(pieces)
+}
+
+fn is_zero_length<T: NumberType>(segment: &LineArcSegment2<T>) -> bool {
+    match segment {
+        LineArcSegment2::LineSegment2(line_segment) => {
+            line_segment.source() == line_segment.target()
+        }
+        LineArcSegment2::ArcSegment2(arc_segment) => arc_segment.is_zero_length(),
+    }
+}
+
+fn piece_between<T: NumberType>(
+    segment: &LineArcSegment2<T>,
+    source: &Point2<T>,
+    target: &Point2<T>,
+) -> Option<LineArcSegment2<T>> {
+    match segment {
+        LineArcSegment2::LineSegment2(_) => {
+            if source == target {
+                return None;
+            }
+            Some(LineSegment2::new(*source, *target).into())
+        }
+        LineArcSegment2::ArcSegment2(arc_segment) => {
+            let center = arc_segment.center();
+            let source_vector = *source - center;
+            let target_vector = *target - center;
+            let piece = ArcSegment2::with_orientation(
+                arc_segment.support(),
+                source_vector.y().atan2(source_vector.x()),
+                target_vector.y().atan2(target_vector.x()),
+                arc_segment.orientation(),
+            )
+            .ok()?;
+            if piece.is_zero_length() {
+                return None;
+            }
+            Some(piece.into())
+        }
+    }
+}
+
+fn mid_point<T: NumberType>(segment: &LineArcSegment2<T>) -> Point2<T> {
+    segment.point_at(T::from_f64(0.5))
+}
+
+/// Pieces with the same endpoints, in either direction, and the same midpoint cover the same
+/// points; only the first is kept.
+fn merge_coincident<T: NumberType>(pieces: Vec<NodedSegment2<T>>) -> Vec<NodedSegment2<T>> {
+    let keys: Vec<(Point2<T>, Point2<T>)> = pieces
+        .iter()
+        .map(|piece| {
+            let source = piece.segment.source();
+            let target = piece.segment.target();
+            if source > target {
+                (source, target)
+            } else {
+                (target, source)
+            }
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..pieces.len()).collect();
+    order.sort_by(|a, b| keys[*a].cmp(&keys[*b]).then(a.cmp(b)));
+    let mut representative: Vec<usize> = (0..pieces.len()).collect();
+    let mut group: Vec<usize> = Vec::new();
+    for index in order {
+        if group
+            .first()
+            .is_some_and(|first| keys[*first] != keys[index])
+        {
+            group.clear();
+        }
+        let middle = mid_point(&pieces[index].segment);
+        match group
+            .iter()
+            .find(|other| mid_point(&pieces[**other].segment) == middle)
+        {
+            Some(other) => representative[index] = *other,
+            None => group.push(index),
+        }
+    }
+    let mut positions: Vec<usize> = vec![usize::MAX; pieces.len()];
+    let mut result: Vec<NodedSegment2<T>> = Vec::new();
+    for (index, piece) in pieces.into_iter().enumerate() {
+        let kept = representative[index];
+        if kept == index {
+            positions[index] = result.len();
+            result.push(piece);
+        } else {
+            let kept = &mut result[positions[kept]];
+            if piece.parent != kept.parent && !kept.coincident_parents.contains(&piece.parent) {
+                kept.coincident_parents.push(piece.parent);
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm::intersection::segment_2_segment_2::segment_2_segment_2_intersection,
+        kernel::circle_segment_2::CircleSegment2,
+    };
+
+    #[test]
+    fn test_node_segment_2() {
+        let segments: Vec<LineArcSegment2<f64>> = vec![
+            LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(4.0, 4.0)).into(),
+            LineSegment2::new(Point2::new(0.0, 4.0), Point2::new(4.0, 0.0)).into(),
+            LineSegment2::new(Point2::new(1.0, 1.0), Point2::new(3.0, 3.0)).into(),
+            ArcSegment2::new(
+                CircleSegment2::new(Point2::new(2.0, 0.0), 2.0),
+                0.0,
+                std::f64::consts::PI,
+            )
+            .into(),
+            LineSegment2::new(Point2::new(5.0, 5.0), Point2::new(5.0, 5.0)).into(),
+        ];
+        let result = node_segment_2(&segments);
+        let summary: Vec<_> = result
+            .iter()
+            .map(|piece| {
+                (
+                    piece.segment().source(),
+                    piece.segment().target(),
+                    piece.parent(),
+                    piece.coincident_parents(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), 0, vec![]),
+                (Point2::new(1.0, 1.0), Point2::new(2.0, 2.0), 0, vec![2]),
+                (Point2::new(2.0, 2.0), Point2::new(3.0, 3.0), 0, vec![2]),
+                (Point2::new(3.0, 3.0), Point2::new(4.0, 4.0), 0, vec![]),
+                (Point2::new(0.0, 4.0), Point2::new(2.0, 2.0), 1, vec![]),
+                (Point2::new(2.0, 2.0), Point2::new(4.0, 0.0), 1, vec![]),
+                (Point2::new(4.0, 0.0), Point2::new(2.0, 2.0), 3, vec![]),
+                (Point2::new(2.0, 2.0), Point2::new(0.0, 0.0), 3, vec![]),
+            ]
+        );
+        assert_no_crossing(&result);
+
+        // Only touching the line at its endpoint, the circle must survive whole.
+        let circle: LineArcSegment2<f64> = ArcSegment2::new(
+            CircleSegment2::new(Point2::new(0.0, 0.0), 1.0),
+            0.0,
+            std::f64::consts::PI * 2.0,
+        )
+        .into();
+        let line: LineArcSegment2<f64> =
+            LineSegment2::new(Point2::new(1.0, 0.0), Point2::new(3.0, 0.0)).into();
+        let result = node_segment_2(&[circle, line]);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].segment(), circle);
+        assert_eq!(result[0].parent(), 0);
+        assert_eq!(result[1].segment(), line);
+
+        // Cut elsewhere too, the circle splits into pieces covering all of it.
+        let line: LineArcSegment2<f64> =
+            LineSegment2::new(Point2::new(-3.0, 0.0), Point2::new(3.0, 0.0)).into();
+        let result = node_segment_2(&[circle, line]);
+        let arcs: Vec<_> = result.iter().filter(|piece| piece.parent() == 0).collect();
+        assert_eq!(arcs.len(), 2);
+        assert!(arcs
+            .iter()
+            .map(|piece| piece.segment().length())
+            .sum::<f64>()
+            .equals(std::f64::consts::PI * 2.0));
+        assert_no_crossing(&result);
+    }
+
+    fn assert_no_crossing(result: &[NodedSegment2<f64>]) {
+        for piece in result {
+            for other in result {
+                if std::ptr::eq(piece, other) {
+                    continue;
+                }
+                for point in
+                    segment_2_segment_2_intersection(&piece.segment(), &other.segment()).points()
+                {
+                    let endpoints = [piece.segment().source(), piece.segment().target()];
+                    assert!(endpoints.contains(&point));
+                }
+            }
+        }
+    }
+}
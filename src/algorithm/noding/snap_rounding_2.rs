@@ -0,0 +1,353 @@
+use std::collections::BTreeSet;
+
+use crate::algorithm::intersection::sweep_segment_2_intersection::SweepSegment2Intersection;
+use crate::kernel::{
+    line_segment_2::LineSegment2, number_type::NumberType, point_2::Point2, segment_2::Segment2,
+    util_enum::GeometryError,
+};
+
+/// Iterated snap rounding of line segments to the grid of multiples of `pixel_size`.
+///
+/// Pixels are the half-open squares of side `pixel_size` around the grid points, and a pixel is
+/// hot when it holds an endpoint or an intersection point. Each segment becomes the chain of
+/// the hot pixels it passes, and each link of a chain is routed again through the hot pixels it
+/// passes until none is left, so no output vertex lies near a link it is not on. Output links
+/// only meet at shared grid vertices or along shared links.
+///
+/// Returns one chain of grid points per segment, from source to target; a segment inside a
+/// single pixel collapses to one point.
+pub fn snap_round_segment_2<T: NumberType>(
+    segments: &[LineSegment2<T>],
+    pixel_size: T,
+) -> Result<Vec<Vec<Point2<T>>>, GeometryError> {
+    if pixel_size <= T::zero() {
+        return Err(GeometryError::InvalidLength);
+    }
+    let mut sweep = SweepSegment2Intersection::new();
+    for segment in segments {
+        sweep.push_segment(segment);
+    }
+    let mut grid = HotPixels {
+        centers: BTreeSet::new(),
+        pixel_size,
+        top: T::zero(),
+    };
+    let points = segments
+        .iter()
+        .flat_map(|segment| [segment.source(), segment.target()])
+        .chain(sweep.intersection());
+    for point in points {
+        let center = grid.snap(&point);
+        if grid.centers.is_empty() || center.y() > grid.top {
+            grid.top = center.y();
+        }
+        grid.centers.insert(center);
+    }
+    Ok(segments
+        .iter()
+        .map(|segment| {
+            let source = grid.snap(&segment.source());
+            let mut chain = vec![source];
+            let mut previous = source;
+            for center in grid.passed(&segment.source(), &segment.target()) {
+                if center != previous {
+                    grid.route(&previous, &center, &mut chain);
+                    previous = center;
+                }
+            }
+            chain
+        })
+        .collect())
+}
+
+struct HotPixels<T: NumberType> {
+    centers: BTreeSet<Point2<T>>,
+    pixel_size: T,
+    top: T,
+}
+
+impl<T: NumberType> HotPixels<T> {
+    fn snap_coordinate(&self, value: T) -> T {
+        (value / self.pixel_size + T::from_f64(0.5)).floor() * self.pixel_size
+    }
+
+    fn snap(&self, point: &Point2<T>) -> Point2<T> {
+        Point2::new(
+            self.snap_coordinate(point.x()),
+            self.snap_coordinate(point.y()),
+        )
+    }
+
+    /// Appends the chain after `source` up to `target`, both centers, through every hot pixel
+    /// the link between them passes.
+    fn route(&self, source: &Point2<T>, target: &Point2<T>, chain: &mut Vec<Point2<T>>) {
+        let inner: Vec<Point2<T>> = self
+            .passed(source, target)
+            .into_iter()
+            .filter(|center| center != source && center != target)
+            .collect();
+        let mut previous = *source;
+        for center in inner {
+            if center != previous {
+                self.route(&previous, &center, chain);
+                previous = center;
+            }
+        }
+        chain.push(*target);
+    }
+
+    /// Centers of the hot pixels the segment passes, in the order it enters them.
+    fn passed(&self, source: &Point2<T>, target: &Point2<T>) -> Vec<Point2<T>> {
+        let half = self.pixel_size / T::from_f64(2.0);
+        let (left, right) = if source.x() < target.x() {
+            (source.x(), target.x())
+        } else {
+            (target.x(), source.x())
+        };
+        let first_column = self.snap_coordinate(right);
+        let last_column = self.snap_coordinate(left);
+        let mut entered: Vec<(T, Point2<T>)> = Vec::new();
+        let mut cursor = Point2::new(first_column, self.top);
+        while let Some(center) = self.centers.range(cursor..).next().copied() {
+            let column = center.x();
+            if column < last_column - half {
+                break;
+            }
+            if let Some((low, high)) = y_range_in_slab(source, target, column - half, column + half)
+            {
+                let from = Point2::new(column, self.snap_coordinate(high));
+                let to = Point2::new(column, self.snap_coordinate(low));
+                for center in self.centers.range(from..=to) {
+                    if let Some(t) = pixel_entry(source, target, center, half) {
+                        entered.push((t, *center));
+                    }
+                }
+            }
+            cursor = Point2::new(column - self.pixel_size, self.top);
+        }
+        entered.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        entered.into_iter().map(|(_, center)| center).collect()
+    }
+}
+
+fn y_range_in_slab<T: NumberType>(
+    source: &Point2<T>,
+    target: &Point2<T>,
+    left: T,
+    right: T,
+) -> Option<(T, T)> {
+    let (low_t, high_t) = if source.x() == target.x() {
+        (T::zero(), T::from_f64(1.0))
+    } else {
+        let left_t = (left - source.x()) / (target.x() - source.x());
+        let right_t = (right - source.x()) / (target.x() - source.x());
+        let (low_t, high_t) = if left_t < right_t {
+            (left_t, right_t)
+        } else {
+            (right_t, left_t)
+        };
+        (
+            if low_t > T::zero() { low_t } else { T::zero() },
+            if high_t < T::from_f64(1.0) {
+                high_t
+            } else {
+                T::from_f64(1.0)
+            },
+        )
+    };
+    if low_t > high_t {
+        return None;
+    }
+    let low_y = source.y() + (target.y() - source.y()) * low_t;
+    let high_y = source.y() + (target.y() - source.y()) * high_t;
+    if low_y < high_y {
+        Some((low_y, high_y))
+    } else {
+        Some((high_y, low_y))
+    }
+}
+
+/// Parameter where the segment enters the half-open pixel, clipped Liang–Barsky style.
+fn pixel_entry<T: NumberType>(
+    source: &Point2<T>,
+    target: &Point2<T>,
+    center: &Point2<T>,
+    half: T,
+) -> Option<T> {
+    let dx = target.x() - source.x();
+    let dy = target.y() - source.y();
+    let right = center.x() + half;
+    let top = center.y() + half;
+    let mut enter = T::zero();
+    let mut exit = T::from_f64(1.0);
+    for (direction, room) in [
+        (-dx, source.x() - (center.x() - half)),
+        (dx, right - source.x()),
+        (-dy, source.y() - (center.y() - half)),
+        (dy, top - source.y()),
+    ] {
+        if direction == T::zero() {
+            if room < T::zero() {
+                return None;
+            }
+        } else {
+            let t = room / direction;
+            if direction < T::zero() {
+                if t > enter {
+                    enter = t;
+                }
+            } else if t < exit {
+                exit = t;
+            }
+        }
+    }
+    if enter > exit {
+        return None;
+    }
+    // The right and top sides belong to the neighbours; touching only them is no visit.
+    let middle = (enter + exit) / T::from_f64(2.0);
+    if source.x() + dx * middle >= right || source.y() + dy * middle >= top {
+        return None;
+    }
+    Some(enter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::intersection::segment_2_segment_2::segment_2_segment_2_intersection;
+
+    #[test]
+    fn test_snap_round_segment_2() {
+        let segments = vec![
+            LineSegment2::new(Point2::new(0.2, 0.1), Point2::new(9.8, 0.3)),
+            LineSegment2::new(Point2::new(4.6, -1.2), Point2::new(4.6, 1.1)),
+            LineSegment2::new(Point2::new(0.1, 0.6), Point2::new(9.9, 1.4)),
+            LineSegment2::new(Point2::new(7.1, 7.1), Point2::new(7.3, 7.2)),
+        ];
+        let result = snap_round_segment_2(&segments, 1.0).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                vec![
+                    Point2::new(0.0, 0.0),
+                    Point2::new(5.0, 0.0),
+                    Point2::new(10.0, 0.0)
+                ],
+                vec![
+                    Point2::new(5.0, -1.0),
+                    Point2::new(5.0, 0.0),
+                    Point2::new(5.0, 1.0)
+                ],
+                vec![
+                    Point2::new(0.0, 1.0),
+                    Point2::new(5.0, 1.0),
+                    Point2::new(10.0, 1.0)
+                ],
+                vec![Point2::new(7.0, 7.0)],
+            ]
+        );
+        assert_eq!(
+            snap_round_segment_2(&segments, 0.0),
+            Err(GeometryError::InvalidLength)
+        );
+
+        // The link from (0, 0) to (8, 4) passes the hot pixel at (5, 3), which the segment
+        // itself misses.
+        let segments = vec![
+            LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(10.0, 4.4)),
+            LineSegment2::new(Point2::new(8.4, 3.6), Point2::new(8.4, 5.0)),
+            LineSegment2::new(Point2::new(5.2, 2.9), Point2::new(5.2, 2.9)),
+        ];
+        let result = snap_round_segment_2(&segments, 1.0).unwrap();
+        assert_eq!(
+            result[0],
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(5.0, 3.0),
+                Point2::new(8.0, 4.0),
+                Point2::new(10.0, 4.0)
+            ]
+        );
+        let links: Vec<LineSegment2<f64>> = result
+            .iter()
+            .flat_map(|chain| {
+                chain
+                    .windows(2)
+                    .map(|pair| LineSegment2::new(pair[0], pair[1]))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        for link in &links {
+            for other in &links {
+                for point in segment_2_segment_2_intersection(link, other).points() {
+                    assert!(
+                        [link.source(), link.target()].contains(&point)
+                            || (link == other)
+                            || (link.source() == other.target() && link.target() == other.source())
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_snap_round_segment_2_hot_intersection() {
+        // The first segment only gets close to the crossing of the last two, but once rounded
+        // to the hot pixel at (6, 3) its link from (0, 0) passes the pixel at (5, 3), hot only
+        // because of that crossing.
+        let segments = vec![
+            LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(10.0, 4.4)),
+            LineSegment2::new(Point2::new(8.4, 3.6), Point2::new(8.4, 5.0)),
+            LineSegment2::new(Point2::new(3.0, 3.6), Point2::new(5.9, 2.7)),
+            LineSegment2::new(Point2::new(4.6, 4.0), Point2::new(5.6, 2.6)),
+        ];
+        let result = snap_round_segment_2(&segments, 1.0).unwrap();
+        assert_eq!(
+            result[0],
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(5.0, 3.0),
+                Point2::new(6.0, 3.0),
+                Point2::new(8.0, 4.0),
+                Point2::new(10.0, 4.0)
+            ]
+        );
+        assert_eq!(
+            result[2],
+            vec![
+                Point2::new(3.0, 4.0),
+                Point2::new(5.0, 3.0),
+                Point2::new(6.0, 3.0)
+            ]
+        );
+        assert_eq!(
+            result[3],
+            vec![
+                Point2::new(5.0, 4.0),
+                Point2::new(5.0, 3.0),
+                Point2::new(6.0, 3.0)
+            ]
+        );
+
+        // Segments closer than a pixel collapse onto the same links.
+        let segments = vec![
+            LineSegment2::new(Point2::new(0.0, 0.1), Point2::new(10.0, 0.2)),
+            LineSegment2::new(Point2::new(10.0, -0.2), Point2::new(0.0, -0.1)),
+        ];
+        let result = snap_round_segment_2(&segments, 0.5).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                vec![Point2::new(0.0, 0.0), Point2::new(10.0, 0.0)],
+                vec![Point2::new(10.0, 0.0), Point2::new(0.0, 0.0)],
+            ]
+        );
+
+        assert!(snap_round_segment_2::<f64>(&[], 1.0).unwrap().is_empty());
+        assert_eq!(
+            snap_round_segment_2(&segments, -1.0),
+            Err(GeometryError::InvalidLength)
+        );
+    }
+}
@@ -0,0 +1 @@
+pub mod polygonize_2;
@@ -0,0 +1,529 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap, HashSet},
+    rc::Rc,
+};
+
+use crate::kernel::{
+    curve_2::Curve2, edge_2::Edge2, face_2::Face2, line_arc_segment_2::LineArcSegment2,
+    number_type::NumberType, point_2::Point2, polyline_2::Polyline2, segment_2::Segment2,
+    vertex_2::Vertex2,
+};
+
+type VertexRef<T> = Rc<RefCell<Vertex2<T>>>;
+type EdgeRef<T> = Rc<RefCell<Edge2<T>>>;
+
+/// Area enclosed by a counter-clockwise shell, minus its clockwise holes.
+#[derive(Debug, Clone)]
+pub struct PolygonWithHoles2<T: NumberType> {
+    shell: Polyline2<T>,
+    holes: Vec<Polyline2<T>>,
+}
+
+impl<T: NumberType> PolygonWithHoles2<T> {
+    pub fn shell(&self) -> Polyline2<T> {
+        self.shell.clone()
+    }
+
+    pub fn holes(&self) -> Vec<Polyline2<T>> {
+        self.holes.clone()
+    }
+
+    pub fn area(&self) -> T {
+        self.holes
+            .iter()
+            .fold(self.shell.signed_area(), |area, hole| {
+                area + hole.signed_area()
+            })
+    }
+}
+
+/// Faces of a noded arrangement together with the linework that bounds none of them.
+#[derive(Debug, Clone)]
+pub struct Polygonization2<T: NumberType> {
+    polygons: Vec<PolygonWithHoles2<T>>,
+    dangles: Vec<LineArcSegment2<T>>,
+    cut_edges: Vec<LineArcSegment2<T>>,
+    invalid_rings: Vec<Polyline2<T>>,
+}
+
+impl<T: NumberType> Polygonization2<T> {
+    pub fn polygons(&self) -> Vec<PolygonWithHoles2<T>> {
+        self.polygons.clone()
+    }
+
+    /// Input segments with a free end, including the ones left free once others were removed.
+    pub fn dangles(&self) -> Vec<LineArcSegment2<T>> {
+        self.dangles.clone()
+    }
+
+    /// Input segments with the same face on both sides, such as bridges between two rings.
+    pub fn cut_edges(&self) -> Vec<LineArcSegment2<T>> {
+        self.cut_edges.clone()
+    }
+
+    /// Rings that cross themselves or enclose no area, in the orientation they were traced.
+    pub fn invalid_rings(&self) -> Vec<Polyline2<T>> {
+        self.invalid_rings.clone()
+    }
+}
+
+/// Extracts every bounded face of line and arc segments that only meet at shared endpoints,
+/// as from [`crate::algorithm::noding::segment_2::node_segment_2`].
+///
+/// Rings passing through a vertex twice are split there, so holes may touch their shell at a
+/// point. A hole belongs to the smallest shell around it; clockwise rings around nothing bound
+/// the unbounded face and are dropped.
+pub fn polygonize_2<T: NumberType>(segments: &[LineArcSegment2<T>]) -> Polygonization2<T> {
+    let mut vertex_indices: BTreeMap<Point2<T>, usize> = BTreeMap::new();
+    let mut vertices: Vec<VertexRef<T>> = Vec::new();
+    let mut outgoing: Vec<Vec<EdgeRef<T>>> = Vec::new();
+    let mut parents: HashMap<*const RefCell<Edge2<T>>, usize> = HashMap::new();
+    let mut halves: Vec<(EdgeRef<T>, EdgeRef<T>)> = Vec::new();
+    for (parent, segment) in segments.iter().enumerate() {
+        if is_zero_length(segment) {
+            continue;
+        }
+        let mut vertex_at = |point: Point2<T>| {
+            *vertex_indices.entry(point).or_insert_with(|| {
+                vertices.push(Rc::new(RefCell::new(Vertex2::new(point.x(), point.y()))));
+                outgoing.push(Vec::new());
+                vertices.len() - 1
+            })
+        };
+        let source = vertex_at(segment.source());
+        let target = vertex_at(segment.target());
+        let forward = new_edge(&vertices[source], &vertices[target], segment);
+        let backward = new_edge(&vertices[target], &vertices[source], &segment.reverse());
+        forward.borrow_mut().set_twin(backward.clone());
+        backward.borrow_mut().set_twin(forward.clone());
+        parents.insert(Rc::as_ptr(&forward), parent);
+        parents.insert(Rc::as_ptr(&backward), parent);
+        outgoing[source].push(forward.clone());
+        outgoing[target].push(backward.clone());
+        halves.push((forward, backward));
+    }
+    for (vertex, mut edges) in vertices.iter().zip(outgoing) {
+        edges.sort_by(|a, b| compare_outgoing(&a.borrow(), &b.borrow()));
+        for edge in edges {
+            vertex.borrow_mut().add_edge(edge);
+        }
+    }
+
+    let mut dangles = Vec::new();
+    let mut free: Vec<usize> = (0..vertices.len())
+        .filter(|index| vertices[*index].borrow().edges().len() == 1)
+        .collect();
+    while let Some(index) = free.pop() {
+        let edges = vertices[index].borrow().edges();
+        if edges.len() != 1 {
+            continue;
+        }
+        let other = edges[0].borrow().target();
+        remove_edge(&edges[0]);
+        dangles.push(segments[parents[&Rc::as_ptr(&edges[0])]]);
+        if other.borrow().edges().len() == 1 {
+            free.push(vertex_indices[&other.borrow().to_point()]);
+        }
+    }
+
+    let mut ring_of: HashMap<*const RefCell<Edge2<T>>, usize> = HashMap::new();
+    for (ring_index, ring) in trace_rings(&vertices).iter().enumerate() {
+        for edge in ring {
+            ring_of.insert(Rc::as_ptr(edge), ring_index);
+        }
+    }
+    let mut cut_edges = Vec::new();
+    for (forward, backward) in &halves {
+        let forward_ring = ring_of.get(&Rc::as_ptr(forward));
+        if forward_ring.is_some() && forward_ring == ring_of.get(&Rc::as_ptr(backward)) {
+            remove_edge(forward);
+            cut_edges.push(segments[parents[&Rc::as_ptr(forward)]]);
+        }
+    }
+
+    let mut faces: Vec<Rc<RefCell<Face2<T>>>> = Vec::new();
+    for ring in trace_rings(&vertices) {
+        for ring in split_at_repeated_vertices(ring) {
+            let face = Rc::new(RefCell::new(Face2::new(ring.clone())));
+            for (index, edge) in ring.iter().enumerate() {
+                let next = &ring[(index + 1) % ring.len()];
+                edge.borrow_mut().set_next(next.clone());
+                next.borrow_mut().set_prev(edge.clone());
+                edge.borrow_mut().set_face(face.clone());
+            }
+            faces.push(face);
+        }
+    }
+
+    let mut invalid_rings = Vec::new();
+    let mut shells: Vec<(usize, Polyline2<T>, T)> = Vec::new();
+    let mut holes: Vec<(usize, Polyline2<T>)> = Vec::new();
+    for (index, face) in faces.iter().enumerate() {
+        let ring = face.borrow().to_polyline();
+        let area = ring.signed_area();
+        if area.equals(T::zero()) || !ring.is_simple() {
+            invalid_rings.push(ring);
+        } else if area > T::zero() {
+            shells.push((index, ring, area));
+        } else {
+            holes.push((index, ring));
+        }
+    }
+    let mut polygon_holes: Vec<Vec<Polyline2<T>>> = vec![Vec::new(); shells.len()];
+    for (index, ring) in holes {
+        let edge = faces[index].borrow().edges()[0].clone();
+        let twin_face = edge.borrow().twin().unwrap().borrow().face().unwrap();
+        let point = edge
+            .borrow()
+            .to_line_arc_segment()
+            .point_at(T::from_f64(0.5));
+        let owner = (0..shells.len())
+            .filter(|owner| {
+                let (shell_index, shell, _) = &shells[*owner];
+                !Rc::ptr_eq(&faces[*shell_index], &twin_face)
+                    && is_point_2_inside_ring(&point, &shell.segments())
+            })
+            .min_by(|a, b| shells[*a].2.partial_cmp(&shells[*b].2).unwrap());
+        if let Some(owner) = owner {
+            polygon_holes[owner].push(ring);
+        }
+    }
+    let polygons = shells
+        .into_iter()
+        .zip(polygon_holes)
+        .map(|((_, shell, _), holes)| PolygonWithHoles2 { shell, holes })
+        .collect();
+
+    for (forward, backward) in &halves {
+        forward.borrow_mut().clear_links();
+        backward.borrow_mut().clear_links();
+    }
+    for vertex in &vertices {
+        let edges = vertex.borrow().edges();
+        for edge in edges {
+            vertex.borrow_mut().remove_edge(edge);
+        }
+    }
+    Polygonization2 {
+        polygons,
+        dangles,
+        cut_edges,
+        invalid_rings,
+    }
+}
+
+fn is_zero_length<T: NumberType>(segment: &LineArcSegment2<T>) -> bool {
+    match segment {
+        LineArcSegment2::LineSegment2(line_segment) => {
+            line_segment.source() == line_segment.target()
+        }
+        LineArcSegment2::ArcSegment2(arc_segment) => arc_segment.is_zero_length(),
+    }
+}
+
+fn new_edge<T: NumberType>(
+    source: &VertexRef<T>,
+    target: &VertexRef<T>,
+    segment: &LineArcSegment2<T>,
+) -> EdgeRef<T> {
+    let edge = match segment {
+        LineArcSegment2::LineSegment2(_) => Edge2::new_segment(source.clone(), target.clone()),
+        LineArcSegment2::ArcSegment2(arc_segment) => {
+            Edge2::new_arc(source.clone(), target.clone(), *arc_segment)
+        }
+    };
+    Rc::new(RefCell::new(edge))
+}
+
+/// Counter-clockwise by the leaving direction; among tangent edges the one bending further left
+/// comes later.
+fn compare_outgoing<T: NumberType>(a: &Edge2<T>, b: &Edge2<T>) -> std::cmp::Ordering {
+    let leaving_radian = |edge: &Edge2<T>| {
+        let tangent = edge.to_line_arc_segment().tangent_at(T::zero());
+        let radian = tangent.y().atan2(tangent.x());
+        if (radian + T::pi()).equals(T::zero()) {
+            T::pi()
+        } else {
+            radian
+        }
+    };
+    let a_radian = leaving_radian(a);
+    let b_radian = leaving_radian(b);
+    if a_radian.equals(b_radian) {
+        let a_curvature = a.to_line_arc_segment().curvature_at(T::zero());
+        let b_curvature = b.to_line_arc_segment().curvature_at(T::zero());
+        a_curvature.partial_cmp(&b_curvature).unwrap()
+    } else {
+        a_radian.partial_cmp(&b_radian).unwrap()
+    }
+}
+
+fn remove_edge<T: NumberType>(edge: &EdgeRef<T>) {
+    let twin = edge.borrow().twin().unwrap();
+    edge.borrow()
+        .source()
+        .borrow_mut()
+        .remove_edge(edge.clone());
+    twin.borrow()
+        .source()
+        .borrow_mut()
+        .remove_edge(twin.clone());
+}
+
+/// Cycles of the edges still attached to a vertex, each turning as far right as it can, so the
+/// face of every cycle lies on its left.
+fn trace_rings<T: NumberType>(vertices: &[VertexRef<T>]) -> Vec<Vec<EdgeRef<T>>> {
+    let mut visited: HashSet<*const RefCell<Edge2<T>>> = HashSet::new();
+    let mut rings = Vec::new();
+    for vertex in vertices {
+        for start in vertex.borrow().edges() {
+            if visited.contains(&Rc::as_ptr(&start)) {
+                continue;
+            }
+            let mut ring = Vec::new();
+            let mut edge = start.clone();
+            loop {
+                visited.insert(Rc::as_ptr(&edge));
+                let twin = edge.borrow().twin().unwrap();
+                let around = edge.borrow().target().borrow().edges();
+                let position = around
+                    .iter()
+                    .position(|other| Rc::ptr_eq(other, &twin))
+                    .unwrap();
+                let next = around[(position + around.len() - 1) % around.len()].clone();
+                ring.push(edge);
+                if Rc::ptr_eq(&next, &start) {
+                    break;
+                }
+                edge = next;
+            }
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+fn split_at_repeated_vertices<T: NumberType>(ring: Vec<EdgeRef<T>>) -> Vec<Vec<EdgeRef<T>>> {
+    let mut rings = Vec::new();
+    let mut path: Vec<EdgeRef<T>> = Vec::new();
+    let mut positions: HashMap<*const RefCell<Vertex2<T>>, usize> = HashMap::new();
+    for edge in ring {
+        let source = Rc::as_ptr(&edge.borrow().source());
+        if let Some(position) = positions.get(&source).copied() {
+            let loop_edges = path.split_off(position);
+            for loop_edge in &loop_edges {
+                positions.remove(&Rc::as_ptr(&loop_edge.borrow().source()));
+            }
+            rings.push(loop_edges);
+        }
+        positions.insert(source, path.len());
+        path.push(edge);
+    }
+    rings.push(path);
+    rings
+}
+
+/// Winding test where each arc turns like its chord, plus a full turn when the point lies
+/// between the chord and the arc.
+fn is_point_2_inside_ring<T: NumberType>(point: &Point2<T>, ring: &[LineArcSegment2<T>]) -> bool {
+    let two_pi = T::pi() * T::from_f64(2.0);
+    let mut turn = T::zero();
+    for segment in ring {
+        let source = segment.source() - *point;
+        let target = segment.target() - *point;
+        turn = turn + source.cross(&target).atan2(source.dot(&target));
+        if let LineArcSegment2::ArcSegment2(arc_segment) = segment {
+            let center = arc_segment.center();
+            if (*point - center).length() >= arc_segment.radius() {
+                continue;
+            }
+            let chord = segment.target() - segment.source();
+            let bulge_side = chord.cross(&(arc_segment.mid_point() - segment.source()));
+            let point_side = chord.cross(&(*point - segment.source()));
+            if arc_segment.is_full() || (bulge_side > T::zero()) == (point_side > T::zero()) {
+                turn = turn
+                    + if arc_segment.sweep_angle() > T::zero() {
+                        two_pi
+                    } else {
+                        -two_pi
+                    };
+            }
+        }
+    }
+    turn.abs() > T::pi()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::{
+        arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2, line_segment_2::LineSegment2,
+    };
+
+    fn line(x0: f64, y0: f64, x1: f64, y1: f64) -> LineArcSegment2<f64> {
+        LineSegment2::new(Point2::new(x0, y0), Point2::new(x1, y1)).into()
+    }
+
+    #[test]
+    fn test_polygonize_2() {
+        let segments = vec![
+            // Outer square with an arc bulging out of its top edge.
+            line(0.0, 0.0, 6.0, 0.0),
+            line(6.0, 0.0, 10.0, 0.0),
+            line(10.0, 0.0, 10.0, 5.0),
+            line(10.0, 5.0, 10.0, 10.0),
+            line(0.0, 10.0, 0.0, 0.0),
+            ArcSegment2::new(
+                CircleSegment2::new(Point2::new(5.0, 10.0), 5.0),
+                0.0,
+                std::f64::consts::PI,
+            )
+            .into(),
+            // Hole touching nothing, split in two by a diagonal.
+            line(2.0, 2.0, 4.0, 2.0),
+            line(4.0, 2.0, 4.0, 4.0),
+            line(4.0, 4.0, 2.0, 4.0),
+            line(2.0, 4.0, 2.0, 2.0),
+            line(2.0, 2.0, 4.0, 4.0),
+            // Circular hole hanging off the outer square by a cut edge.
+            line(10.0, 5.0, 8.0, 5.0),
+            ArcSegment2::new(
+                CircleSegment2::new(Point2::new(7.0, 5.0), 1.0),
+                0.0,
+                2.0 * std::f64::consts::PI,
+            )
+            .into(),
+            // Dangling chain.
+            line(6.0, 0.0, 6.0, 1.0),
+            line(6.0, 1.0, 7.0, 2.0),
+        ];
+        let result = polygonize_2(&segments);
+        let pi = std::f64::consts::PI;
+        let mut areas: Vec<f64> = result
+            .polygons()
+            .iter()
+            .map(|polygon| polygon.area())
+            .collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let expected = [2.0, 2.0, pi, 100.0 + 12.5 * pi - 4.0 - pi];
+        assert_eq!(areas.len(), expected.len());
+        for (area, expected) in areas.iter().zip(expected) {
+            assert!(area.equals(expected));
+        }
+        let outer = result
+            .polygons()
+            .into_iter()
+            .find(|polygon| polygon.holes().len() == 2)
+            .unwrap();
+        assert!(outer.shell().is_closed());
+        assert_eq!(result.dangles().len(), 2);
+        assert_eq!(result.cut_edges(), vec![line(10.0, 5.0, 8.0, 5.0)]);
+        assert!(result.invalid_rings().is_empty());
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> Vec<LineArcSegment2<f64>> {
+        vec![
+            line(x, y, x + size, y),
+            line(x + size, y, x + size, y + size),
+            line(x + size, y + size, x, y + size),
+            line(x, y + size, x, y),
+        ]
+    }
+
+    fn areas(result: &Polygonization2<f64>) -> Vec<(f64, Vec<f64>)> {
+        let mut areas: Vec<(f64, Vec<f64>)> = result
+            .polygons()
+            .iter()
+            .map(|polygon| {
+                (
+                    polygon.area(),
+                    polygon
+                        .holes()
+                        .iter()
+                        .map(|hole| hole.signed_area())
+                        .collect(),
+                )
+            })
+            .collect();
+        areas.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        areas
+    }
+
+    #[test]
+    fn test_polygonize_2_nested_and_dangling() {
+        // An island in a lake in a field, with one dangle inside the lake and one on its own.
+        let mut segments = square(0.0, 0.0, 10.0);
+        segments.extend(square(2.0, 2.0, 6.0));
+        segments.extend(square(4.0, 4.0, 2.0));
+        segments.push(line(2.0, 2.0, 3.0, 3.0));
+        segments.push(line(20.0, 0.0, 21.0, 0.0));
+        let result = polygonize_2(&segments);
+        assert_eq!(
+            areas(&result),
+            vec![(4.0, vec![]), (32.0, vec![-4.0]), (64.0, vec![-36.0])]
+        );
+        assert_eq!(
+            result.dangles(),
+            vec![line(20.0, 0.0, 21.0, 0.0), line(2.0, 2.0, 3.0, 3.0)]
+        );
+        assert!(result.cut_edges().is_empty());
+        assert!(result.invalid_rings().is_empty());
+
+        // A hole touching its shell at a vertex is split off there.
+        let segments = vec![
+            line(0.0, 0.0, 4.0, 0.0),
+            line(4.0, 0.0, 4.0, 4.0),
+            line(4.0, 4.0, 0.0, 4.0),
+            line(0.0, 4.0, 0.0, 2.0),
+            line(0.0, 2.0, 0.0, 0.0),
+            line(0.0, 2.0, 2.0, 1.0),
+            line(2.0, 1.0, 2.0, 3.0),
+            line(2.0, 3.0, 0.0, 2.0),
+        ];
+        assert_eq!(
+            areas(&polygonize_2(&segments)),
+            vec![(2.0, vec![]), (14.0, vec![-2.0])]
+        );
+
+        let mut segments = square(0.0, 0.0, 1.0);
+        segments.extend(square(3.0, 0.0, 1.0));
+        segments.push(line(1.0, 0.0, 3.0, 0.0));
+        let result = polygonize_2(&segments);
+        assert_eq!(areas(&result), vec![(1.0, vec![]), (1.0, vec![])]);
+        assert_eq!(result.cut_edges(), vec![line(1.0, 0.0, 3.0, 0.0)]);
+        assert!(result.dangles().is_empty());
+    }
+
+    #[test]
+    fn test_polygonize_2_degenerate() {
+        let result = polygonize_2::<f64>(&[]);
+        assert!(result.polygons().is_empty());
+        assert!(result.dangles().is_empty());
+
+        let result = polygonize_2(&[line(1.0, 1.0, 1.0, 1.0)]);
+        assert!(result.polygons().is_empty());
+        assert!(result.dangles().is_empty());
+        assert!(result.invalid_rings().is_empty());
+
+        let circle: LineArcSegment2<f64> = ArcSegment2::new(
+            CircleSegment2::new(Point2::new(0.0, 0.0), 1.0),
+            0.0,
+            2.0 * std::f64::consts::PI,
+        )
+        .into();
+        assert_eq!(
+            areas(&polygonize_2(&[circle])),
+            vec![(std::f64::consts::PI, vec![])]
+        );
+
+        // Two copies of one segment enclose no area.
+        let result = polygonize_2(&[line(0.0, 0.0, 1.0, 0.0), line(1.0, 0.0, 0.0, 0.0)]);
+        assert!(result.polygons().is_empty());
+        assert!(!result.invalid_rings().is_empty());
+        assert!(result
+            .invalid_rings()
+            .iter()
+            .all(|ring| ring.signed_area().equals(0.0)));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod segment_2_segment_2;
+pub mod sweep_segment_2_proximity;
@@ -0,0 +1,126 @@
+use crate::{
+    algorithm::intersection::segment_2_segment_2::segment_2_segment_2_intersection,
+    kernel::{
+        curve_2::Curve2, line_arc_segment_2::LineArcSegment2, number_type::NumberType,
+        point_2::Point2, segment_2::Segment2, vector_2::Vector2,
+    },
+};
+
+/// Closest points of two segments, the first on `segment_a`, and their distance.
+///
+/// Meeting segments return one of their common points twice.
+pub fn segment_2_segment_2_closest_points<T: NumberType>(
+    segment_a: &LineArcSegment2<T>,
+    segment_b: &LineArcSegment2<T>,
+) -> (Point2<T>, Point2<T>, T) {
+    let intersection = segment_2_segment_2_intersection(segment_a, segment_b);
+    if !intersection.is_empty() {
+        let point = intersection
+            .points()
+            .first()
+            .copied()
+            .unwrap_or(segment_a.source());
+        return (point, point, T::zero());
+    }
+    let mut closest = (segment_a.source(), segment_b.source(), T::zero());
+    let mut is_first = true;
+    let mut consider = |point_a: Point2<T>, point_b: Point2<T>| {
+        let distance = point_a.distance(&point_b);
+        if is_first || distance < closest.2 {
+            closest = (point_a, point_b, distance);
+            is_first = false;
+        }
+    };
+    for point in candidates(segment_a, segment_b) {
+        consider(point, closest_point(segment_b, &point));
+    }
+    for point in candidates(segment_b, segment_a) {
+        consider(closest_point(segment_a, &point), point);
+    }
+    closest
+}
+
+fn closest_point<T: NumberType>(segment: &LineArcSegment2<T>, point: &Point2<T>) -> Point2<T> {
+    segment.point_at(segment.closest_parameter(point))
+}
+
+/// Points of `segment` where a closest pair with `other` may start: its endpoints and, on
+/// arcs, the points whose normal also meets `other` at a right angle.
+fn candidates<T: NumberType>(
+    segment: &LineArcSegment2<T>,
+    other: &LineArcSegment2<T>,
+) -> Vec<Point2<T>> {
+    let mut points = vec![segment.source(), segment.target()];
+    if let LineArcSegment2::ArcSegment2(arc_segment) = segment {
+        let center = arc_segment.center();
+        let direction = match other {
+            LineArcSegment2::LineSegment2(line_segment) => {
+                let along = line_segment.target() - line_segment.source();
+                Vector2::new(-along.y(), along.x())
+            }
+            LineArcSegment2::ArcSegment2(other_arc_segment) => other_arc_segment.center() - center,
+        };
+        let length = direction.length();
+        if !length.equals(T::zero()) {
+            let offset = direction * (arc_segment.radius() / length);
+            for point in [
+                Point2::new(center.x() + offset.x(), center.y() + offset.y()),
+                Point2::new(center.x() - offset.x(), center.y() - offset.y()),
+            ] {
+                if closest_point(segment, &point) == point {
+                    points.push(point);
+                }
+            }
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::{
+        arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2, line_segment_2::LineSegment2,
+    };
+
+    #[test]
+    fn test_segment_2_segment_2_closest_points() {
+        let line_a: LineArcSegment2<f64> =
+            LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(4.0, 0.0)).into();
+        let line_b: LineArcSegment2<f64> =
+            LineSegment2::new(Point2::new(5.0, 1.0), Point2::new(5.0, 3.0)).into();
+        let (point_a, point_b, distance) = segment_2_segment_2_closest_points(&line_a, &line_b);
+        assert_eq!(point_a, Point2::new(4.0, 0.0));
+        assert_eq!(point_b, Point2::new(5.0, 1.0));
+        assert!(distance.equals(2.0_f64.sqrt()));
+
+        let arc: LineArcSegment2<f64> = ArcSegment2::new(
+            CircleSegment2::new(Point2::new(2.0, 3.0), 2.0),
+            std::f64::consts::PI,
+            std::f64::consts::PI * 2.0,
+        )
+        .into();
+        let (point_a, point_b, distance) = segment_2_segment_2_closest_points(&line_a, &arc);
+        assert_eq!(point_a, Point2::new(2.0, 0.0));
+        assert_eq!(point_b, Point2::new(2.0, 1.0));
+        assert!(distance.equals(1.0));
+
+        let other_arc: LineArcSegment2<f64> = ArcSegment2::new(
+            CircleSegment2::new(Point2::new(8.0, 3.0), 1.0),
+            std::f64::consts::PI / 2.0,
+            std::f64::consts::PI * 1.5,
+        )
+        .into();
+        let (point_a, point_b, distance) = segment_2_segment_2_closest_points(&arc, &other_arc);
+        assert_eq!(point_a, Point2::new(4.0, 3.0));
+        assert_eq!(point_b, Point2::new(7.0, 3.0));
+        assert!(distance.equals(3.0));
+
+        let crossing: LineArcSegment2<f64> =
+            LineSegment2::new(Point2::new(1.0, -1.0), Point2::new(1.0, 1.0)).into();
+        let (point_a, point_b, distance) = segment_2_segment_2_closest_points(&line_a, &crossing);
+        assert_eq!(point_a, Point2::new(1.0, 0.0));
+        assert_eq!(point_b, point_a);
+        assert_eq!(distance, 0.0);
+    }
+}
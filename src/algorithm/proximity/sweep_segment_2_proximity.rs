@@ -0,0 +1,344 @@
+use std::collections::BTreeSet;
+
+use crate::kernel::{
+    bbox_2::BBox2, curve_2::Curve2, line_arc_segment_2::LineArcSegment2, number_type::NumberType,
+    point_2::Point2,
+};
+
+use super::segment_2_segment_2::segment_2_segment_2_closest_points;
+
+/// Two segments within the query distance, with their closest points in index order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentProximity2<T: NumberType> {
+    indices: (usize, usize),
+    points: (Point2<T>, Point2<T>),
+    distance: T,
+}
+
+impl<T: NumberType> SegmentProximity2<T> {
+    /// Push order, the smaller first.
+    pub fn indices(&self) -> (usize, usize) {
+        self.indices
+    }
+
+    pub fn points(&self) -> (Point2<T>, Point2<T>) {
+        self.points
+    }
+
+    pub fn distance(&self) -> T {
+        self.distance
+    }
+}
+
+/// Sweep over the bounding boxes of line and arc segments that finds every pair at most a
+/// distance apart, including pairs that meet, such as consecutive pieces of a polyline.
+///
+/// Boxes are grown by half the distance and swept along x, while the active boxes are kept by
+/// their y-intervals, so each box only visits the active boxes it overlaps. Only pairs whose
+/// grown boxes overlap are measured exactly, which costs O(n log² n + k) for k such pairs
+/// instead of O(n²), also when many boxes share the same x-range.
+pub struct SweepSegment2Proximity<T: NumberType> {
+    segments: Vec<LineArcSegment2<T>>,
+}
+
+impl<T: NumberType> SweepSegment2Proximity<T> {
+    pub fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+
+    pub fn push_segment(&mut self, segment: &LineArcSegment2<T>) {
+        self.segments.push(*segment);
+    }
+
+    /// Pairs sorted by their indices.
+    pub fn within(&self, distance: T) -> Vec<SegmentProximity2<T>> {
+        let boxes: Vec<BBox2<T>> = self
+            .segments
+            .iter()
+            .map(|segment| segment.bbox().expand(distance / T::from_f64(2.0)))
+            .collect();
+        let mut order: Vec<usize> = (0..self.segments.len()).collect();
+        order.sort_by(|a, b| {
+            boxes[*a]
+                .min()
+                .x()
+                .partial_cmp(&boxes[*b].min().x())
+                .unwrap()
+        });
+        // Boxes ending left of the sweep were all entered before it, so they leave in this order.
+        let mut leaving: Vec<usize> = (0..self.segments.len()).collect();
+        leaving.sort_by(|a, b| {
+            boxes[*a]
+                .max()
+                .x()
+                .partial_cmp(&boxes[*b].max().x())
+                .unwrap()
+        });
+        let mut leaving = leaving.into_iter().peekable();
+        let mut active = ActiveIntervals::new(&boxes);
+        let mut result = Vec::new();
+        for index in order {
+            let left = boxes[index].min().x() - T::default_eps();
+            while let Some(other) = leaving.next_if(|other| boxes[*other].max().x() < left) {
+                active.remove(other);
+            }
+            let eps = T::default_eps();
+            for other in
+                active.overlapping(boxes[index].min().y() - eps, boxes[index].max().y() + eps)
+            {
+                if !boxes[index].intersects(&boxes[other]) {
+                    continue;
+                }
+                let (first, second) = if index < other {
+                    (index, other)
+                } else {
+                    (other, index)
+                };
+                let (point_a, point_b, gap) = segment_2_segment_2_closest_points(
+                    &self.segments[first],
+                    &self.segments[second],
+                );
+                if gap < distance || gap.equals(distance) {
+                    result.push(SegmentProximity2 {
+                        indices: (first, second),
+                        points: (point_a, point_b),
+                        distance: gap,
+                    });
+                }
+            }
+            active.insert(index);
+        }
+        result.sort_by_key(|proximity| proximity.indices);
+        result
+    }
+}
+
+/// Y-intervals of the active boxes, in a segment tree over the ranks of all box y-bounds for
+/// the intervals holding a value, and ordered by their lower rank for the ones starting above it.
+struct ActiveIntervals<T: NumberType> {
+    bounds: Vec<T>,
+    ranks: Vec<(usize, usize)>,
+    tree: Vec<BTreeSet<usize>>,
+    starts: BTreeSet<(usize, usize)>,
+}
+
+impl<T: NumberType> ActiveIntervals<T> {
+    fn new(boxes: &[BBox2<T>]) -> Self {
+        let mut bounds: Vec<T> = boxes
+            .iter()
+            .flat_map(|bbox| [bbox.min().y(), bbox.max().y()])
+            .collect();
+        bounds.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        bounds.dedup();
+        let rank = |value: T| bounds.partition_point(|bound| *bound < value);
+        let ranks = boxes
+            .iter()
+            .map(|bbox| (rank(bbox.min().y()), rank(bbox.max().y())))
+            .collect();
+        let tree = vec![BTreeSet::new(); 4 * bounds.len().max(1)];
+        Self {
+            bounds,
+            ranks,
+            tree,
+            starts: BTreeSet::new(),
+        }
+    }
+
+    fn insert(&mut self, index: usize) {
+        let interval = self.ranks[index];
+        self.update(1, (0, self.bounds.len() - 1), interval, index, true);
+        self.starts.insert((interval.0, index));
+    }
+
+    fn remove(&mut self, index: usize) {
+        let interval = self.ranks[index];
+        self.update(1, (0, self.bounds.len() - 1), interval, index, false);
+        self.starts.remove(&(interval.0, index));
+    }
+
+    fn update(
+        &mut self,
+        node: usize,
+        (from, to): (usize, usize),
+        (low, high): (usize, usize),
+        index: usize,
+        insert: bool,
+    ) {
+        if high < from || to < low {
+            return;
+        }
+        if low <= from && to <= high {
+            if insert {
+                self.tree[node].insert(index);
+            } else {
+                self.tree[node].remove(&index);
+            }
+            return;
+        }
+        let middle = (from + to) / 2;
+        self.update(2 * node, (from, middle), (low, high), index, insert);
+        self.update(2 * node + 1, (middle + 1, to), (low, high), index, insert);
+    }
+
+    /// Active intervals holding the last bound at most `low`, and those starting above it up to
+    /// `high`; the first may end just below `low`.
+    fn overlapping(&self, low: T, high: T) -> Vec<usize> {
+        let first_above = self.bounds.partition_point(|bound| *bound <= low);
+        let end = self.bounds.partition_point(|bound| *bound <= high);
+        let mut result = Vec::new();
+        if first_above > 0 {
+            let rank = first_above - 1;
+            let (mut node, mut from, mut to) = (1, 0, self.bounds.len() - 1);
+            loop {
+                result.extend(self.tree[node].iter().copied());
+                if from == to {
+                    break;
+                }
+                let middle = (from + to) / 2;
+                if rank <= middle {
+                    (node, to) = (2 * node, middle);
+                } else {
+                    (node, from) = (2 * node + 1, middle + 1);
+                }
+            }
+        }
+        if first_above < end {
+            result.extend(
+                self.starts
+                    .range((first_above, 0)..(end, 0))
+                    .map(|(_, index)| *index),
+            );
+        }
+        result
+    }
+}
+
+impl<T: NumberType> Default for SweepSegment2Proximity<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::{
+        arc_segment_2::ArcSegment2, circle_segment_2::CircleSegment2, line_segment_2::LineSegment2,
+    };
+
+    #[test]
+    fn test_sweep_segment_2_proximity() {
+        let mut sweep = SweepSegment2Proximity::new();
+        sweep.push_segment(&LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(4.0, 0.0)).into());
+        sweep.push_segment(&LineSegment2::new(Point2::new(4.0, 0.0), Point2::new(4.0, 4.0)).into());
+        sweep.push_segment(&LineSegment2::new(Point2::new(2.0, 0.5), Point2::new(2.0, 3.0)).into());
+        sweep.push_segment(
+            &ArcSegment2::new(
+                CircleSegment2::new(Point2::new(6.0, 2.0), 1.5),
+                std::f64::consts::PI / 2.0,
+                std::f64::consts::PI * 1.5,
+            )
+            .into(),
+        );
+        sweep.push_segment(&LineSegment2::new(Point2::new(9.0, 9.0), Point2::new(9.0, 9.5)).into());
+        let result = sweep.within(0.6);
+        let summary: Vec<_> = result
+            .iter()
+            .map(|proximity| {
+                (
+                    proximity.indices(),
+                    proximity.points(),
+                    proximity.distance(),
+                )
+            })
+            .collect();
+        assert_eq!(summary.len(), 3);
+        assert_eq!(summary[0].0, (0, 1));
+        assert_eq!(summary[0].1, (Point2::new(4.0, 0.0), Point2::new(4.0, 0.0)));
+        assert_eq!(summary[1].0, (0, 2));
+        assert_eq!(summary[1].1, (Point2::new(2.0, 0.0), Point2::new(2.0, 0.5)));
+        assert!(summary[1].2.equals(0.5));
+        assert_eq!(summary[2].0, (1, 3));
+        assert_eq!(summary[2].1, (Point2::new(4.0, 2.0), Point2::new(4.5, 2.0)));
+        assert!(sweep
+            .within(0.4)
+            .iter()
+            .all(|proximity| proximity.indices() == (0, 1)));
+    }
+
+    #[test]
+    fn test_sweep_segment_2_proximity_stacked() {
+        // Boxes sharing one x-range are told apart by y alone.
+        let mut sweep = SweepSegment2Proximity::new();
+        for i in 0..500 {
+            let y = i as f64;
+            sweep.push_segment(
+                &LineSegment2::new(Point2::new(0.0, y), Point2::new(100.0, y)).into(),
+            );
+        }
+        assert!(sweep.within(0.5).is_empty());
+        let result = sweep.within(1.0);
+        assert_eq!(
+            result
+                .iter()
+                .map(|proximity| proximity.indices())
+                .collect::<Vec<_>>(),
+            (0..499).map(|i| (i, i + 1)).collect::<Vec<_>>()
+        );
+        assert!(result
+            .iter()
+            .all(|proximity| proximity.distance().equals(1.0)));
+
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let segments: Vec<LineArcSegment2<f64>> = (0..300)
+            .map(|i| {
+                let source = Point2::new(random() * 20.0, random() * 20.0);
+                if i % 3 == 0 {
+                    let radian = random() * std::f64::consts::PI * 2.0;
+                    ArcSegment2::new(
+                        CircleSegment2::new(source, 0.2 + random()),
+                        radian,
+                        radian + random() * 4.0,
+                    )
+                    .into()
+                } else {
+                    LineSegment2::new(
+                        source,
+                        Point2::new(source.x() + random() * 8.0, source.y() + random() - 0.5),
+                    )
+                    .into()
+                }
+            })
+            .collect();
+        let mut sweep = SweepSegment2Proximity::new();
+        segments
+            .iter()
+            .for_each(|segment| sweep.push_segment(segment));
+        let mut expected = Vec::new();
+        for i in 0..segments.len() {
+            for j in i + 1..segments.len() {
+                let (_, _, gap) = segment_2_segment_2_closest_points(&segments[i], &segments[j]);
+                if gap < 0.3 || gap.equals(0.3) {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert!(expected.len() > 100);
+        assert_eq!(
+            sweep
+                .within(0.3)
+                .iter()
+                .map(|proximity| proximity.indices())
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use std::cmp::Ordering;
+
+use crate::kernel::{number_type::NumberType, point_2::Point2};
+
+/// Orders by counterclockwise angle around `center` from the positive x axis, nearer points first on ties.
+pub fn compare_radially<T: NumberType>(
+    center: &Point2<T>,
+    a: &Point2<T>,
+    b: &Point2<T>,
+) -> Ordering {
+    let vector_a = *a - *center;
+    let vector_b = *b - *center;
+    vector_a.compare_radian(&vector_b).then_with(|| {
+        vector_a
+            .dot(&vector_a)
+            .partial_cmp(&vector_b.dot(&vector_b))
+            .unwrap_or(Ordering::Equal)
+    })
+}
+
+pub fn sort_radially<T: NumberType>(center: &Point2<T>, points: &mut [Point2<T>]) {
+    points.sort_by(|a, b| compare_radially(center, a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_radially() {
+        let center = Point2::new(1.0, 1.0);
+        let mut points = vec![
+            Point2::new(1.0, 0.0),
+            Point2::new(3.0, 3.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+        ];
+        sort_radially(&center, &mut points);
+        assert_eq!(
+            points,
+            vec![
+                Point2::new(1.0, 1.0),
+                Point2::new(2.0, 1.0),
+                Point2::new(2.0, 2.0),
+                Point2::new(3.0, 3.0),
+                Point2::new(0.0, 1.0),
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 0.0),
+            ]
+        );
+    }
+}
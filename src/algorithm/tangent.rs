@@ -0,0 +1,6 @@
+pub mod apollonius;
+pub mod circle_segment_2_circle_segment_2;
+pub mod line_2_circle_segment_2;
+pub mod line_2_line_2;
+pub mod point_2_circle_segment_2;
+pub mod tangent_circle_2;
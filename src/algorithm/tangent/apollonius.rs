@@ -0,0 +1,171 @@
+use crate::kernel::{
+    circle_segment_2::CircleSegment2, number_type::NumberType, point_2::Point2, segment_2::Segment2,
+};
+
+use super::tangent_circle_2::{push_tangent_circle_2, tangency_point, TangentCircle2};
+
+/// Circles touching all three circles, up to eight of them.
+///
+/// Every combination of outer and inner tangency gives the equations
+/// |center - center_i| = radius + s_i * radius_i. Their pairwise differences are linear,
+/// so two unknowns follow from the third, which then solves a quadratic.
+/// Circles of radius zero stand for points.
+pub fn apollonius_tangent_circle<T: NumberType>(
+    circle_segment_a: &impl Segment2<T>,
+    circle_segment_b: &impl Segment2<T>,
+    circle_segment_c: &impl Segment2<T>,
+) -> Vec<TangentCircle2<T>> {
+    let circles = [
+        (circle_segment_a.center(), circle_segment_a.radius()),
+        (circle_segment_b.center(), circle_segment_b.radius()),
+        (circle_segment_c.center(), circle_segment_c.radius()),
+    ];
+    let mut result = Vec::new();
+    for signs in 0..8 {
+        let signed_radii: Vec<T> = circles
+            .iter()
+            .enumerate()
+            .map(|(i, (_, radius))| {
+                if signs & (1 << i) == 0 {
+                    *radius
+                } else {
+                    -*radius
+                }
+            })
+            .collect();
+        for (center, radius) in solve(&circles, &signed_radii) {
+            let points: Vec<Point2<T>> = circles
+                .iter()
+                .filter_map(|(circle_center, circle_radius)| {
+                    tangency_point(&center, radius, circle_center, *circle_radius)
+                })
+                .collect();
+            if points.len() == 3 {
+                push_tangent_circle_2(
+                    &mut result,
+                    TangentCircle2::new(CircleSegment2::new(center, radius), points),
+                );
+            }
+        }
+    }
+    result
+}
+
+/// Solves (x - x_i)^2 + (y - y_i)^2 = (r + s_i)^2 for positive r.
+fn solve<T: NumberType>(circles: &[(Point2<T>, T); 3], signed_radii: &[T]) -> Vec<(Point2<T>, T)> {
+    let two = T::from_f64(2.0);
+    let (center_0, radius_0) = (circles[0].0, signed_radii[0]);
+    let square = |point: &Point2<T>, radius: T| {
+        point.x() * point.x() + point.y() * point.y() - radius * radius
+    };
+    let rows: Vec<([T; 3], T)> = (1..3)
+        .map(|i| {
+            let center = circles[i].0;
+            (
+                [
+                    two * (center.x() - center_0.x()),
+                    two * (center.y() - center_0.y()),
+                    two * (signed_radii[i] - radius_0),
+                ],
+                square(&center, signed_radii[i]) - square(&center_0, radius_0),
+            )
+        })
+        .collect();
+    let (a, e_a) = rows[0];
+    let (b, e_b) = rows[1];
+    let mut pivot = None;
+    for free in 0..3 {
+        let (j, k) = ((free + 1) % 3, (free + 2) % 3);
+        let det = a[j] * b[k] - a[k] * b[j];
+        if pivot.is_none_or(|(_, _, _, best): (usize, usize, usize, T)| det.abs() > best.abs()) {
+            pivot = Some((free, j, k, det));
+        }
+    }
+    let (free, j, k, det) = pivot.unwrap();
+    if det.equals(T::zero()) {
+        return Vec::new();
+    }
+    let mut offset = [T::zero(); 3];
+    let mut slope = [T::zero(); 3];
+    slope[free] = T::from_f64(1.0);
+    offset[j] = (e_a * b[k] - e_b * a[k]) / det;
+    slope[j] = -(a[free] * b[k] - b[free] * a[k]) / det;
+    offset[k] = (a[j] * e_b - b[j] * e_a) / det;
+    slope[k] = -(a[j] * b[free] - b[j] * a[free]) / det;
+
+    let x_0 = offset[0] - center_0.x();
+    let y_0 = offset[1] - center_0.y();
+    let r_0 = offset[2] + radius_0;
+    let quadratic_a = slope[0] * slope[0] + slope[1] * slope[1] - slope[2] * slope[2];
+    let quadratic_b = two * (x_0 * slope[0] + y_0 * slope[1] - r_0 * slope[2]);
+    let quadratic_c = x_0 * x_0 + y_0 * y_0 - r_0 * r_0;
+    let mut roots = Vec::new();
+    if quadratic_a.equals(T::zero()) {
+        if !quadratic_b.equals(T::zero()) {
+            roots.push(-quadratic_c / quadratic_b);
+        }
+    } else {
+        let discriminant = quadratic_b * quadratic_b - T::from_f64(4.0) * quadratic_a * quadratic_c;
+        if discriminant.equals(T::zero()) {
+            roots.push(-quadratic_b / (two * quadratic_a));
+        } else if discriminant > T::zero() {
+            let root = discriminant.sqrt();
+            roots.push((-quadratic_b + root) / (two * quadratic_a));
+            roots.push((-quadratic_b - root) / (two * quadratic_a));
+        }
+    }
+    roots
+        .into_iter()
+        .map(|u| {
+            (
+                Point2::new(offset[0] + slope[0] * u, offset[1] + slope[1] * u),
+                offset[2] + slope[2] * u,
+            )
+        })
+        .filter(|(_, radius)| *radius > T::zero() && !radius.equals(T::zero()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apollonius_tangent_circle() {
+        let circle_segments = [
+            CircleSegment2::new(Point2::new(0.0, 0.0), 1.0),
+            CircleSegment2::new(Point2::new(10.0, 0.0), 2.0),
+            CircleSegment2::new(Point2::new(4.0, 8.0), 1.5),
+        ];
+        let result = apollonius_tangent_circle(
+            &circle_segments[0],
+            &circle_segments[1],
+            &circle_segments[2],
+        );
+        assert_eq!(result.len(), 8);
+        for tangent_circle in &result {
+            let circle = tangent_circle.circle();
+            for (point, circle_segment) in
+                tangent_circle.tangency_points().iter().zip(circle_segments)
+            {
+                assert!((point.distance(&circle.center()) - circle.radius()).abs() < 1e-8);
+                assert!(
+                    (point.distance(&circle_segment.center()) - circle_segment.radius()).abs()
+                        < 1e-8
+                );
+            }
+        }
+
+        let points = [
+            CircleSegment2::new(Point2::new(1.0, 0.0), 0.0),
+            CircleSegment2::new(Point2::new(0.0, 1.0), 0.0),
+            CircleSegment2::new(Point2::new(-1.0, 0.0), 0.0),
+        ];
+        let result = apollonius_tangent_circle(&points[0], &points[1], &points[2]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].circle(),
+            CircleSegment2::new(Point2::new(0.0, 0.0), 1.0)
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    algorithm::intersection::segment_2_segment_2::segment_2_segment_2_intersection,
+    kernel::{
+        circle_segment_2::CircleSegment2, line_segment_2::LineSegment2, number_type::NumberType,
+        point_2::Point2, segment_2::Segment2,
+    },
+};
+
+use super::tangent_circle_2::{push_tangent_circle_2, tangency_point, TangentCircle2};
+
+/// Common tangents keeping both circles on the same side, from the tangency point on `circle_segment_a`
+/// to the one on `circle_segment_b`.
+pub fn circle_segment_2_circle_segment_2_outer_tangent<T: NumberType>(
+    circle_segment_a: &impl Segment2<T>,
+    circle_segment_b: &impl Segment2<T>,
+) -> Vec<LineSegment2<T>> {
+    common_tangent(circle_segment_a, circle_segment_b, false)
+}
+
+/// Common tangents passing between the circles, from the tangency point on `circle_segment_a`
+/// to the one on `circle_segment_b`.
+pub fn circle_segment_2_circle_segment_2_inner_tangent<T: NumberType>(
+    circle_segment_a: &impl Segment2<T>,
+    circle_segment_b: &impl Segment2<T>,
+) -> Vec<LineSegment2<T>> {
+    common_tangent(circle_segment_a, circle_segment_b, true)
+}
+
+/// Circles of `radius` touching both circles, from the outside or the inside.
+pub fn circle_segment_2_circle_segment_2_tangent_circle<T: NumberType>(
+    circle_segment_a: &impl Segment2<T>,
+    circle_segment_b: &impl Segment2<T>,
+    radius: T,
+) -> Vec<TangentCircle2<T>> {
+    let mut result = Vec::new();
+    if radius < T::zero() || radius.equals(T::zero()) {
+        return result;
+    }
+    let center_a = circle_segment_a.center();
+    let center_b = circle_segment_b.center();
+    let radius_a = circle_segment_a.radius();
+    let radius_b = circle_segment_b.radius();
+    for distance_a in [radius_a + radius, (radius_a - radius).abs()] {
+        for distance_b in [radius_b + radius, (radius_b - radius).abs()] {
+            if distance_a.equals(T::zero()) || distance_b.equals(T::zero()) {
+                continue;
+            }
+            let points = segment_2_segment_2_intersection(
+                &CircleSegment2::new(center_a, distance_a),
+                &CircleSegment2::new(center_b, distance_b),
+            );
+            for center in points.points() {
+                if let (Some(point_a), Some(point_b)) = (
+                    tangency_point(&center, radius, &center_a, radius_a),
+                    tangency_point(&center, radius, &center_b, radius_b),
+                ) {
+                    push_tangent_circle_2(
+                        &mut result,
+                        TangentCircle2::new(
+                            CircleSegment2::new(center, radius),
+                            vec![point_a, point_b],
+                        ),
+                    );
+                }
+            }
+        }
+    }
+    result
+}
+
+fn common_tangent<T: NumberType>(
+    circle_segment_a: &impl Segment2<T>,
+    circle_segment_b: &impl Segment2<T>,
+    inner: bool,
+) -> Vec<LineSegment2<T>> {
+    let center_a = circle_segment_a.center();
+    let center_b = circle_segment_b.center();
+    let radius_a = circle_segment_a.radius();
+    let radius_b = if inner {
+        -circle_segment_b.radius()
+    } else {
+        circle_segment_b.radius()
+    };
+    let distance = center_a.distance(&center_b);
+    if distance.equals(T::zero()) {
+        return Vec::new();
+    }
+    let one = T::from_f64(1.0);
+    let mut cosine = (radius_a - radius_b) / distance;
+    if cosine.abs() > one && !cosine.abs().equals(one) {
+        return Vec::new();
+    }
+    if cosine > one {
+        cosine = one;
+    } else if cosine < -one {
+        cosine = -one;
+    }
+    let vector = center_b - center_a;
+    let radian = vector.y().atan2(vector.x());
+    let offset = cosine.acos();
+    let radians = if offset.equals(T::zero()) || offset.equals(T::pi()) {
+        vec![radian + offset]
+    } else {
+        vec![radian + offset, radian - offset]
+    };
+    radians
+        .iter()
+        .map(|radian| {
+            let (cos, sin) = (radian.cos(), radian.sin());
+            LineSegment2::new(
+                Point2::new(center_a.x() + radius_a * cos, center_a.y() + radius_a * sin),
+                Point2::new(center_b.x() + radius_b * cos, center_b.y() + radius_b * sin),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circle_segment_2_circle_segment_2_tangent() {
+        let circle_segment_a = CircleSegment2::new(Point2::new(0.0, 0.0), 1.0);
+        let circle_segment_b = CircleSegment2::new(Point2::new(4.0, 0.0), 1.0);
+        assert_eq!(
+            circle_segment_2_circle_segment_2_outer_tangent(&circle_segment_a, &circle_segment_b),
+            vec![
+                LineSegment2::new(Point2::new(0.0, 1.0), Point2::new(4.0, 1.0)),
+                LineSegment2::new(Point2::new(0.0, -1.0), Point2::new(4.0, -1.0)),
+            ]
+        );
+        let inner =
+            circle_segment_2_circle_segment_2_inner_tangent(&circle_segment_a, &circle_segment_b);
+        assert_eq!(
+            inner,
+            vec![
+                LineSegment2::new(
+                    Point2::new(0.5, 0.75_f64.sqrt()),
+                    Point2::new(3.5, -(0.75_f64.sqrt()))
+                ),
+                LineSegment2::new(
+                    Point2::new(0.5, -(0.75_f64.sqrt())),
+                    Point2::new(3.5, 0.75_f64.sqrt())
+                ),
+            ]
+        );
+
+        let touching = CircleSegment2::new(Point2::new(2.0, 0.0), 1.0);
+        assert_eq!(
+            circle_segment_2_circle_segment_2_inner_tangent(&circle_segment_a, &touching),
+            vec![LineSegment2::new(
+                Point2::new(1.0, 0.0),
+                Point2::new(1.0, 0.0)
+            )]
+        );
+        let inside = CircleSegment2::new(Point2::new(0.5, 0.0), 0.2);
+        assert!(
+            circle_segment_2_circle_segment_2_outer_tangent(&circle_segment_a, &inside).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_circle_segment_2_circle_segment_2_tangent_circle() {
+        let circle_segment_a = CircleSegment2::new(Point2::new(0.0, 0.0), 1.0);
+        let circle_segment_b = CircleSegment2::new(Point2::new(4.0, 0.0), 1.0);
+        let result = circle_segment_2_circle_segment_2_tangent_circle(
+            &circle_segment_a,
+            &circle_segment_b,
+            3.0,
+        );
+        assert_eq!(result.len(), 7);
+        assert!(result.contains(&TangentCircle2::new(
+            CircleSegment2::new(Point2::new(2.0, 0.0), 3.0),
+            vec![Point2::new(-1.0, 0.0), Point2::new(5.0, 0.0)]
+        )));
+        for tangent_circle in &result {
+            let circle = tangent_circle.circle();
+            for (point, circle_segment) in tangent_circle
+                .tangency_points()
+                .iter()
+                .zip([circle_segment_a, circle_segment_b])
+            {
+                assert!(point.distance(&circle.center()).equals(3.0));
+                assert!(point.distance(&circle_segment.center()).equals(1.0));
+            }
+        }
+        assert!(circle_segment_2_circle_segment_2_tangent_circle(
+            &circle_segment_a,
+            &circle_segment_b,
+            0.5
+        )
+        .is_empty());
+    }
+}
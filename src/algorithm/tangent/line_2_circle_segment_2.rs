@@ -0,0 +1,98 @@
+use crate::{
+    algorithm::projection::point_2_line_2::point_2_project_line_2,
+    kernel::{
+        circle_segment_2::CircleSegment2, line_2::Line2, number_type::NumberType, point_2::Point2,
+        segment_2::Segment2,
+    },
+};
+
+use super::{
+    line_2_line_2::offset_line_2,
+    tangent_circle_2::{push_tangent_circle_2, tangency_point, TangentCircle2},
+};
+
+/// Circles of `radius` touching the line and the circle, from the outside or the inside.
+pub fn line_2_circle_segment_2_tangent_circle<T: NumberType>(
+    line: &Line2<T>,
+    circle_segment: &impl Segment2<T>,
+    radius: T,
+) -> Vec<TangentCircle2<T>> {
+    let mut result = Vec::new();
+    if radius < T::zero() || radius.equals(T::zero()) {
+        return result;
+    }
+    let circle_center = circle_segment.center();
+    let circle_radius = circle_segment.radius();
+    for side in [T::from_f64(1.0), T::from_f64(-1.0)] {
+        let offset_line = offset_line_2(line, radius * side);
+        for distance in [circle_radius + radius, (circle_radius - radius).abs()] {
+            if distance.equals(T::zero()) {
+                continue;
+            }
+            for center in line_2_circle_intersection(&offset_line, &circle_center, distance) {
+                if let Some(point) = tangency_point(&center, radius, &circle_center, circle_radius)
+                {
+                    push_tangent_circle_2(
+                        &mut result,
+                        TangentCircle2::new(
+                            CircleSegment2::new(center, radius),
+                            vec![point_2_project_line_2(&center, line), point],
+                        ),
+                    );
+                }
+            }
+        }
+    }
+    result
+}
+
+fn line_2_circle_intersection<T: NumberType>(
+    line: &Line2<T>,
+    center: &Point2<T>,
+    radius: T,
+) -> Vec<Point2<T>> {
+    let foot = point_2_project_line_2(center, line);
+    let distance = foot.distance(center);
+    if distance.equals(radius) {
+        return vec![foot];
+    }
+    if distance > radius {
+        return Vec::new();
+    }
+    let half = (radius * radius - distance * distance).sqrt();
+    let norm = (line.a() * line.a() + line.b() * line.b()).sqrt();
+    let (dx, dy) = (line.b() / norm * half, -line.a() / norm * half);
+    vec![
+        Point2::new(foot.x() + dx, foot.y() + dy),
+        Point2::new(foot.x() - dx, foot.y() - dy),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_2_circle_segment_2_tangent_circle() {
+        let line = Line2::new(0.0, 1.0, 0.0);
+        let circle_segment = CircleSegment2::new(Point2::new(0.0, 3.0), 1.0);
+        let result = line_2_circle_segment_2_tangent_circle(&line, &circle_segment, 2.0);
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result[2],
+            TangentCircle2::new(
+                CircleSegment2::new(Point2::new(0.0, 2.0), 2.0),
+                vec![Point2::new(0.0, 0.0), Point2::new(0.0, 4.0)]
+            )
+        );
+        for tangent_circle in &result {
+            let center = tangent_circle.circle().center();
+            let points = tangent_circle.tangency_points();
+            assert!(points[0].y().equals(0.0));
+            assert!(center.distance(&points[0]).equals(2.0));
+            assert!(center.distance(&points[1]).equals(2.0));
+            assert!(circle_segment.center().distance(&points[1]).equals(1.0));
+        }
+        assert!(line_2_circle_segment_2_tangent_circle(&line, &circle_segment, 0.5).is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    algorithm::{
+        intersection::{
+            intersection_enum::Linear2Intersection, line_2_line_2::line_2_line_2_intersection,
+        },
+        projection::point_2_line_2::point_2_project_line_2,
+    },
+    kernel::{circle_segment_2::CircleSegment2, line_2::Line2, number_type::NumberType},
+};
+
+use super::tangent_circle_2::{push_tangent_circle_2, TangentCircle2};
+
+/// Circles of `radius` touching both lines, one in each of the four angles they form.
+///
+/// Parallel lines give no circle.
+pub fn line_2_line_2_tangent_circle<T: NumberType>(
+    line_a: &Line2<T>,
+    line_b: &Line2<T>,
+    radius: T,
+) -> Vec<TangentCircle2<T>> {
+    let mut result = Vec::new();
+    if radius < T::zero() || radius.equals(T::zero()) {
+        return result;
+    }
+    for side_a in [T::from_f64(1.0), T::from_f64(-1.0)] {
+        for side_b in [T::from_f64(1.0), T::from_f64(-1.0)] {
+            let center = line_2_line_2_intersection(
+                &offset_line_2(line_a, radius * side_a),
+                &offset_line_2(line_b, radius * side_b),
+            );
+            if let Linear2Intersection::Point(center, _) = center {
+                push_tangent_circle_2(
+                    &mut result,
+                    TangentCircle2::new(
+                        CircleSegment2::new(center, radius),
+                        vec![
+                            point_2_project_line_2(&center, line_a),
+                            point_2_project_line_2(&center, line_b),
+                        ],
+                    ),
+                );
+            }
+        }
+    }
+    result
+}
+
+/// Line of the points at signed `distance` from the line, positive on the side of its normal (a, b).
+pub(crate) fn offset_line_2<T: NumberType>(line: &Line2<T>, distance: T) -> Line2<T> {
+    let norm = (line.a() * line.a() + line.b() * line.b()).sqrt();
+    Line2::new(line.a(), line.b(), line.c() - distance * norm)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::point_2::Point2;
+
+    use super::*;
+
+    #[test]
+    fn test_line_2_line_2_tangent_circle() {
+        let line_a = Line2::new(0.0, 1.0, 0.0);
+        let line_b = Line2::new(1.0, 0.0, 0.0);
+        let result = line_2_line_2_tangent_circle(&line_a, &line_b, 2.0);
+        assert_eq!(result.len(), 4);
+        assert_eq!(
+            result[0],
+            TangentCircle2::new(
+                CircleSegment2::new(Point2::new(2.0, 2.0), 2.0),
+                vec![Point2::new(2.0, 0.0), Point2::new(0.0, 2.0)]
+            )
+        );
+        assert_eq!(
+            result[3].circle(),
+            CircleSegment2::new(Point2::new(-2.0, -2.0), 2.0)
+        );
+
+        let line_c = Line2::new(0.0, 2.0, -8.0);
+        assert!(line_2_line_2_tangent_circle(&line_a, &line_c, 2.0).is_empty());
+    }
+}
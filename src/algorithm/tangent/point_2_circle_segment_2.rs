@@ -0,0 +1,60 @@
+use crate::kernel::{
+    line_segment_2::LineSegment2, number_type::NumberType, point_2::Point2, segment_2::Segment2,
+};
+
+/// Segments from the point to its two tangency points on the circle.
+///
+/// Points on or inside the circle have no tangent segment.
+pub fn point_2_circle_segment_2_tangent<T: NumberType>(
+    point: &Point2<T>,
+    circle_segment: &impl Segment2<T>,
+) -> Vec<LineSegment2<T>> {
+    let center = circle_segment.center();
+    let radius = circle_segment.radius();
+    let distance = center.distance(point);
+    if distance < radius || distance.equals(radius) {
+        return Vec::new();
+    }
+    let vector = *point - center;
+    let radian = vector.y().atan2(vector.x());
+    let offset = (radius / distance).acos();
+    [radian + offset, radian - offset]
+        .iter()
+        .map(|radian| {
+            LineSegment2::new(
+                *point,
+                Point2::new(
+                    center.x() + radius * radian.cos(),
+                    center.y() + radius * radian.sin(),
+                ),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::circle_segment_2::CircleSegment2;
+
+    use super::*;
+
+    #[test]
+    fn test_point_2_circle_segment_2_tangent() {
+        let circle_segment = CircleSegment2::new(Point2::new(0.0, 0.0), 1.0);
+        let point = Point2::new(2.0, 0.0);
+        let result = point_2_circle_segment_2_tangent(&point, &circle_segment);
+        assert_eq!(
+            result,
+            vec![
+                LineSegment2::new(point, Point2::new(0.5, 0.75_f64.sqrt())),
+                LineSegment2::new(point, Point2::new(0.5, -0.75_f64.sqrt())),
+            ]
+        );
+        assert!(
+            point_2_circle_segment_2_tangent(&Point2::new(1.0, 0.0), &circle_segment).is_empty()
+        );
+        assert!(
+            point_2_circle_segment_2_tangent(&Point2::new(0.5, 0.0), &circle_segment).is_empty()
+        );
+    }
+}
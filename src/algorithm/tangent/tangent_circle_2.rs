@@ -0,0 +1,68 @@
+use crate::kernel::{circle_segment_2::CircleSegment2, number_type::NumberType, point_2::Point2};
+
+/// A constructed circle with one tangency point per given object, in the order of the objects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TangentCircle2<T: NumberType> {
+    circle: CircleSegment2<T>,
+    tangency_points: Vec<Point2<T>>,
+}
+
+impl<T: NumberType> TangentCircle2<T> {
+    pub fn new(circle: CircleSegment2<T>, tangency_points: Vec<Point2<T>>) -> Self {
+        Self {
+            circle,
+            tangency_points,
+        }
+    }
+
+    pub fn circle(&self) -> CircleSegment2<T> {
+        self.circle
+    }
+
+    pub fn tangency_points(&self) -> Vec<Point2<T>> {
+        self.tangency_points.clone()
+    }
+}
+
+/// Pushes the tangent circle unless an equal circle is already present.
+pub(crate) fn push_tangent_circle_2<T: NumberType>(
+    result: &mut Vec<TangentCircle2<T>>,
+    tangent_circle: TangentCircle2<T>,
+) {
+    if result
+        .iter()
+        .all(|other| other.circle != tangent_circle.circle)
+    {
+        result.push(tangent_circle);
+    }
+}
+
+/// Point where the circle around `center` with `radius` touches the given circle.
+///
+/// Returns `None` for concentric circles, whose tangency is undefined.
+pub(crate) fn tangency_point<T: NumberType>(
+    center: &Point2<T>,
+    radius: T,
+    circle_center: &Point2<T>,
+    circle_radius: T,
+) -> Option<Point2<T>> {
+    let vector = *center - *circle_center;
+    let distance = vector.length();
+    if distance.equals(T::zero()) {
+        return None;
+    }
+    let direction = vector.normalize() * circle_radius;
+    let near = Point2::new(
+        circle_center.x() + direction.x(),
+        circle_center.y() + direction.y(),
+    );
+    let far = Point2::new(
+        circle_center.x() - direction.x(),
+        circle_center.y() - direction.y(),
+    );
+    if (near.distance(center) - radius).abs() <= (far.distance(center) - radius).abs() {
+        Some(near)
+    } else {
+        Some(far)
+    }
+}
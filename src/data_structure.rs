@@ -1,3 +1,2 @@
-pub mod avl_tree;
 pub mod circular_doubly_linked_list;
-pub mod priority_queue;
+pub mod treap;
@@ -0,0 +1,362 @@
+use std::cmp::Ordering;
+
+const NIL: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+struct TreapNode<T: Copy> {
+    value: T,
+    priority: u64,
+    left: usize,
+    right: usize,
+    parent: usize,
+}
+
+/// Arena-backed treap ordered by a caller-supplied comparator. Insertion returns a handle
+/// that stays valid until the node is removed, so neighbours are found without searching.
+#[derive(Debug, Clone)]
+pub struct Treap<T: Copy> {
+    nodes: Vec<TreapNode<T>>,
+    free: Vec<usize>,
+    root: usize,
+    seed: u64,
+}
+
+impl<T: Copy> Treap<T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            seed: 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.free.clear();
+        self.root = NIL;
+    }
+
+    pub fn get(&self, handle: usize) -> T {
+        self.nodes[handle].value
+    }
+
+    /// Equal values go to the right of the ones already stored.
+    pub fn insert_by(&mut self, value: T, mut compare: impl FnMut(&T, &T) -> Ordering) -> usize {
+        let priority = self.next_priority();
+        let node = TreapNode {
+            value,
+            priority,
+            left: NIL,
+            right: NIL,
+            parent: NIL,
+        };
+        let handle = match self.free.pop() {
+            Some(handle) => {
+                self.nodes[handle] = node;
+                handle
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        if self.root == NIL {
+            self.root = handle;
+            return handle;
+        }
+        let mut current = self.root;
+        loop {
+            if compare(&value, &self.nodes[current].value) == Ordering::Less {
+                if self.nodes[current].left == NIL {
+                    self.nodes[current].left = handle;
+                    break;
+                }
+                current = self.nodes[current].left;
+            } else {
+                if self.nodes[current].right == NIL {
+                    self.nodes[current].right = handle;
+                    break;
+                }
+                current = self.nodes[current].right;
+            }
+        }
+        self.nodes[handle].parent = current;
+        while self.nodes[handle].parent != NIL
+            && self.nodes[self.nodes[handle].parent].priority < self.nodes[handle].priority
+        {
+            let parent = self.nodes[handle].parent;
+            if self.nodes[parent].left == handle {
+                self.rotate_right(parent);
+            } else {
+                self.rotate_left(parent);
+            }
+        }
+        handle
+    }
+
+    pub fn remove(&mut self, handle: usize) -> T {
+        loop {
+            let left = self.nodes[handle].left;
+            let right = self.nodes[handle].right;
+            if left == NIL && right == NIL {
+                break;
+            }
+            if right == NIL
+                || (left != NIL && self.nodes[left].priority > self.nodes[right].priority)
+            {
+                self.rotate_right(handle);
+            } else {
+                self.rotate_left(handle);
+            }
+        }
+        self.replace_child(self.nodes[handle].parent, handle, NIL);
+        self.free.push(handle);
+        self.nodes[handle].value
+    }
+
+    pub fn prev(&self, handle: usize) -> Option<usize> {
+        let left = self.nodes[handle].left;
+        if left != NIL {
+            return Some(self.rightmost(left));
+        }
+        let mut current = handle;
+        let mut parent = self.nodes[current].parent;
+        while parent != NIL && self.nodes[parent].left == current {
+            current = parent;
+            parent = self.nodes[current].parent;
+        }
+        if parent == NIL {
+            None
+        } else {
+            Some(parent)
+        }
+    }
+
+    pub fn next(&self, handle: usize) -> Option<usize> {
+        let right = self.nodes[handle].right;
+        if right != NIL {
+            return Some(self.leftmost(right));
+        }
+        let mut current = handle;
+        let mut parent = self.nodes[current].parent;
+        while parent != NIL && self.nodes[parent].right == current {
+            current = parent;
+            parent = self.nodes[current].parent;
+        }
+        if parent == NIL {
+            None
+        } else {
+            Some(parent)
+        }
+    }
+
+    /// First value, in order, for which `predicate` holds; the predicate must be false for a
+    /// prefix of the values and true for the rest.
+    pub fn lower_bound_by(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        let mut result = None;
+        let mut current = self.root;
+        while current != NIL {
+            if predicate(&self.nodes[current].value) {
+                result = Some(current);
+                current = self.nodes[current].left;
+            } else {
+                current = self.nodes[current].right;
+            }
+        }
+        result
+    }
+
+    fn next_priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        self.seed
+    }
+
+    fn leftmost(&self, mut handle: usize) -> usize {
+        while self.nodes[handle].left != NIL {
+            handle = self.nodes[handle].left;
+        }
+        handle
+    }
+
+    fn rightmost(&self, mut handle: usize) -> usize {
+        while self.nodes[handle].right != NIL {
+            handle = self.nodes[handle].right;
+        }
+        handle
+    }
+
+    fn replace_child(&mut self, parent: usize, old: usize, new: usize) {
+        if parent == NIL {
+            self.root = new;
+        } else if self.nodes[parent].left == old {
+            self.nodes[parent].left = new;
+        } else {
+            self.nodes[parent].right = new;
+        }
+        if new != NIL {
+            self.nodes[new].parent = parent;
+        }
+    }
+
+    fn rotate_left(&mut self, handle: usize) {
+        let right = self.nodes[handle].right;
+        let inner = self.nodes[right].left;
+        self.nodes[handle].right = inner;
+        if inner != NIL {
+            self.nodes[inner].parent = handle;
+        }
+        self.replace_child(self.nodes[handle].parent, handle, right);
+        self.nodes[right].left = handle;
+        self.nodes[handle].parent = right;
+    }
+
+    fn rotate_right(&mut self, handle: usize) {
+        let left = self.nodes[handle].left;
+        let inner = self.nodes[left].right;
+        self.nodes[handle].left = inner;
+        if inner != NIL {
+            self.nodes[inner].parent = handle;
+        }
+        self.replace_child(self.nodes[handle].parent, handle, left);
+        self.nodes[left].right = handle;
+        self.nodes[handle].parent = left;
+    }
+}
+
+impl<T: Copy> Default for Treap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(treap: &Treap<(i32, usize)>) -> Vec<(i32, usize)> {
+        let mut values = Vec::new();
+        let mut current = treap.lower_bound_by(|_| true);
+        while let Some(handle) = current {
+            values.push(treap.get(handle));
+            current = treap.next(handle);
+        }
+        values
+    }
+
+    fn insert(treap: &mut Treap<(i32, usize)>, key: i32, id: usize) -> usize {
+        treap.insert_by((key, id), |a, b| a.0.cmp(&b.0))
+    }
+
+    #[test]
+    fn test_treap() {
+        let mut treap = Treap::new();
+        let keys = [5, 1, 9, 3, 7, 2, 8];
+        let handles: Vec<usize> = keys
+            .iter()
+            .enumerate()
+            .map(|(id, key)| insert(&mut treap, *key, id))
+            .collect();
+        let keys_in_order: Vec<i32> = values(&treap).iter().map(|value| value.0).collect();
+        assert_eq!(keys_in_order, vec![1, 2, 3, 5, 7, 8, 9]);
+
+        assert_eq!(treap.get(handles[0]), (5, 0));
+        assert_eq!(treap.prev(handles[0]).map(|h| treap.get(h)), Some((3, 3)));
+        assert_eq!(treap.next(handles[0]).map(|h| treap.get(h)), Some((7, 4)));
+        assert_eq!(treap.prev(handles[1]), None);
+        assert_eq!(treap.next(handles[2]), None);
+
+        assert_eq!(treap.remove(handles[0]), (5, 0));
+        assert_eq!(treap.next(handles[3]).map(|h| treap.get(h)), Some((7, 4)));
+        assert_eq!(treap.prev(handles[4]).map(|h| treap.get(h)), Some((3, 3)));
+        assert_eq!(treap.remove(handles[1]), (1, 1));
+        assert_eq!(treap.prev(handles[5]), None);
+        let keys_in_order: Vec<i32> = values(&treap).iter().map(|value| value.0).collect();
+        assert_eq!(keys_in_order, vec![2, 3, 7, 8, 9]);
+
+        let reused = insert(&mut treap, 4, 7);
+        assert!(reused == handles[0] || reused == handles[1]);
+        assert_eq!(treap.get(reused), (4, 7));
+        assert_eq!(treap.prev(reused).map(|h| treap.get(h)), Some((3, 3)));
+        assert_eq!(treap.next(reused).map(|h| treap.get(h)), Some((7, 4)));
+
+        treap.clear();
+        assert_eq!(treap.lower_bound_by(|_| true), None);
+        let handle = insert(&mut treap, 1, 0);
+        assert_eq!(values(&treap), vec![(1, 0)]);
+        assert_eq!(treap.remove(handle), (1, 0));
+        assert_eq!(values(&treap), vec![]);
+    }
+
+    #[test]
+    fn test_treap_duplicates() {
+        let mut treap = Treap::new();
+        let mut handles = Vec::new();
+        for id in 0..6 {
+            handles.push(insert(&mut treap, (id % 2) as i32, id));
+        }
+        assert_eq!(
+            values(&treap),
+            vec![(0, 0), (0, 2), (0, 4), (1, 1), (1, 3), (1, 5)]
+        );
+        treap.remove(handles[2]);
+        insert(&mut treap, 0, 6);
+        assert_eq!(
+            values(&treap),
+            vec![(0, 0), (0, 4), (0, 6), (1, 1), (1, 3), (1, 5)]
+        );
+    }
+
+    #[test]
+    fn test_treap_lower_bound_by() {
+        let mut treap = Treap::new();
+        for (id, key) in [10, 30, 20, 30, 40].iter().enumerate() {
+            insert(&mut treap, *key, id);
+        }
+        let lower_bound = |key: i32| {
+            treap
+                .lower_bound_by(|value| value.0 >= key)
+                .map(|handle| treap.get(handle))
+        };
+        assert_eq!(lower_bound(0), Some((10, 0)));
+        assert_eq!(lower_bound(10), Some((10, 0)));
+        assert_eq!(lower_bound(11), Some((20, 2)));
+        assert_eq!(lower_bound(30), Some((30, 1)));
+        assert_eq!(lower_bound(40), Some((40, 4)));
+        assert_eq!(lower_bound(41), None);
+    }
+
+    #[test]
+    fn test_treap_random() {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let mut treap = Treap::new();
+        let mut expected: Vec<((i32, usize), usize)> = Vec::new();
+        for id in 0..2000 {
+            if !expected.is_empty() && random() % 3 == 0 {
+                let (value, handle) = expected.remove((random() % expected.len() as u64) as usize);
+                assert_eq!(treap.remove(handle), value);
+            } else {
+                let key = (random() % 50) as i32;
+                let handle = insert(&mut treap, key, id);
+                let position = expected.partition_point(|(value, _)| value.0 <= key);
+                expected.insert(position, ((key, id), handle));
+            }
+        }
+        let expected_values: Vec<(i32, usize)> = expected.iter().map(|(value, _)| *value).collect();
+        assert_eq!(values(&treap), expected_values);
+        for (index, (_, handle)) in expected.iter().enumerate() {
+            let prev = index.checked_sub(1).map(|index| expected[index].1);
+            let next = expected.get(index + 1).map(|(_, handle)| *handle);
+            assert_eq!(treap.prev(*handle), prev);
+            assert_eq!(treap.next(*handle), next);
+        }
+    }
+}
@@ -1,14 +1,21 @@
 pub mod arc_segment_2;
+pub mod bbox_2;
 pub mod circle_segment_2;
+pub mod clothoid_2;
+pub mod curve_2;
 pub mod edge_2;
 pub mod face_2;
 pub mod line_2;
+pub mod line_arc_segment_2;
 pub mod line_segment_2;
 pub mod number_type;
+pub mod nurbs_curve_2;
 pub mod point_2;
 pub mod polygon_2;
+pub mod polyline_2;
 pub mod ray_2;
 pub mod segment_2;
+pub mod tessellation_2;
 pub mod triangle_2;
 pub mod util_enum;
 pub mod vector_2;
@@ -1,72 +1,364 @@
 use super::{
-    circle_segment_2::CircleSegment2, number_type::NumberType, point_2::Point2,
-    segment_2::Segment2, util_enum::Segment2Type,
+    bbox_2::BBox2,
+    circle_segment_2::CircleSegment2,
+    curve_2::{clamp_parameter, Curve2},
+    number_type::NumberType,
+    point_2::Point2,
+    segment_2::Segment2,
+    tessellation_2::{circular_pieces, point_on_circle, ChordTolerance},
+    util_enum::{ApproximationSide, GeometryError, Orientation, Segment2Type, TurnDirection},
+    vector_2::Vector2,
 };
 
+/// Circular arc with its source radian in [0, 2pi) and its sweep in [0, 2pi] along `orientation`.
+///
+/// A zero sweep is a zero-length arc and a sweep of 2pi is a full circle.
 #[derive(Debug, Clone, Copy)]
 pub struct ArcSegment2<T: NumberType> {
     support: CircleSegment2<T>,
     source_radian: T,
-    target_radian: T,
+    sweep_radian: T,
+    orientation: Orientation,
 }
 
 impl<T: NumberType> ArcSegment2<T> {
+    /// Counter-clockwise arc, see [`ArcSegment2::with_orientation`].
+    ///
+    /// # Panics
+    ///
+    /// Panics with [`GeometryError::InvalidSweep`] when the span exceeds 2pi; use
+    /// [`ArcSegment2::with_orientation`] to handle such radians without panicking.
     pub fn new(support: CircleSegment2<T>, source_radian: T, target_radian: T) -> Self {
-        let mut source_radian = source_radian;
-        let mut target_radian = target_radian;
-        let four_pi = T::pi() * T::from_f64(4.0);
-        while !source_radian.equals(four_pi) && source_radian > four_pi {
-            source_radian = source_radian - four_pi;
+        match Self::with_orientation(
+            support,
+            source_radian,
+            target_radian,
+            Orientation::CounterClockwise,
+        ) {
+            Ok(arc_segment) => arc_segment,
+            Err(error) => panic!("{}", error),
         }
-        while !target_radian.equals(four_pi) && target_radian > four_pi {
-            target_radian = target_radian - four_pi;
+    }
+
+    /// The span from source to target is measured along the orientation and wraps once when it runs backwards,
+    /// so equal radians give a zero-length arc while a full circle needs the target one turn ahead.
+    pub fn with_orientation(
+        support: CircleSegment2<T>,
+        source_radian: T,
+        target_radian: T,
+        orientation: Orientation,
+    ) -> Result<Self, GeometryError> {
+        let mut span = match orientation {
+            Orientation::CounterClockwise => target_radian - source_radian,
+            Orientation::Clockwise => source_radian - target_radian,
+        };
+        if span < T::zero() && !span.equals(T::zero()) {
+            span = span + T::pi() * T::from_f64(2.0);
         }
-        if source_radian.equals(target_radian) {
-            source_radian = T::zero();
-            target_radian = T::pi() * T::from_f64(2.0);
+        Self::checked(support, source_radian, span, orientation)
+    }
+
+    /// Positive sweeps turn counter-clockwise and negative sweeps clockwise.
+    pub fn from_sweep(
+        support: CircleSegment2<T>,
+        source_radian: T,
+        sweep_radian: T,
+    ) -> Result<Self, GeometryError> {
+        let orientation = if sweep_radian < T::zero() && !sweep_radian.equals(T::zero()) {
+            Orientation::Clockwise
+        } else {
+            Orientation::CounterClockwise
+        };
+        Self::checked(support, source_radian, sweep_radian.abs(), orientation)
+    }
+
+    /// Arc from `source` through `mid` to `target`.
+    pub fn from_three_points(
+        source: &Point2<T>,
+        mid: &Point2<T>,
+        target: &Point2<T>,
+    ) -> Result<Self, GeometryError> {
+        if source == mid || mid == target || source == target {
+            return Err(GeometryError::CoincidentPoints);
         }
-        Self {
+        let orientation = match Point2::turn(source, mid, target) {
+            TurnDirection::Left => Orientation::CounterClockwise,
+            TurnDirection::Right => Orientation::Clockwise,
+            TurnDirection::Collinear => return Err(GeometryError::CollinearPoints),
+        };
+        let a = *mid - *source;
+        let b = *target - *source;
+        let denominator = T::from_f64(2.0) * a.cross(&b);
+        let a_squared = a.dot(&a);
+        let b_squared = b.dot(&b);
+        let center = Point2::new(
+            source.x() + (b.y() * a_squared - a.y() * b_squared) / denominator,
+            source.y() + (a.x() * b_squared - b.x() * a_squared) / denominator,
+        );
+        Self::with_orientation(
+            CircleSegment2::new(center, center.distance(source)),
+            radian_of(&center, source),
+            radian_of(&center, target),
+            orientation,
+        )
+    }
+
+    /// Arc from `source` to `target` with the DXF bulge, the tangent of a quarter of the sweep.
+    ///
+    /// Positive bulges turn counter-clockwise and a bulge of zero is a straight line.
+    pub fn from_bulge(
+        source: &Point2<T>,
+        target: &Point2<T>,
+        bulge: T,
+    ) -> Result<Self, GeometryError> {
+        if source == target {
+            return Err(GeometryError::CoincidentPoints);
+        }
+        if bulge.equals(T::zero()) {
+            return Err(GeometryError::CollinearPoints);
+        }
+        let chord = *target - *source;
+        let normal = Vector2::new(-chord.y(), chord.x());
+        let offset = (T::from_f64(1.0) - bulge * bulge) / (T::from_f64(4.0) * bulge);
+        let center = Point2::new(
+            (source.x() + target.x()) / T::from_f64(2.0) + normal.x() * offset,
+            (source.y() + target.y()) / T::from_f64(2.0) + normal.y() * offset,
+        );
+        Self::from_sweep(
+            CircleSegment2::new(center, center.distance(source)),
+            radian_of(&center, source),
+            T::from_f64(4.0) * bulge.atan(),
+        )
+    }
+
+    /// Arc around `center` from `source` to the radian of `target`, which must lie on the same circle.
+    pub fn from_center_source_target(
+        center: &Point2<T>,
+        source: &Point2<T>,
+        target: &Point2<T>,
+        orientation: Orientation,
+    ) -> Result<Self, GeometryError> {
+        let radius = center.distance(source);
+        if radius.equals(T::zero()) || !center.distance(target).equals(radius) {
+            return Err(GeometryError::InvalidRadius);
+        }
+        Self::with_orientation(
+            CircleSegment2::new(*center, radius),
+            radian_of(center, source),
+            radian_of(center, target),
+            orientation,
+        )
+    }
+
+    /// Arc leaving `source` along `tangent` and ending at `target`.
+    pub fn from_source_tangent_target(
+        source: &Point2<T>,
+        tangent: &Vector2<T>,
+        target: &Point2<T>,
+    ) -> Result<Self, GeometryError> {
+        if tangent.length().equals(T::zero()) {
+            return Err(GeometryError::InvalidLength);
+        }
+        if source == target {
+            return Err(GeometryError::CoincidentPoints);
+        }
+        let tangent = tangent.normalize();
+        let normal = Vector2::new(-tangent.y(), tangent.x());
+        let chord = *target - *source;
+        let projection = normal.dot(&chord);
+        if projection.equals(T::zero()) {
+            return Err(GeometryError::CollinearPoints);
+        }
+        let signed_radius = chord.dot(&chord) / (T::from_f64(2.0) * projection);
+        let center = Point2::new(
+            source.x() + normal.x() * signed_radius,
+            source.y() + normal.y() * signed_radius,
+        );
+        let orientation = if signed_radius > T::zero() {
+            Orientation::CounterClockwise
+        } else {
+            Orientation::Clockwise
+        };
+        Self::with_orientation(
+            CircleSegment2::new(center, signed_radius.abs()),
+            radian_of(&center, source),
+            radian_of(&center, target),
+            orientation,
+        )
+    }
+
+    fn checked(
+        support: CircleSegment2<T>,
+        source_radian: T,
+        sweep_radian: T,
+        orientation: Orientation,
+    ) -> Result<Self, GeometryError> {
+        let two_pi = T::pi() * T::from_f64(2.0);
+        if (sweep_radian < T::zero() || sweep_radian > two_pi)
+            && !sweep_radian.equals(T::zero())
+            && !sweep_radian.equals(two_pi)
+        {
+            return Err(GeometryError::InvalidSweep);
+        }
+        Ok(Self::canonical(
             support,
             source_radian,
-            target_radian,
+            sweep_radian,
+            orientation,
+        ))
+    }
+
+    fn canonical(
+        support: CircleSegment2<T>,
+        source_radian: T,
+        sweep_radian: T,
+        orientation: Orientation,
+    ) -> Self {
+        let two_pi = T::pi() * T::from_f64(2.0);
+        let sweep_radian = if sweep_radian.equals(T::zero()) {
+            T::zero()
+        } else if sweep_radian.equals(two_pi) {
+            two_pi
+        } else {
+            sweep_radian
+        };
+        Self {
+            support,
+            source_radian: normalize_radian(source_radian),
+            sweep_radian,
+            orientation,
         }
     }
 
+    pub fn support(&self) -> CircleSegment2<T> {
+        self.support
+    }
+
+    pub fn is_zero_length(&self) -> bool {
+        self.sweep_radian.equals(T::zero())
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.sweep_radian.equals(T::pi() * T::from_f64(2.0))
+    }
+
+    /// Signed sweep, positive when counter-clockwise.
+    pub fn sweep_angle(&self) -> T {
+        self.signed_sweep()
+    }
+
+    /// DXF bulge, the tangent of a quarter of the signed sweep.
+    pub fn bulge(&self) -> T {
+        let quarter = self.signed_sweep() / T::from_f64(4.0);
+        quarter.sin() / quarter.cos()
+    }
+
+    pub fn mid_point(&self) -> Point2<T> {
+        self.point_at(T::from_f64(0.5))
+    }
+
+    fn signed_sweep(&self) -> T {
+        match self.orientation {
+            Orientation::CounterClockwise => self.sweep_radian,
+            Orientation::Clockwise => -self.sweep_radian,
+        }
+    }
+
+    /// Start of the same point set traversed counter-clockwise.
+    fn counter_clockwise_source_radian(&self) -> T {
+        match self.orientation {
+            Orientation::CounterClockwise => self.source_radian,
+            Orientation::Clockwise => self.target_radian(),
+        }
+    }
+
+    fn radian_at(&self, t: T) -> T {
+        self.source_radian + self.signed_sweep() * clamp_parameter(t)
+    }
+
+    /// Offset of the radian from the source along the orientation, in [0, 2pi).
+    fn offset_of(&self, radian: T) -> T {
+        match self.orientation {
+            Orientation::CounterClockwise => normalize_radian(radian - self.source_radian),
+            Orientation::Clockwise => normalize_radian(self.source_radian - radian),
+        }
+    }
+
+    /// Points from source to target, see [`ApproximationSide`] for `side`.
+    pub fn tessellate(
+        &self,
+        tolerance: ChordTolerance<T>,
+        side: ApproximationSide,
+    ) -> Result<Vec<Point2<T>>, GeometryError> {
+        let pieces = circular_pieces(self.radius(), self.sweep_radian, tolerance, side, 1)?;
+        if self.is_zero_length() {
+            return Ok(vec![self.source()]);
+        }
+        let step = self.signed_sweep() / T::from_f64(pieces as f64);
+        let center = self.center();
+        let radius = self.radius();
+        let points = match side {
+            ApproximationSide::Inner => (0..=pieces)
+                .map(|i| {
+                    point_on_circle(
+                        &center,
+                        radius,
+                        self.source_radian + step * T::from_f64(i as f64),
+                    )
+                })
+                .collect(),
+            ApproximationSide::Outer => {
+                let half_step = step / T::from_f64(2.0);
+                let mut points = vec![self.source()];
+                points.extend((0..pieces).map(|i| {
+                    point_on_circle(
+                        &center,
+                        radius / half_step.cos(),
+                        self.source_radian + half_step + step * T::from_f64(i as f64),
+                    )
+                }));
+                points.push(self.target());
+                points
+            }
+        };
+        Ok(points)
+    }
+
     pub fn is_top(&self) -> bool {
         let pi = T::pi();
-        self.source_radian < pi && (self.target_radian.equals(pi) || self.target_radian < pi)
+        let source_radian = self.counter_clockwise_source_radian();
+        let target_radian = source_radian + self.sweep_radian;
+        source_radian < pi && (target_radian.equals(pi) || target_radian < pi)
     }
 
+    /// Splits the arc at the extreme left and right points into counter-clockwise x-monotone pieces.
     pub fn monotone(&self) -> Vec<ArcSegment2<T>> {
-        let mut arcs = Vec::new();
+        if self.is_zero_length() {
+            return vec![*self];
+        }
         let pi = T::pi();
-        let mut radians = Vec::new();
-        let mut end_pi = T::zero();
-        let mut min_flag = false;
-        while !end_pi.equals(self.target_radian) && end_pi < self.target_radian {
-            if !min_flag && end_pi > self.source_radian {
-                min_flag = true;
-                if end_pi > self.source_radian {
-                    radians.push(end_pi - pi);
-                    if !self.source_radian.equals(end_pi - pi) {
-                        radians.push(self.source_radian.clone());
-                    }
-                }
-            }
-            if end_pi.equals(self.source_radian) || end_pi > self.source_radian {
-                radians.push(end_pi.clone());
+        let source_radian = self.counter_clockwise_source_radian();
+        let target_radian = source_radian + self.sweep_radian;
+        let mut radians = vec![source_radian];
+        let mut cut = pi;
+        while cut < target_radian && !cut.equals(target_radian) {
+            if cut > source_radian && !cut.equals(source_radian) {
+                radians.push(cut);
             }
-            end_pi = end_pi + pi;
-        }
-        radians.push(self.target_radian.clone());
-        for i in 0..radians.len() - 1 {
-            arcs.push(ArcSegment2::new(
-                self.support,
-                radians[i].clone(),
-                radians[i + 1].clone(),
-            ));
+            cut = cut + pi;
         }
-        arcs
+        radians.push(target_radian);
+        radians
+            .windows(2)
+            .map(|pair| {
+                ArcSegment2::canonical(
+                    self.support,
+                    pair[0],
+                    pair[1] - pair[0],
+                    Orientation::CounterClockwise,
+                )
+            })
+            .collect()
     }
 }
 
@@ -90,13 +382,22 @@ impl<T: NumberType> Segment2<T> for ArcSegment2<T> {
     fn target(&self) -> Point2<T> {
         let center = self.center();
         let radius = self.radius();
-        let x = center.x() + radius * self.target_radian.cos();
-        let y = center.y() + radius * self.target_radian.sin();
+        let target_radian = self.target_radian();
+        let x = center.x() + radius * target_radian.cos();
+        let y = center.y() + radius * target_radian.sin();
         Point2::new(x, y)
     }
 
     fn target_radian(&self) -> T {
-        self.target_radian
+        normalize_radian(self.source_radian + self.signed_sweep())
+    }
+
+    fn sweep_radian(&self) -> T {
+        self.sweep_radian
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
     }
 
     fn center(&self) -> Point2<T> {
@@ -108,11 +409,472 @@ impl<T: NumberType> Segment2<T> for ArcSegment2<T> {
     }
 }
 
+impl<T: NumberType> Curve2<T> for ArcSegment2<T> {
+    type Piece = ArcSegment2<T>;
+
+    fn point_at(&self, t: T) -> Point2<T> {
+        let radian = self.radian_at(t);
+        let center = self.center();
+        let radius = self.radius();
+        Point2::new(
+            center.x() + radius * radian.cos(),
+            center.y() + radius * radian.sin(),
+        )
+    }
+
+    fn tangent_at(&self, t: T) -> Vector2<T> {
+        let radian = self.radian_at(t);
+        match self.orientation {
+            Orientation::CounterClockwise => Vector2::new(-radian.sin(), radian.cos()),
+            Orientation::Clockwise => Vector2::new(radian.sin(), -radian.cos()),
+        }
+    }
+
+    fn curvature_at(&self, _t: T) -> T {
+        match self.orientation {
+            Orientation::CounterClockwise => T::from_f64(1.0) / self.radius(),
+            Orientation::Clockwise => T::from_f64(-1.0) / self.radius(),
+        }
+    }
+
+    fn length(&self) -> T {
+        self.radius() * self.sweep_radian
+    }
+
+    fn split_at(&self, t: T) -> (Self, Self) {
+        let t = clamp_parameter(t);
+        let first_sweep = self.sweep_radian * t;
+        (
+            ArcSegment2::canonical(
+                self.support,
+                self.source_radian,
+                first_sweep,
+                self.orientation,
+            ),
+            ArcSegment2::canonical(
+                self.support,
+                self.radian_at(t),
+                self.sweep_radian - first_sweep,
+                self.orientation,
+            ),
+        )
+    }
+
+    fn reverse(&self) -> Self {
+        let orientation = match self.orientation {
+            Orientation::CounterClockwise => Orientation::Clockwise,
+            Orientation::Clockwise => Orientation::CounterClockwise,
+        };
+        ArcSegment2::canonical(
+            self.support,
+            self.target_radian(),
+            self.sweep_radian,
+            orientation,
+        )
+    }
+
+    fn closest_parameter(&self, point: &Point2<T>) -> T {
+        if self.is_zero_length() {
+            return T::zero();
+        }
+        let vector = *point - self.center();
+        let radian = self.offset_of(vector.y().atan2(vector.x()));
+        if radian > self.sweep_radian {
+            if point.distance(&self.source()) < point.distance(&self.target()) {
+                T::zero()
+            } else {
+                T::from_f64(1.0)
+            }
+        } else {
+            radian / self.sweep_radian
+        }
+    }
+
+    fn bbox(&self) -> BBox2<T> {
+        let mut bbox = BBox2::new(self.source(), self.target());
+        let center = self.center();
+        let radius = self.radius();
+        let half_pi = T::pi() / T::from_f64(2.0);
+        let source_radian = self.counter_clockwise_source_radian();
+        let target_radian = source_radian + self.sweep_radian;
+        for quarter in 0..8 {
+            let radian = half_pi * T::from_f64(quarter as f64);
+            if radian > source_radian && radian < target_radian {
+                let point = Point2::new(
+                    center.x() + radius * radian.cos(),
+                    center.y() + radius * radian.sin(),
+                );
+                bbox = bbox.union(&BBox2::new(point, point));
+            }
+        }
+        bbox
+    }
+}
+
 impl<T: NumberType> PartialEq for ArcSegment2<T> {
     fn eq(&self, other: &Self) -> bool {
         self.center() == other.center()
-            && self.radius() == other.radius()
+            && self.radius().equals(other.radius())
             && self.source_radian.equals(other.source_radian)
-            && self.target_radian.equals(other.target_radian)
+            && self.sweep_radian.equals(other.sweep_radian)
+            && self.orientation == other.orientation
+    }
+}
+
+fn radian_of<T: NumberType>(center: &Point2<T>, point: &Point2<T>) -> T {
+    let vector = *point - *center;
+    vector.y().atan2(vector.x())
+}
+
+/// Maps the radian into [0, 2pi).
+pub(crate) fn normalize_radian<T: NumberType>(radian: T) -> T {
+    let two_pi = T::pi() * T::from_f64(2.0);
+    let mut radian = radian;
+    while radian < T::zero() {
+        radian = radian + two_pi;
+    }
+    while radian > two_pi || radian.equals(two_pi) {
+        radian = radian - two_pi;
+    }
+    if radian.equals(T::zero()) {
+        T::zero()
+    } else {
+        radian
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn test_arc_segment_2_curve() {
+        let support = CircleSegment2::new(Point2::new(1.0, 1.0), 2.0);
+        let arc_segment = ArcSegment2::new(support, 1.5 * PI, 0.5 * PI);
+        assert!(arc_segment.length().equals(2.0 * PI));
+        assert_eq!(arc_segment.point_at(0.5), Point2::new(3.0, 1.0));
+        assert_eq!(arc_segment.tangent_at(0.5), Vector2::new(0.0, 1.0));
+        assert_eq!(arc_segment.curvature_at(0.5), 0.5);
+        assert!(arc_segment
+            .closest_parameter(&Point2::new(5.0, 1.0))
+            .equals(0.5));
+        assert_eq!(arc_segment.closest_parameter(&Point2::new(-1.0, -0.5)), 0.0);
+        assert_eq!(arc_segment.closest_parameter(&Point2::new(-1.0, 2.5)), 1.0);
+
+        let (first, second) = arc_segment.split_at(0.5);
+        assert_eq!(first, ArcSegment2::new(support, 1.5 * PI, 2.0 * PI));
+        assert_eq!(second, ArcSegment2::new(support, 2.0 * PI, 2.5 * PI));
+        assert_eq!(
+            arc_segment.bbox(),
+            BBox2::new(Point2::new(1.0, -1.0), Point2::new(3.0, 3.0))
+        );
+        assert_eq!(
+            ArcSegment2::new(support, 0.25 * PI, 0.75 * PI).bbox(),
+            BBox2::new(
+                Point2::new(1.0 - 2.0_f64.sqrt(), 1.0 + 2.0_f64.sqrt()),
+                Point2::new(1.0 + 2.0_f64.sqrt(), 3.0)
+            )
+        );
+    }
+
+    #[test]
+    fn test_arc_segment_2_orientation() {
+        let support = CircleSegment2::new(Point2::new(0.0, 0.0), 1.0);
+        let arc_segment = ArcSegment2::new(support, -0.5 * PI, 0.5 * PI);
+        assert!(arc_segment.source_radian().equals(1.5 * PI));
+        assert!(arc_segment.target_radian().equals(0.5 * PI));
+        assert!(arc_segment.sweep_radian().equals(PI));
+        assert_eq!(
+            ArcSegment2::with_orientation(support, 0.0, 3.0 * PI, Orientation::CounterClockwise)
+                .err(),
+            Some(GeometryError::InvalidSweep)
+        );
+        assert_eq!(
+            ArcSegment2::from_sweep(support, 0.0, -2.5 * PI).err(),
+            Some(GeometryError::InvalidSweep)
+        );
+
+        let zero_length = ArcSegment2::new(support, PI, PI);
+        assert!(zero_length.is_zero_length());
+        assert!(zero_length.length().equals(0.0));
+        assert_eq!(zero_length.monotone(), vec![zero_length]);
+        let full = ArcSegment2::new(support, PI, 3.0 * PI);
+        assert!(full.is_full());
+        assert_eq!(full.monotone().len(), 2);
+
+        let clockwise =
+            ArcSegment2::with_orientation(support, 0.5 * PI, 1.5 * PI, Orientation::Clockwise)
+                .unwrap();
+        assert_eq!(clockwise.orientation(), Orientation::Clockwise);
+        assert!(clockwise.sweep_radian().equals(PI));
+        assert_eq!(clockwise.point_at(0.5), Point2::new(1.0, 0.0));
+        assert_eq!(clockwise.tangent_at(0.5), Vector2::new(0.0, -1.0));
+        assert_eq!(clockwise.curvature_at(0.5), -1.0);
+        assert!(clockwise
+            .closest_parameter(&Point2::new(2.0, 0.0))
+            .equals(0.5));
+        assert_eq!(
+            clockwise,
+            ArcSegment2::from_sweep(support, 0.5 * PI, -PI).unwrap()
+        );
+        assert_eq!(
+            clockwise.reverse(),
+            ArcSegment2::new(support, 1.5 * PI, 0.5 * PI)
+        );
+        assert_eq!(clockwise.reverse().reverse(), clockwise);
+        assert_eq!(
+            clockwise.bbox(),
+            BBox2::new(Point2::new(0.0, -1.0), Point2::new(1.0, 1.0))
+        );
+        assert_eq!(
+            clockwise.monotone(),
+            vec![
+                ArcSegment2::new(support, 1.5 * PI, 2.0 * PI),
+                ArcSegment2::new(support, 0.0, 0.5 * PI)
+            ]
+        );
+        let (first, second) = clockwise.split_at(0.5);
+        assert_eq!(
+            first,
+            ArcSegment2::from_sweep(support, 0.5 * PI, -0.5 * PI).unwrap()
+        );
+        assert_eq!(
+            second,
+            ArcSegment2::from_sweep(support, 0.0, -0.5 * PI).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "arc sweep must not exceed 2pi")]
+    fn test_arc_segment_2_new_invalid_sweep() {
+        let support = CircleSegment2::new(Point2::new(0.0, 0.0), 1.0);
+        ArcSegment2::new(support, 0.0, 7.0);
+    }
+
+    #[test]
+    fn test_arc_segment_2_constructors() {
+        let source = Point2::new(1.0, 0.0);
+        let mid = Point2::new(0.0, 1.0);
+        let target = Point2::new(-1.0, 0.0);
+        let upper = ArcSegment2::from_three_points(&source, &mid, &target).unwrap();
+        assert_eq!(upper.center(), Point2::new(0.0, 0.0));
+        assert!(upper.radius().equals(1.0));
+        assert_eq!(upper.orientation(), Orientation::CounterClockwise);
+        assert!(upper.sweep_angle().equals(PI));
+        assert!(upper.bulge().equals(1.0));
+        assert_eq!(upper.mid_point(), mid);
+        let lower = ArcSegment2::from_three_points(&target, &mid, &source).unwrap();
+        assert_eq!(lower, upper.reverse());
+        assert!(lower.bulge().equals(-1.0));
+        assert_eq!(
+            ArcSegment2::from_three_points(&source, &Point2::new(0.0, 0.0), &target).err(),
+            Some(GeometryError::CollinearPoints)
+        );
+        assert_eq!(
+            ArcSegment2::from_three_points(&source, &source, &target).err(),
+            Some(GeometryError::CoincidentPoints)
+        );
+
+        assert_eq!(
+            ArcSegment2::from_bulge(&source, &target, 1.0).unwrap(),
+            upper
+        );
+        let quarter =
+            ArcSegment2::from_bulge(&source, &mid, -(PI / 8.0).sin() / (PI / 8.0).cos()).unwrap();
+        assert_eq!(quarter.center(), Point2::new(1.0, 1.0));
+        assert_eq!(quarter.orientation(), Orientation::Clockwise);
+        assert!(quarter.sweep_angle().equals(-PI / 2.0));
+        let major = ArcSegment2::from_bulge(&source, &mid, 3.0).unwrap();
+        assert!(major.bulge().equals(3.0));
+        assert_eq!(
+            ArcSegment2::from_bulge(&major.source(), &major.target(), major.bulge()).unwrap(),
+            major
+        );
+        assert_eq!(
+            ArcSegment2::from_bulge(&source, &target, 0.0).err(),
+            Some(GeometryError::CollinearPoints)
+        );
+
+        let center = Point2::new(0.0, 0.0);
+        assert_eq!(
+            ArcSegment2::from_center_source_target(
+                &center,
+                &source,
+                &target,
+                Orientation::CounterClockwise
+            )
+            .unwrap(),
+            upper
+        );
+        assert_eq!(
+            ArcSegment2::from_center_source_target(
+                &center,
+                &source,
+                &Point2::new(-2.0, 0.0),
+                Orientation::Clockwise
+            )
+            .err(),
+            Some(GeometryError::InvalidRadius)
+        );
+
+        assert_eq!(
+            ArcSegment2::from_source_tangent_target(&source, &Vector2::new(0.0, 3.0), &target)
+                .unwrap(),
+            upper
+        );
+        assert_eq!(
+            ArcSegment2::from_source_tangent_target(&target, &Vector2::new(0.0, 1.0), &source)
+                .unwrap(),
+            lower
+        );
+        assert_eq!(
+            ArcSegment2::from_source_tangent_target(&source, &Vector2::new(-1.0, 0.0), &target)
+                .err(),
+            Some(GeometryError::CollinearPoints)
+        );
+        assert_eq!(
+            ArcSegment2::from_source_tangent_target(&source, &Vector2::new(0.0, 0.0), &target)
+                .err(),
+            Some(GeometryError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_arc_segment_2_constructors_degenerate() {
+        let source = Point2::new(1.0, 0.0);
+        let target = Point2::new(-1.0, 0.0);
+        let center = Point2::new(0.0, 0.0);
+        assert_eq!(
+            ArcSegment2::from_three_points(&source, &Point2::new(0.0, 1.0), &source).err(),
+            Some(GeometryError::CoincidentPoints)
+        );
+        assert_eq!(
+            ArcSegment2::from_three_points(&source, &Point2::new(0.0, 1e-12), &target).err(),
+            Some(GeometryError::CollinearPoints)
+        );
+        assert_eq!(
+            ArcSegment2::from_bulge(&source, &source, 1.0).err(),
+            Some(GeometryError::CoincidentPoints)
+        );
+        assert_eq!(
+            ArcSegment2::from_center_source_target(
+                &center,
+                &center,
+                &target,
+                Orientation::CounterClockwise
+            )
+            .err(),
+            Some(GeometryError::InvalidRadius)
+        );
+        assert_eq!(
+            ArcSegment2::from_source_tangent_target(&source, &Vector2::new(0.0, 1.0), &source)
+                .err(),
+            Some(GeometryError::CoincidentPoints)
+        );
+
+        let support = CircleSegment2::new(center, 1.0);
+        assert_eq!(
+            ArcSegment2::from_sweep(support, 0.0, 7.0).err(),
+            Some(GeometryError::InvalidSweep)
+        );
+        assert_eq!(
+            ArcSegment2::from_sweep(support, 0.0, -7.0).err(),
+            Some(GeometryError::InvalidSweep)
+        );
+        assert_eq!(
+            ArcSegment2::with_orientation(support, 0.0, 7.0, Orientation::CounterClockwise).err(),
+            Some(GeometryError::InvalidSweep)
+        );
+
+        // Equal radians give a zero-length arc, while a full sweep keeps the whole circle.
+        let point = ArcSegment2::from_center_source_target(
+            &center,
+            &source,
+            &source,
+            Orientation::Clockwise,
+        )
+        .unwrap();
+        assert!(point.is_zero_length());
+        assert!(point.bulge().equals(0.0));
+        assert_eq!(point.mid_point(), source);
+        let full = ArcSegment2::from_sweep(support, 0.0, 2.0 * PI).unwrap();
+        assert!(full.is_full());
+        assert!(full.sweep_angle().equals(2.0 * PI));
+        assert_eq!(full.mid_point(), target);
+
+        // A tangent pointing away from the target turns the other way.
+        let lower =
+            ArcSegment2::from_source_tangent_target(&source, &Vector2::new(0.0, -1.0), &target)
+                .unwrap();
+        assert_eq!(lower.orientation(), Orientation::Clockwise);
+        assert_eq!(lower.mid_point(), Point2::new(0.0, -1.0));
+        let flat = ArcSegment2::from_bulge(&source, &target, 1e-3).unwrap();
+        assert!(flat.radius() > 100.0);
+        assert!(flat.bulge().equals(1e-3));
+    }
+
+    #[test]
+    fn test_arc_segment_2_tessellate() {
+        let support = CircleSegment2::new(Point2::new(1.0, 2.0), 10.0);
+        let arc_segment = ArcSegment2::new(support, 0.25 * PI, 1.25 * PI);
+        let tolerance = ChordTolerance::MaxDeviation(0.1);
+        let inner = arc_segment
+            .tessellate(tolerance, ApproximationSide::Inner)
+            .unwrap();
+        assert_eq!(inner[0], arc_segment.source());
+        assert_eq!(*inner.last().unwrap(), arc_segment.target());
+        for pair in inner.windows(2) {
+            assert!(pair[0].distance(&support.center()).equals(10.0));
+            let mid = Point2::new(
+                (pair[0].x() + pair[1].x()) / 2.0,
+                (pair[0].y() + pair[1].y()) / 2.0,
+            );
+            let distance = mid.distance(&support.center());
+            assert!(distance < 10.0 && distance > 9.9);
+        }
+
+        let outer = arc_segment
+            .tessellate(tolerance, ApproximationSide::Outer)
+            .unwrap();
+        assert_eq!(outer.len(), inner.len() + 1);
+        assert_eq!(outer[0], arc_segment.source());
+        assert_eq!(*outer.last().unwrap(), arc_segment.target());
+        for point in &outer[1..outer.len() - 1] {
+            let distance = point.distance(&support.center());
+            assert!(distance > 10.0 && distance < 10.1);
+        }
+
+        let clockwise = arc_segment.reverse();
+        let points = clockwise
+            .tessellate(
+                ChordTolerance::MaxRadian(0.25 * PI),
+                ApproximationSide::Inner,
+            )
+            .unwrap();
+        assert_eq!(points.len(), 5);
+        assert_eq!(points[2], arc_segment.mid_point());
+        let full = ArcSegment2::new(support, 0.0, 2.0 * PI)
+            .tessellate(ChordTolerance::MaxRadian(7.0), ApproximationSide::Outer)
+            .unwrap();
+        assert_eq!(full.len(), 6);
+        for point in &full[1..full.len() - 1] {
+            assert!(point
+                .distance(&support.center())
+                .equals(10.0 * 2.0_f64.sqrt()));
+        }
+        assert_eq!(
+            ArcSegment2::new(support, PI, PI)
+                .tessellate(tolerance, ApproximationSide::Outer)
+                .unwrap(),
+            vec![Point2::new(-9.0, 2.0)]
+        );
+        assert_eq!(
+            arc_segment
+                .tessellate(ChordTolerance::MaxDeviation(0.0), ApproximationSide::Inner)
+                .err(),
+            Some(GeometryError::InvalidTolerance)
+        );
     }
 }
@@ -0,0 +1,129 @@
+use super::{number_type::NumberType, point_2::Point2};
+
+/// Axis-aligned bounding box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BBox2<T: NumberType> {
+    min: Point2<T>,
+    max: Point2<T>,
+}
+
+impl<T: NumberType> BBox2<T> {
+    pub fn new(a: Point2<T>, b: Point2<T>) -> Self {
+        Self {
+            min: Point2::new(smaller(a.x(), b.x()), smaller(a.y(), b.y())),
+            max: Point2::new(larger(a.x(), b.x()), larger(a.y(), b.y())),
+        }
+    }
+
+    /// Returns None for an empty slice.
+    pub fn from_points(points: &[Point2<T>]) -> Option<Self> {
+        let first = points.first()?;
+        let bbox = Self::new(*first, *first);
+        Some(
+            points
+                .iter()
+                .skip(1)
+                .fold(bbox, |bbox, point| bbox.union(&Self::new(*point, *point))),
+        )
+    }
+
+    pub fn min(&self) -> Point2<T> {
+        self.min
+    }
+
+    pub fn max(&self) -> Point2<T> {
+        self.max
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x() - self.min.x()
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y() - self.min.y()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Point2::new(
+                smaller(self.min.x(), other.min.x()),
+                smaller(self.min.y(), other.min.y()),
+            ),
+            max: Point2::new(
+                larger(self.max.x(), other.max.x()),
+                larger(self.max.y(), other.max.y()),
+            ),
+        }
+    }
+
+    /// Grows the box by `margin` on every side.
+    pub fn expand(&self, margin: T) -> Self {
+        Self {
+            min: Point2::new(self.min.x() - margin, self.min.y() - margin),
+            max: Point2::new(self.max.x() + margin, self.max.y() + margin),
+        }
+    }
+
+    /// Boxes touching at the boundary intersect.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let eps = T::default_eps();
+        self.min.x() <= other.max.x() + eps
+            && other.min.x() <= self.max.x() + eps
+            && self.min.y() <= other.max.y() + eps
+            && other.min.y() <= self.max.y() + eps
+    }
+
+    pub fn contains_point(&self, point: &Point2<T>) -> bool {
+        self.intersects(&Self::new(*point, *point))
+    }
+}
+
+fn smaller<T: NumberType>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn larger<T: NumberType>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bbox_2() {
+        let bbox = BBox2::new(Point2::new(3.0, 1.0), Point2::new(1.0, 4.0));
+        assert_eq!(bbox.min(), Point2::new(1.0, 1.0));
+        assert_eq!(bbox.max(), Point2::new(3.0, 4.0));
+        assert_eq!(bbox.width(), 2.0);
+        assert_eq!(bbox.height(), 3.0);
+        assert!(bbox.contains_point(&Point2::new(3.0, 2.0)));
+        assert!(!bbox.contains_point(&Point2::new(3.5, 2.0)));
+
+        let other = BBox2::from_points(&[
+            Point2::new(3.0, 0.0),
+            Point2::new(5.0, 2.0),
+            Point2::new(4.0, -1.0),
+        ])
+        .unwrap();
+        assert_eq!(
+            other,
+            BBox2::new(Point2::new(3.0, -1.0), Point2::new(5.0, 2.0))
+        );
+        assert!(bbox.intersects(&other));
+        assert!(!bbox.intersects(&other.expand(-0.5)));
+        assert_eq!(
+            bbox.union(&other),
+            BBox2::new(Point2::new(1.0, -1.0), Point2::new(5.0, 4.0))
+        );
+        assert_eq!(BBox2::<f64>::from_points(&[]), None);
+    }
+}
@@ -1,6 +1,14 @@
 use super::{
-    arc_segment_2::ArcSegment2, number_type::NumberType, point_2::Point2, segment_2::Segment2,
-    util_enum::Segment2Type,
+    arc_segment_2::{normalize_radian, ArcSegment2},
+    bbox_2::BBox2,
+    curve_2::{clamp_parameter, Curve2},
+    number_type::NumberType,
+    point_2::Point2,
+    polygon_2::Polygon2,
+    segment_2::Segment2,
+    tessellation_2::{circular_pieces, point_on_circle, ChordTolerance},
+    util_enum::{ApproximationSide, GeometryError, Orientation, Segment2Type},
+    vector_2::Vector2,
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -22,6 +30,32 @@ impl<T: NumberType> CircleSegment2<T> {
         arcs.push(ArcSegment2::new(self.clone(), pi, two_pi));
         arcs
     }
+
+    /// Counter-clockwise polygon vertices starting at radian zero, see [`ApproximationSide`] for `side`.
+    pub fn tessellate(
+        &self,
+        tolerance: ChordTolerance<T>,
+        side: ApproximationSide,
+    ) -> Result<Vec<Point2<T>>, GeometryError> {
+        let two_pi = T::pi() * T::from_f64(2.0);
+        let pieces = circular_pieces(self.radius, two_pi, tolerance, side, 3)?;
+        let step = two_pi / T::from_f64(pieces as f64);
+        let radius = match side {
+            ApproximationSide::Inner => self.radius,
+            ApproximationSide::Outer => self.radius / (step / T::from_f64(2.0)).cos(),
+        };
+        Ok((0..pieces)
+            .map(|i| point_on_circle(&self.center, radius, step * T::from_f64(i as f64)))
+            .collect())
+    }
+
+    pub fn to_polygon(
+        &self,
+        tolerance: ChordTolerance<T>,
+        side: ApproximationSide,
+    ) -> Result<Polygon2<T>, GeometryError> {
+        Ok(Polygon2::new(self.tessellate(tolerance, side)?))
+    }
 }
 
 impl<T: NumberType> Segment2<T> for CircleSegment2<T> {
@@ -29,6 +63,14 @@ impl<T: NumberType> Segment2<T> for CircleSegment2<T> {
         return Segment2Type::CircleSegment2;
     }
 
+    fn sweep_radian(&self) -> T {
+        T::pi() * T::from_f64(2.0)
+    }
+
+    fn orientation(&self) -> Orientation {
+        Orientation::CounterClockwise
+    }
+
     fn center(&self) -> Point2<T> {
         self.center.clone()
     }
@@ -37,3 +79,127 @@ impl<T: NumberType> Segment2<T> for CircleSegment2<T> {
         self.radius
     }
 }
+
+impl<T: NumberType> Curve2<T> for CircleSegment2<T> {
+    type Piece = ArcSegment2<T>;
+
+    fn point_at(&self, t: T) -> Point2<T> {
+        let radian = T::pi() * T::from_f64(2.0) * clamp_parameter(t);
+        Point2::new(
+            self.center.x() + self.radius * radian.cos(),
+            self.center.y() + self.radius * radian.sin(),
+        )
+    }
+
+    fn tangent_at(&self, t: T) -> Vector2<T> {
+        let radian = T::pi() * T::from_f64(2.0) * clamp_parameter(t);
+        Vector2::new(-radian.sin(), radian.cos())
+    }
+
+    fn curvature_at(&self, _t: T) -> T {
+        T::from_f64(1.0) / self.radius
+    }
+
+    fn length(&self) -> T {
+        T::pi() * T::from_f64(2.0) * self.radius
+    }
+
+    fn split_at(&self, t: T) -> (ArcSegment2<T>, ArcSegment2<T>) {
+        let two_pi = T::pi() * T::from_f64(2.0);
+        let radian = two_pi * clamp_parameter(t);
+        (
+            ArcSegment2::new(*self, T::zero(), radian),
+            ArcSegment2::new(*self, radian, two_pi),
+        )
+    }
+
+    fn reverse(&self) -> ArcSegment2<T> {
+        ArcSegment2::from_sweep(*self, T::zero(), -T::pi() * T::from_f64(2.0)).unwrap()
+    }
+
+    fn closest_parameter(&self, point: &Point2<T>) -> T {
+        let vector = *point - self.center;
+        normalize_radian(vector.y().atan2(vector.x())) / (T::pi() * T::from_f64(2.0))
+    }
+
+    fn bbox(&self) -> BBox2<T> {
+        BBox2::new(
+            Point2::new(self.center.x() - self.radius, self.center.y() - self.radius),
+            Point2::new(self.center.x() + self.radius, self.center.y() + self.radius),
+        )
+    }
+}
+
+impl<T: NumberType> PartialEq for CircleSegment2<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.center == other.center && self.radius.equals(other.radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::algorithm::triangulation::earcut_2::earcut_2;
+
+    use super::*;
+
+    #[test]
+    fn test_circle_segment_2_curve() {
+        let circle_segment = CircleSegment2::new(Point2::new(1.0, 1.0), 2.0);
+        assert_eq!(circle_segment.point_at(0.25), Point2::new(1.0, 3.0));
+        assert_eq!(circle_segment.tangent_at(0.25), Vector2::new(-1.0, 0.0));
+        assert_eq!(circle_segment.curvature_at(0.25), 0.5);
+        assert!(circle_segment.length().equals(4.0 * std::f64::consts::PI));
+        assert!(circle_segment
+            .closest_parameter(&Point2::new(1.0, -5.0))
+            .equals(0.75));
+        let (first, second) = circle_segment.split_at(0.25);
+        assert_eq!(first.target(), Point2::new(1.0, 3.0));
+        assert_eq!(second.source(), Point2::new(1.0, 3.0));
+        assert!((first.length() + second.length()).equals(circle_segment.length()));
+        let (first, second) = circle_segment.split_at(1.0);
+        assert!(first.is_full());
+        assert!(second.is_zero_length());
+
+        let reversed = circle_segment.reverse();
+        assert!(reversed.is_full());
+        assert_eq!(reversed.orientation(), Orientation::Clockwise);
+        assert_eq!(reversed.point_at(0.25), circle_segment.point_at(0.75));
+        assert_eq!(reversed.tangent_at(0.25), Vector2::new(-1.0, 0.0));
+        assert_eq!(reversed.curvature_at(0.25), -0.5);
+        assert_eq!(
+            circle_segment.bbox(),
+            BBox2::new(Point2::new(-1.0, -1.0), Point2::new(3.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn test_circle_segment_2_tessellate() {
+        let circle_segment = CircleSegment2::new(Point2::new(0.0, 0.0), 1.0);
+        let tolerance = ChordTolerance::MaxRadian(PI / 2.0);
+        assert_eq!(
+            circle_segment
+                .tessellate(tolerance, ApproximationSide::Inner)
+                .unwrap(),
+            vec![
+                Point2::new(1.0, 0.0),
+                Point2::new(0.0, 1.0),
+                Point2::new(-1.0, 0.0),
+                Point2::new(0.0, -1.0)
+            ]
+        );
+        let outer = circle_segment
+            .to_polygon(tolerance, ApproximationSide::Outer)
+            .unwrap();
+        assert_eq!(outer.vertices()[1], Point2::new(0.0, 2.0_f64.sqrt()));
+        assert!(outer.area().equals(4.0));
+
+        let polygon = circle_segment
+            .to_polygon(ChordTolerance::MaxDeviation(1e-3), ApproximationSide::Inner)
+            .unwrap();
+        let count = polygon.vertices().len();
+        assert!(polygon.area() < PI && polygon.area() > PI - 1e-2);
+        assert_eq!(earcut_2(polygon).len(), count - 2);
+    }
+}
@@ -0,0 +1,425 @@
+use super::{
+    arc_segment_2::ArcSegment2, line_segment_2::LineSegment2, number_type::NumberType,
+    point_2::Point2, segment_2::Segment2, util_enum::GeometryError, vector_2::Vector2,
+};
+
+const GAUSS_NODES: [f64; 5] = [
+    -0.906_179_845_938_664,
+    -0.538_469_310_105_683_1,
+    0.0,
+    0.538_469_310_105_683_1,
+    0.906_179_845_938_664,
+];
+const GAUSS_WEIGHTS: [f64; 5] = [
+    0.236_926_885_056_189_1,
+    0.478_628_670_499_366_5,
+    0.568_888_888_888_888_9,
+    0.478_628_670_499_366_5,
+    0.236_926_885_056_189_1,
+];
+const MAX_PIECE_RADIAN: f64 = 0.25;
+const MIN_LOCATE_PIECES: usize = 16;
+const MAX_ITERATIONS: usize = 64;
+
+/// Euler spiral, the curvature changes linearly along the station `s`:
+/// curvature(s) = source_curvature + sharpness * s, for s in [0, length].
+///
+/// Positive curvature turns left (counter-clockwise).
+#[derive(Debug, Clone, Copy)]
+pub struct Clothoid2<T: NumberType> {
+    source: Point2<T>,
+    source_radian: T,
+    source_curvature: T,
+    sharpness: T,
+    length: T,
+}
+
+impl<T: NumberType> Clothoid2<T> {
+    pub fn new(
+        source: Point2<T>,
+        source_radian: T,
+        source_curvature: T,
+        sharpness: T,
+        length: T,
+    ) -> Result<Self, GeometryError> {
+        if length < T::zero() || length.equals(T::zero()) {
+            return Err(GeometryError::InvalidLength);
+        }
+        Ok(Self {
+            source,
+            source_radian,
+            source_curvature,
+            sharpness,
+            length,
+        })
+    }
+
+    pub fn from_curvatures(
+        source: Point2<T>,
+        source_radian: T,
+        source_curvature: T,
+        target_curvature: T,
+        length: T,
+    ) -> Result<Self, GeometryError> {
+        if length < T::zero() || length.equals(T::zero()) {
+            return Err(GeometryError::InvalidLength);
+        }
+        let sharpness = (target_curvature - source_curvature) / length;
+        Self::new(source, source_radian, source_curvature, sharpness, length)
+    }
+
+    /// Transition spiral from the line (curvature 0) into the support circle of the arc.
+    ///
+    /// The spiral starts on the supporting line of `line_segment`, heading from its source to its
+    /// target, and ends tangent to the circle. The circle must not touch the line.
+    pub fn fit_line_arc(
+        line_segment: &LineSegment2<T>,
+        arc_segment: &ArcSegment2<T>,
+    ) -> Result<Self, GeometryError> {
+        let direction = line_segment.target() - line_segment.source();
+        if direction.length().equals(T::zero()) {
+            return Err(GeometryError::InvalidLength);
+        }
+        let direction = direction.normalize();
+        let to_center = arc_segment.center() - line_segment.source();
+        let height = direction.cross(&to_center);
+        let radius = arc_segment.radius();
+        let shift = height.abs() - radius;
+        if shift < T::zero() || shift.equals(T::zero()) {
+            return Err(GeometryError::NoSolution);
+        }
+
+        let two = T::from_f64(2.0);
+        let shift_of = |length: T| {
+            let spiral = Self::new(
+                Point2::new(T::zero(), T::zero()),
+                T::zero(),
+                T::zero(),
+                T::from_f64(1.0) / (radius * length),
+                length,
+            )
+            .unwrap();
+            let (x, y) = spiral.displacement(length);
+            let radian = length / (two * radius);
+            (
+                x - radius * radian.sin(),
+                y - radius * (T::from_f64(1.0) - radian.cos()),
+            )
+        };
+        let mut low = T::zero();
+        let mut high = two * radius;
+        if shift_of(high).1 < shift {
+            return Err(GeometryError::NoSolution);
+        }
+        for _ in 0..MAX_ITERATIONS {
+            let middle = (low + high) / two;
+            if shift_of(middle).1 < shift {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        let length = (low + high) / two;
+        let (offset, _) = shift_of(length);
+
+        let station = direction.dot(&to_center) - offset;
+        let source = line_segment.source().get_vector() + direction * station;
+        let side = if height > T::zero() {
+            T::from_f64(1.0)
+        } else {
+            T::from_f64(-1.0)
+        };
+        Self::new(
+            Point2::new(source.x(), source.y()),
+            direction.y().atan2(direction.x()),
+            T::zero(),
+            side / (radius * length),
+            length,
+        )
+    }
+
+    pub fn source(&self) -> Point2<T> {
+        self.source
+    }
+
+    pub fn source_radian(&self) -> T {
+        self.source_radian
+    }
+
+    pub fn source_curvature(&self) -> T {
+        self.source_curvature
+    }
+
+    pub fn target(&self) -> Point2<T> {
+        self.point_at(self.length)
+    }
+
+    pub fn target_radian(&self) -> T {
+        self.radian_at(self.length)
+    }
+
+    pub fn target_curvature(&self) -> T {
+        self.curvature_at(self.length)
+    }
+
+    pub fn sharpness(&self) -> T {
+        self.sharpness
+    }
+
+    pub fn length(&self) -> T {
+        self.length
+    }
+
+    pub fn curvature_at(&self, station: T) -> T {
+        self.source_curvature + self.sharpness * self.clamp_station(station)
+    }
+
+    /// Tangent direction at `station`, not normalized to [0, 2pi).
+    pub fn radian_at(&self, station: T) -> T {
+        let station = self.clamp_station(station);
+        self.source_radian
+            + self.source_curvature * station
+            + self.sharpness * station * station / T::from_f64(2.0)
+    }
+
+    pub fn tangent_at(&self, station: T) -> Vector2<T> {
+        let radian = self.radian_at(station);
+        Vector2::new(radian.cos(), radian.sin())
+    }
+
+    /// Evaluates the generalized Fresnel integrals with composite Gauss-Legendre quadrature.
+    pub fn point_at(&self, station: T) -> Point2<T> {
+        let (x, y) = self.displacement(self.clamp_station(station));
+        Point2::new(self.source.x() + x, self.source.y() + y)
+    }
+
+    /// Point at `offset` from the curve, positive offsets lie on the left.
+    pub fn point_at_offset(&self, station: T, offset: T) -> Point2<T> {
+        let point = self.point_at(station);
+        let tangent = self.tangent_at(station);
+        Point2::new(
+            point.x() - tangent.y() * offset,
+            point.y() + tangent.x() * offset,
+        )
+    }
+
+    /// Returns (station, offset) of the closest point on the curve, see `point_at_offset`.
+    pub fn locate_point(&self, point: &Point2<T>) -> (T, T) {
+        let pieces = self.pieces(self.length).max(MIN_LOCATE_PIECES);
+        let stations = self.uniform_stations(pieces);
+        let mut station = stations[0];
+        let mut distance = self.point_at(station).distance(point);
+        for candidate in stations.iter().skip(1) {
+            let candidate_distance = self.point_at(*candidate).distance(point);
+            if candidate_distance < distance {
+                station = *candidate;
+                distance = candidate_distance;
+            }
+        }
+        for _ in 0..MAX_ITERATIONS {
+            let difference = self.point_at(station) - *point;
+            let tangent = self.tangent_at(station);
+            let normal = Vector2::new(-tangent.y(), tangent.x());
+            let value = difference.dot(&tangent);
+            let derivative =
+                T::from_f64(1.0) + self.curvature_at(station) * difference.dot(&normal);
+            if derivative.equals(T::zero()) {
+                break;
+            }
+            let next = self.clamp_station(station - value / derivative);
+            let step = next - station;
+            station = next;
+            if step.equals(T::zero()) {
+                break;
+            }
+        }
+        let tangent = self.tangent_at(station);
+        let offset = tangent.cross(&(*point - self.point_at(station)));
+        (station, offset)
+    }
+
+    /// Stations whose chords deviate at most `max_deviation` from the curve.
+    pub fn stations(&self, max_deviation: T) -> Vec<T> {
+        if max_deviation < T::zero() || max_deviation.equals(T::zero()) {
+            return self.uniform_stations(self.pieces(self.length));
+        }
+        let curvature = max_abs(self.source_curvature, self.target_curvature());
+        if curvature.equals(T::zero()) {
+            return vec![T::zero(), self.length];
+        }
+        let step = (T::from_f64(8.0) * max_deviation / curvature).sqrt();
+        let mut pieces = 1;
+        while step * T::from_f64(pieces as f64) < self.length {
+            pieces += 1;
+        }
+        self.uniform_stations(pieces)
+    }
+
+    pub fn tessellate(&self, max_deviation: T) -> Vec<Point2<T>> {
+        self.stations(max_deviation)
+            .into_iter()
+            .map(|station| self.point_at(station))
+            .collect()
+    }
+
+    fn clamp_station(&self, station: T) -> T {
+        if station < T::zero() {
+            T::zero()
+        } else if station > self.length {
+            self.length
+        } else {
+            station
+        }
+    }
+
+    /// Number of pieces so that the tangent turns at most `MAX_PIECE_RADIAN` within a piece.
+    fn pieces(&self, station: T) -> usize {
+        let curvature = max_abs(
+            self.source_curvature,
+            self.source_curvature + self.sharpness * station,
+        );
+        let turning = curvature * station;
+        let mut pieces = 1;
+        while T::from_f64(MAX_PIECE_RADIAN * pieces as f64) < turning {
+            pieces += 1;
+        }
+        pieces
+    }
+
+    fn uniform_stations(&self, pieces: usize) -> Vec<T> {
+        let step = self.length / T::from_f64(pieces as f64);
+        let mut stations: Vec<T> = (0..pieces)
+            .map(|index| step * T::from_f64(index as f64))
+            .collect();
+        stations.push(self.length);
+        stations
+    }
+
+    fn displacement(&self, station: T) -> (T, T) {
+        let pieces = self.pieces(station);
+        let width = station / T::from_f64(pieces as f64);
+        let half = width / T::from_f64(2.0);
+        let mut x = T::zero();
+        let mut y = T::zero();
+        for piece in 0..pieces {
+            let middle = width * T::from_f64(piece as f64) + half;
+            for (node, weight) in GAUSS_NODES.iter().zip(GAUSS_WEIGHTS.iter()) {
+                let radian = self.radian_at(middle + half * T::from_f64(*node));
+                let weight = half * T::from_f64(*weight);
+                x = x + weight * radian.cos();
+                y = y + weight * radian.sin();
+            }
+        }
+        (x, y)
+    }
+}
+
+fn max_abs<T: NumberType>(a: T, b: T) -> T {
+    if a.abs() > b.abs() {
+        a.abs()
+    } else {
+        b.abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::kernel::circle_segment_2::CircleSegment2;
+
+    use super::*;
+
+    #[test]
+    fn test_clothoid_2_new() {
+        let origin = Point2::new(0.0, 0.0);
+        assert_eq!(
+            Clothoid2::new(origin, 0.0, 0.0, 1.0, 0.0).unwrap_err(),
+            GeometryError::InvalidLength
+        );
+
+        let line = Clothoid2::new(Point2::new(1.0, 1.0), PI / 2.0, 0.0, 0.0, 5.0).unwrap();
+        assert_eq!(line.target(), Point2::new(1.0, 6.0));
+
+        let circle = Clothoid2::new(origin, 0.0, 1.0, 0.0, PI).unwrap();
+        assert_eq!(circle.point_at(PI / 2.0), Point2::new(1.0, 1.0));
+        assert_eq!(circle.target(), Point2::new(0.0, 2.0));
+    }
+
+    #[test]
+    fn test_clothoid_2_point_at() {
+        let spiral = Clothoid2::new(Point2::new(0.0, 0.0), 0.0, 0.0, 1.0, 2.0).unwrap();
+        let scale = PI.sqrt();
+        assert_eq!(
+            spiral.point_at(scale),
+            Point2::new(scale * 0.7798934003768228, scale * 0.4382591473903548)
+        );
+
+        let spiral =
+            Clothoid2::from_curvatures(Point2::new(0.0, 0.0), 0.0, 0.0, 0.1, 10.0).unwrap();
+        assert_eq!(spiral.curvature_at(5.0), 0.05);
+        assert!(spiral.target_radian().equals(0.5));
+        assert!(spiral.target_curvature().equals(0.1));
+    }
+
+    #[test]
+    fn test_clothoid_2_locate_point() {
+        let spiral = Clothoid2::new(Point2::new(1.0, 2.0), 0.3, 0.1, 0.05, 10.0).unwrap();
+        let point = spiral.point_at_offset(3.0, 0.5);
+        let (station, offset) = spiral.locate_point(&point);
+        assert!(station.equals(3.0));
+        assert!(offset.equals(0.5));
+
+        let point = spiral.point_at_offset(7.0, -1.5);
+        let (station, offset) = spiral.locate_point(&point);
+        assert!(station.equals(7.0));
+        assert!(offset.equals(-1.5));
+    }
+
+    #[test]
+    fn test_clothoid_2_fit_line_arc() {
+        let line_segment = LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(100.0, 0.0));
+        let arc_segment =
+            ArcSegment2::new(CircleSegment2::new(Point2::new(50.0, 26.0), 25.0), 0.0, PI);
+        let spiral = Clothoid2::fit_line_arc(&line_segment, &arc_segment).unwrap();
+        assert!(spiral.source().y().equals(0.0));
+        assert!(spiral.source_radian().equals(0.0));
+        assert!(spiral.sharpness() > 0.0);
+        assert!(spiral.target_curvature().equals(1.0 / 25.0));
+        let target = spiral.target();
+        assert!(target.distance(&arc_segment.center()).equals(25.0));
+        assert!(spiral
+            .tangent_at(spiral.length())
+            .dot(&(target - arc_segment.center()))
+            .equals(0.0));
+
+        let arc_segment =
+            ArcSegment2::new(CircleSegment2::new(Point2::new(50.0, -26.0), 25.0), PI, 0.0);
+        let spiral = Clothoid2::fit_line_arc(&line_segment, &arc_segment).unwrap();
+        assert!(spiral.sharpness() < 0.0);
+        assert!(spiral.target().distance(&arc_segment.center()).equals(25.0));
+
+        let arc_segment =
+            ArcSegment2::new(CircleSegment2::new(Point2::new(50.0, 20.0), 25.0), 0.0, PI);
+        assert_eq!(
+            Clothoid2::fit_line_arc(&line_segment, &arc_segment).unwrap_err(),
+            GeometryError::NoSolution
+        );
+    }
+
+    #[test]
+    fn test_clothoid_2_tessellate() {
+        let spiral = Clothoid2::new(Point2::new(0.0, 0.0), 0.0, 0.0, 0.02, 20.0).unwrap();
+        let stations = spiral.stations(1e-3);
+        let points = spiral.tessellate(1e-3);
+        assert_eq!(stations.len(), points.len());
+        assert_eq!(points[0], spiral.source());
+        assert_eq!(points[points.len() - 1], spiral.target());
+        for i in 0..stations.len() - 1 {
+            let middle = spiral.point_at((stations[i] + stations[i + 1]) / 2.0);
+            let chord = points[i + 1] - points[i];
+            let deviation = chord.normalize().cross(&(middle - points[i])).abs();
+            assert!(deviation <= 1e-3);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use super::{bbox_2::BBox2, number_type::NumberType, point_2::Point2, vector_2::Vector2};
+
+/** Curve2 trait
+ *
+ * Parametric curve over t in [0, 1], proportional to the arc length from the source.
+ *
+ * - LineSegment2, ArcSegment2 and CircleSegment2 have implemented this trait, a circle starts at radian 0.
+ *
+ * - Curvature is signed, positive when the curve turns left.
+ *
+ * - Splitting and reversing return pieces, so a circle reverses to a clockwise full arc from
+ *   radian 0, and splitting it at t = 0 or t = 1 gives a zero-length piece besides the full arc.
+ */
+pub trait Curve2<T: NumberType> {
+    type Piece: Curve2<T>;
+
+    fn point_at(&self, t: T) -> Point2<T>;
+
+    /// Unit tangent in the direction of increasing t.
+    fn tangent_at(&self, t: T) -> Vector2<T>;
+
+    fn curvature_at(&self, t: T) -> T;
+
+    fn length(&self) -> T;
+
+    fn split_at(&self, t: T) -> (Self::Piece, Self::Piece);
+
+    fn reverse(&self) -> Self::Piece;
+
+    fn closest_parameter(&self, point: &Point2<T>) -> T;
+
+    fn bbox(&self) -> BBox2<T>;
+}
+
+pub(crate) fn clamp_parameter<T: NumberType>(t: T) -> T {
+    if t < T::zero() {
+        T::zero()
+    } else if t > T::from_f64(1.0) {
+        T::from_f64(1.0)
+    } else {
+        t
+    }
+}
@@ -1,8 +1,8 @@
 use std::{cell::RefCell, rc::Rc};
 
 use super::{
-    arc_segment_2::ArcSegment2, face_2::Face2, line_segment_2::LineSegment2,
-    number_type::NumberType, util_enum::Edge2Type, vertex_2::Vertex2,
+    arc_segment_2::ArcSegment2, face_2::Face2, line_arc_segment_2::LineArcSegment2,
+    line_segment_2::LineSegment2, number_type::NumberType, util_enum::Edge2Type, vertex_2::Vertex2,
 };
 
 #[derive(Debug, Clone)]
@@ -14,6 +14,7 @@ pub struct Edge2<T: NumberType> {
     prev: Option<Rc<RefCell<Self>>>,
     face: Option<Rc<RefCell<Face2<T>>>>,
     edge_type: Edge2Type,
+    arc: Option<ArcSegment2<T>>,
 }
 
 impl<T: NumberType> Edge2<T> {
@@ -26,10 +27,16 @@ impl<T: NumberType> Edge2<T> {
             prev: None,
             face: None,
             edge_type: Edge2Type::Segment,
+            arc: None,
         }
     }
 
-    pub fn new_arc(source: Rc<RefCell<Vertex2<T>>>, target: Rc<RefCell<Vertex2<T>>>) -> Self {
+    /// The arc runs from `source` to `target`.
+    pub fn new_arc(
+        source: Rc<RefCell<Vertex2<T>>>,
+        target: Rc<RefCell<Vertex2<T>>>,
+        arc: ArcSegment2<T>,
+    ) -> Self {
         Self {
             source,
             target,
@@ -38,6 +45,7 @@ impl<T: NumberType> Edge2<T> {
             prev: None,
             face: None,
             edge_type: Edge2Type::Arc,
+            arc: Some(arc),
         }
     }
 
@@ -89,6 +97,14 @@ impl<T: NumberType> Edge2<T> {
         self.face = Some(face.clone())
     }
 
+    /// Drops the twin, next, prev and face links, which otherwise keep each other alive.
+    pub fn clear_links(&mut self) {
+        self.twin = None;
+        self.next = None;
+        self.prev = None;
+        self.face = None;
+    }
+
     pub fn edge_type(&self) -> Edge2Type {
         self.edge_type
     }
@@ -99,8 +115,16 @@ impl<T: NumberType> Edge2<T> {
         LineSegment2::new(source.to_point(), target.to_point())
     }
 
+    /// Panics on segment edges.
     pub fn to_arc(&self) -> ArcSegment2<T> {
-        todo!()
+        self.arc.unwrap()
+    }
+
+    pub fn to_line_arc_segment(&self) -> LineArcSegment2<T> {
+        match self.edge_type {
+            Edge2Type::Segment => self.to_segment().into(),
+            Edge2Type::Arc => self.to_arc().into(),
+        }
     }
 }
 
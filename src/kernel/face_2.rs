@@ -1,20 +1,48 @@
 use std::{cell::RefCell, rc::Rc};
 
-use super::{edge_2::Edge2, number_type::NumberType};
+use super::{edge_2::Edge2, number_type::NumberType, polyline_2::Polyline2};
 
+/// Face bounded by a closed cycle of edges, with the face on the left of each edge.
 #[derive(Debug, Clone)]
 pub struct Face2<T: NumberType> {
     edges: Vec<Rc<RefCell<Edge2<T>>>>,
 }
 
 impl<T: NumberType> Face2<T> {
+    pub fn new(edges: Vec<Rc<RefCell<Edge2<T>>>>) -> Self {
+        Self { edges }
+    }
+
     pub fn edges(&self) -> Vec<Rc<RefCell<Edge2<T>>>> {
         self.edges.clone()
     }
+
+    /// Closed boundary in edge order.
+    pub fn to_polyline(&self) -> Polyline2<T> {
+        Polyline2::from_segments(
+            self.edges
+                .iter()
+                .map(|edge| edge.borrow().to_line_arc_segment())
+                .collect(),
+        )
+        .unwrap()
+    }
 }
 
 impl<T: NumberType> PartialEq for Face2<T> {
-    fn eq(&self, _other: &Self) -> bool {
-        todo!()
+    /// Faces are equal when their boundaries are the same cycle, from any starting edge.
+    fn eq(&self, other: &Self) -> bool {
+        let count = self.edges.len();
+        if count != other.edges.len() {
+            return false;
+        }
+        if count == 0 {
+            return true;
+        }
+        (0..count).any(|offset| {
+            (0..count).all(|index| {
+                *self.edges[index].borrow() == *other.edges[(index + offset) % count].borrow()
+            })
+        })
     }
 }
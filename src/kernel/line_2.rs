@@ -1,6 +1,11 @@
-use super::{number_type::NumberType, point_2::Point2};
+use crate::algorithm::location::location_enum::Point2Line2Location;
 
-/// a*x + b*y + c = 0
+use super::{
+    line_segment_2::LineSegment2, number_type::NumberType, point_2::Point2, ray_2::Ray2,
+    segment_2::Segment2, util_enum::GeometryError, vector_2::Vector2,
+};
+
+/// a*x + b*y + c = 0, directed along (b, -a) so that a*x + b*y + c > 0 on the left.
 #[derive(Debug, Clone, Copy)]
 pub struct Line2<T: NumberType> {
     a: T,
@@ -13,25 +18,30 @@ impl<T: NumberType> Line2<T> {
         Self { a, b, c }
     }
 
-    pub fn from_points(start: &Point2<T>, end: &Point2<T>) -> Self {
-        if start.x().equals(end.x()) {
-            return Self {
-                a: T::from_f64(1.0),
-                b: T::zero(),
-                c: -start.x(),
-            };
-        } else if start.y().equals(end.y()) {
-            return Self {
-                a: T::zero(),
-                b: T::from_f64(1.0),
-                c: -start.y(),
-            };
-        } else {
-            let a = (end.y() - start.y()) / (end.x() - start.x());
-            let b = T::from_f64(-1.0);
-            let c = start.y() - a * start.x();
-            Self { a, b, c }
+    /// Directed from `start` to `end`, coefficients are formed from the cross product without division.
+    pub fn from_points(start: &Point2<T>, end: &Point2<T>) -> Result<Self, GeometryError> {
+        if start == end {
+            return Err(GeometryError::CoincidentPoints);
         }
+        Ok(Self::from_point_direction(start, &(*end - *start)))
+    }
+
+    pub fn from_point_direction(point: &Point2<T>, direction: &Vector2<T>) -> Self {
+        let a = -direction.y();
+        let b = direction.x();
+        Self {
+            a,
+            b,
+            c: -(a * point.x() + b * point.y()),
+        }
+    }
+
+    pub fn from_ray(ray: &Ray2<T>) -> Self {
+        Self::from_point_direction(&ray.origin(), &ray.direction())
+    }
+
+    pub fn from_line_segment(line_segment: &LineSegment2<T>) -> Result<Self, GeometryError> {
+        Self::from_points(&line_segment.source(), &line_segment.target())
     }
 }
 
@@ -47,8 +57,123 @@ impl<T: NumberType> Line2<T> {
     pub fn c(&self) -> T {
         self.c
     }
+
+    /// Unit direction.
+    pub fn direction(&self) -> Vector2<T> {
+        Vector2::new(self.b, -self.a).normalize()
+    }
+
+    /// Point of the line closest to the origin.
+    pub fn point(&self) -> Point2<T> {
+        let squared_norm = self.a * self.a + self.b * self.b;
+        Point2::new(
+            -self.a * self.c / squared_norm,
+            -self.b * self.c / squared_norm,
+        )
+    }
+
+    /// Same line and direction with a unit normal (a, b).
+    pub fn normalize(&self) -> Self {
+        let norm = (self.a * self.a + self.b * self.b).sqrt();
+        if norm.equals(T::zero()) {
+            return *self;
+        }
+        Self::new(self.a / norm, self.b / norm, self.c / norm)
+    }
+
+    /// Positive on the left.
+    pub fn signed_distance(&self, point: &Point2<T>) -> T {
+        let line = self.normalize();
+        line.a * point.x() + line.b * point.y() + line.c
+    }
+
+    pub fn distance(&self, point: &Point2<T>) -> T {
+        self.signed_distance(point).abs()
+    }
+
+    pub fn side_of(&self, point: &Point2<T>) -> Point2Line2Location {
+        let signed_distance = self.signed_distance(point);
+        if signed_distance.equals(T::zero()) {
+            Point2Line2Location::On
+        } else if signed_distance > T::zero() {
+            Point2Line2Location::Left
+        } else {
+            Point2Line2Location::Right
+        }
+    }
+
+    pub fn project(&self, point: &Point2<T>) -> Point2<T> {
+        let line = self.normalize();
+        let distance = line.a * point.x() + line.b * point.y() + line.c;
+        Point2::new(point.x() - line.a * distance, point.y() - line.b * distance)
+    }
+
+    pub fn reflect(&self, point: &Point2<T>) -> Point2<T> {
+        let projection = self.project(point);
+        Point2::new(
+            projection.x() + projection.x() - point.x(),
+            projection.y() + projection.y() - point.y(),
+        )
+    }
+
+    /// Same direction through `point`.
+    pub fn parallel_through(&self, point: &Point2<T>) -> Self {
+        Self::new(self.a, self.b, -(self.a * point.x() + self.b * point.y()))
+    }
+
+    /// Through `point`, directed along the direction turned counterclockwise by a right angle.
+    pub fn perpendicular_through(&self, point: &Point2<T>) -> Self {
+        Self::from_point_direction(point, &Vector2::new(self.a, self.b))
+    }
+
+    /// Bisectors of the angles between the lines, the one directed along the sum of the directions first.
+    ///
+    /// Parallel lines have a single bisector midway between them.
+    pub fn bisectors(&self, other: &Self) -> Vec<Self> {
+        let first = self.normalize();
+        let second = other.normalize();
+        [
+            Self::new(first.a + second.a, first.b + second.b, first.c + second.c),
+            Self::new(first.a - second.a, first.b - second.b, first.c - second.c),
+        ]
+        .into_iter()
+        .filter(|line| !(line.a * line.a + line.b * line.b).equals(T::zero()))
+        .map(|line| line.normalize())
+        .collect()
+    }
+
+    pub fn is_parallel(&self, other: &Self) -> bool {
+        let first = self.normalize();
+        let second = other.normalize();
+        (first.a * second.b - first.b * second.a).equals(T::zero())
+    }
+
+    /// Parallel and directed the same way, which `==` does not check.
+    pub fn same_direction(&self, other: &Self) -> bool {
+        let first = self.normalize();
+        let second = other.normalize();
+        self.is_parallel(other) && first.a * second.a + first.b * second.b > T::zero()
+    }
+
+    pub fn is_perpendicular(&self, other: &Self) -> bool {
+        let first = self.normalize();
+        let second = other.normalize();
+        (first.a * second.a + first.b * second.b).equals(T::zero())
+    }
+
+    /// Ray from [`Line2::point`] along the direction.
+    pub fn to_ray(&self) -> Ray2<T> {
+        Ray2::new(self.point(), self.direction())
+    }
+
+    /// Segment between the projections of `source` and `target`.
+    pub fn to_line_segment(&self, source: &Point2<T>, target: &Point2<T>) -> LineSegment2<T> {
+        LineSegment2::new(self.project(source), self.project(target))
+    }
 }
 
+/// Lines are equal when they cover the same points, whatever their direction; compare with
+/// [`Line2::same_direction`] as well for directed lines.
 impl<T: NumberType> PartialEq for Line2<T> {
     fn eq(&self, other: &Self) -> bool {
         let det = self.a * other.b - self.b * other.a;
@@ -75,14 +200,86 @@ mod tests {
 
         let start = Point2::new(1.0, 2.0);
         let end = Point2::new(3.0, 4.0);
-        let line5 = Line2::from_points(&start, &end);
+        let line5 = Line2::from_points(&start, &end).unwrap();
         let line6 = Line2::new(1.0, -1.0, 1.0);
         assert_eq!(line5, line6);
 
         let start = Point2::new(1.0, 2.0);
         let end = Point2::new(1.0, 4.0);
-        let line7 = Line2::from_points(&start, &end);
+        let line7 = Line2::from_points(&start, &end).unwrap();
         let line8 = Line2::new(1.0, 0.0, -1.0);
         assert_eq!(line7, line8);
+
+        assert_eq!(
+            Line2::from_points(&start, &start).err(),
+            Some(GeometryError::CoincidentPoints)
+        );
+        let line_segment = LineSegment2::new(start, start);
+        assert_eq!(
+            Line2::from_line_segment(&line_segment).err(),
+            Some(GeometryError::CoincidentPoints)
+        );
+    }
+
+    #[test]
+    fn test_line2_operations() {
+        let line = Line2::from_points(&Point2::new(1.0, 1.0), &Point2::new(1.0, 1e9)).unwrap();
+        assert_eq!(line, Line2::new(1.0, 0.0, -1.0));
+        assert_eq!(line.direction(), Vector2::new(0.0, 1.0));
+        assert_eq!(
+            line.side_of(&Point2::new(0.0, 5.0)),
+            Point2Line2Location::Left
+        );
+        assert_eq!(
+            line.side_of(&Point2::new(2.0, 5.0)),
+            Point2Line2Location::Right
+        );
+        assert_eq!(
+            line.side_of(&Point2::new(1.0, -3.0)),
+            Point2Line2Location::On
+        );
+
+        let line = Line2::new(3.0, 4.0, -10.0);
+        assert_eq!(line.normalize(), line);
+        assert_eq!(line.normalize().a(), 0.6);
+        assert_eq!(line.signed_distance(&Point2::new(0.0, 0.0)), -2.0);
+        assert_eq!(line.distance(&Point2::new(0.0, 0.0)), 2.0);
+        assert_eq!(line.point(), Point2::new(1.2, 1.6));
+        assert_eq!(line.project(&Point2::new(0.0, 0.0)), Point2::new(1.2, 1.6));
+        assert_eq!(line.reflect(&Point2::new(0.0, 0.0)), Point2::new(2.4, 3.2));
+
+        let parallel = line.parallel_through(&Point2::new(0.0, 0.0));
+        assert!(line.is_parallel(&parallel));
+        assert_eq!(parallel, Line2::new(3.0, 4.0, 0.0));
+        let perpendicular = line.perpendicular_through(&Point2::new(0.0, 0.0));
+        assert!(line.is_perpendicular(&perpendicular));
+        assert_eq!(perpendicular.direction(), Vector2::new(0.6, 0.8));
+
+        let ray = Ray2::new(Point2::new(0.0, 1.0), Vector2::new(1.0, 1.0));
+        let line = Line2::from_ray(&ray);
+        assert_eq!(line, Line2::new(1.0, -1.0, 1.0));
+        assert_eq!(line.direction(), ray.direction());
+        let ray = line.to_ray();
+        assert_eq!(ray.origin(), Point2::new(-0.5, 0.5));
+        let line_segment = line.to_line_segment(&Point2::new(0.0, 0.0), &Point2::new(2.0, 2.0));
+        assert_eq!(line_segment.source(), Point2::new(-0.5, 0.5));
+        assert_eq!(line_segment.target(), Point2::new(1.5, 2.5));
+        assert_eq!(Line2::from_line_segment(&line_segment).unwrap(), line);
+
+        let x_axis = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(1.0, 0.0)).unwrap();
+        let y_axis = Line2::from_points(&Point2::new(0.0, 0.0), &Point2::new(0.0, 1.0)).unwrap();
+        let bisectors = x_axis.bisectors(&y_axis);
+        assert_eq!(bisectors.len(), 2);
+        assert_eq!(bisectors[0], Line2::new(1.0, -1.0, 0.0));
+        assert_eq!(bisectors[0].direction(), Vector2::new(1.0, 1.0).normalize());
+        assert_eq!(bisectors[1], Line2::new(1.0, 1.0, 0.0));
+        let shifted = Line2::from_points(&Point2::new(0.0, 2.0), &Point2::new(-1.0, 2.0)).unwrap();
+        assert_eq!(x_axis.bisectors(&shifted), vec![Line2::new(0.0, 1.0, -1.0)]);
+
+        let reversed = Line2::from_points(&Point2::new(1.0, 0.0), &Point2::new(0.0, 0.0)).unwrap();
+        assert_eq!(x_axis, reversed);
+        assert!(!x_axis.same_direction(&reversed));
+        assert!(x_axis.same_direction(&Line2::new(0.0, 2.0, -4.0)));
+        assert!(!x_axis.same_direction(&y_axis));
     }
 }
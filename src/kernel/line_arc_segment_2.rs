@@ -0,0 +1,171 @@
+use super::{
+    arc_segment_2::ArcSegment2,
+    bbox_2::BBox2,
+    curve_2::Curve2,
+    line_segment_2::LineSegment2,
+    number_type::NumberType,
+    point_2::Point2,
+    segment_2::Segment2,
+    util_enum::{Orientation, Segment2Type},
+    vector_2::Vector2,
+};
+
+/// A piece of a mixed line/arc path.
+#[derive(Debug, Clone, Copy)]
+pub enum LineArcSegment2<T: NumberType> {
+    LineSegment2(LineSegment2<T>),
+    ArcSegment2(ArcSegment2<T>),
+}
+
+impl<T: NumberType> From<LineSegment2<T>> for LineArcSegment2<T> {
+    fn from(line_segment: LineSegment2<T>) -> Self {
+        LineArcSegment2::LineSegment2(line_segment)
+    }
+}
+
+impl<T: NumberType> From<ArcSegment2<T>> for LineArcSegment2<T> {
+    fn from(arc_segment: ArcSegment2<T>) -> Self {
+        LineArcSegment2::ArcSegment2(arc_segment)
+    }
+}
+
+impl<T: NumberType> Segment2<T> for LineArcSegment2<T> {
+    fn segment_type(&self) -> Segment2Type {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.segment_type(),
+            LineArcSegment2::ArcSegment2(segment) => segment.segment_type(),
+        }
+    }
+
+    fn source(&self) -> Point2<T> {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.source(),
+            LineArcSegment2::ArcSegment2(segment) => segment.source(),
+        }
+    }
+
+    fn source_radian(&self) -> T {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.source_radian(),
+            LineArcSegment2::ArcSegment2(segment) => segment.source_radian(),
+        }
+    }
+
+    fn target(&self) -> Point2<T> {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.target(),
+            LineArcSegment2::ArcSegment2(segment) => segment.target(),
+        }
+    }
+
+    fn target_radian(&self) -> T {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.target_radian(),
+            LineArcSegment2::ArcSegment2(segment) => segment.target_radian(),
+        }
+    }
+
+    fn sweep_radian(&self) -> T {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.sweep_radian(),
+            LineArcSegment2::ArcSegment2(segment) => segment.sweep_radian(),
+        }
+    }
+
+    fn orientation(&self) -> Orientation {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.orientation(),
+            LineArcSegment2::ArcSegment2(segment) => segment.orientation(),
+        }
+    }
+
+    fn center(&self) -> Point2<T> {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.center(),
+            LineArcSegment2::ArcSegment2(segment) => segment.center(),
+        }
+    }
+
+    fn radius(&self) -> T {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.radius(),
+            LineArcSegment2::ArcSegment2(segment) => segment.radius(),
+        }
+    }
+}
+
+impl<T: NumberType> Curve2<T> for LineArcSegment2<T> {
+    type Piece = LineArcSegment2<T>;
+
+    fn point_at(&self, t: T) -> Point2<T> {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.point_at(t),
+            LineArcSegment2::ArcSegment2(segment) => segment.point_at(t),
+        }
+    }
+
+    fn tangent_at(&self, t: T) -> Vector2<T> {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.tangent_at(t),
+            LineArcSegment2::ArcSegment2(segment) => segment.tangent_at(t),
+        }
+    }
+
+    fn curvature_at(&self, t: T) -> T {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.curvature_at(t),
+            LineArcSegment2::ArcSegment2(segment) => segment.curvature_at(t),
+        }
+    }
+
+    fn length(&self) -> T {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.length(),
+            LineArcSegment2::ArcSegment2(segment) => segment.length(),
+        }
+    }
+
+    fn split_at(&self, t: T) -> (Self, Self) {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => {
+                let (first, second) = segment.split_at(t);
+                (first.into(), second.into())
+            }
+            LineArcSegment2::ArcSegment2(segment) => {
+                let (first, second) = segment.split_at(t);
+                (first.into(), second.into())
+            }
+        }
+    }
+
+    fn reverse(&self) -> Self {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.reverse().into(),
+            LineArcSegment2::ArcSegment2(segment) => segment.reverse().into(),
+        }
+    }
+
+    fn closest_parameter(&self, point: &Point2<T>) -> T {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.closest_parameter(point),
+            LineArcSegment2::ArcSegment2(segment) => segment.closest_parameter(point),
+        }
+    }
+
+    fn bbox(&self) -> BBox2<T> {
+        match self {
+            LineArcSegment2::LineSegment2(segment) => segment.bbox(),
+            LineArcSegment2::ArcSegment2(segment) => segment.bbox(),
+        }
+    }
+}
+
+impl<T: NumberType> PartialEq for LineArcSegment2<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (LineArcSegment2::LineSegment2(a), LineArcSegment2::LineSegment2(b)) => a == b,
+            (LineArcSegment2::ArcSegment2(a), LineArcSegment2::ArcSegment2(b)) => a == b,
+            _ => false,
+        }
+    }
+}
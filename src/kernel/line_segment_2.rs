@@ -1,5 +1,11 @@
 use super::{
-    number_type::NumberType, point_2::Point2, segment_2::Segment2, util_enum::Segment2Type,
+    bbox_2::BBox2,
+    curve_2::{clamp_parameter, Curve2},
+    number_type::NumberType,
+    point_2::Point2,
+    segment_2::Segment2,
+    util_enum::Segment2Type,
+    vector_2::Vector2,
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -28,8 +34,90 @@ impl<T: NumberType> Segment2<T> for LineSegment2<T> {
     }
 }
 
+impl<T: NumberType> Curve2<T> for LineSegment2<T> {
+    type Piece = LineSegment2<T>;
+
+    fn point_at(&self, t: T) -> Point2<T> {
+        let point = self.source.get_vector() + (self.target - self.source) * clamp_parameter(t);
+        Point2::new(point.x(), point.y())
+    }
+
+    fn tangent_at(&self, _t: T) -> Vector2<T> {
+        (self.target - self.source).normalize()
+    }
+
+    fn curvature_at(&self, _t: T) -> T {
+        T::zero()
+    }
+
+    fn length(&self) -> T {
+        self.source.distance(&self.target)
+    }
+
+    fn split_at(&self, t: T) -> (Self, Self) {
+        let point = self.point_at(t);
+        (
+            LineSegment2::new(self.source, point),
+            LineSegment2::new(point, self.target),
+        )
+    }
+
+    fn reverse(&self) -> Self {
+        LineSegment2::new(self.target, self.source)
+    }
+
+    fn closest_parameter(&self, point: &Point2<T>) -> T {
+        let direction = self.target - self.source;
+        let length = direction.dot(&direction);
+        if length.equals(T::zero()) {
+            return T::zero();
+        }
+        clamp_parameter(direction.dot(&(*point - self.source)) / length)
+    }
+
+    fn bbox(&self) -> BBox2<T> {
+        BBox2::new(self.source, self.target)
+    }
+}
+
 impl<T: NumberType> PartialEq for LineSegment2<T> {
     fn eq(&self, other: &Self) -> bool {
         self.source == other.source && self.target == other.target
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_segment_2_curve() {
+        let line_segment = LineSegment2::new(Point2::new(1.0, 1.0), Point2::new(4.0, 5.0));
+        assert_eq!(line_segment.length(), 5.0);
+        assert_eq!(line_segment.point_at(0.4), Point2::new(2.2, 2.6));
+        assert_eq!(line_segment.tangent_at(0.4), Vector2::new(0.6, 0.8));
+        assert_eq!(line_segment.curvature_at(0.4), 0.0);
+        assert!(line_segment
+            .closest_parameter(&Point2::new(6.2, -0.4))
+            .equals(0.4));
+        assert_eq!(line_segment.closest_parameter(&Point2::new(9.0, 9.0)), 1.0);
+
+        let (first, second) = line_segment.split_at(0.4);
+        assert_eq!(
+            first,
+            LineSegment2::new(Point2::new(1.0, 1.0), Point2::new(2.2, 2.6))
+        );
+        assert_eq!(
+            second,
+            LineSegment2::new(Point2::new(2.2, 2.6), Point2::new(4.0, 5.0))
+        );
+        assert_eq!(
+            line_segment.reverse(),
+            LineSegment2::new(Point2::new(4.0, 5.0), Point2::new(1.0, 1.0))
+        );
+        assert_eq!(
+            line_segment.bbox(),
+            BBox2::new(Point2::new(1.0, 1.0), Point2::new(4.0, 5.0))
+        );
+    }
+}
@@ -32,6 +32,7 @@ pub trait NumberType:
     fn acos(self) -> Self;
     fn atan(self) -> Self;
     fn atan2(self, other: Self) -> Self;
+    fn floor(self) -> Self;
 }
 
 impl DefaultNumberValueTrait for f32 {
@@ -111,6 +112,10 @@ impl NumberType for f32 {
     fn atan2(self, other: Self) -> Self {
         self.atan2(other)
     }
+
+    fn floor(self) -> Self {
+        self.floor()
+    }
 }
 
 impl NumberType for f64 {
@@ -146,4 +151,8 @@ impl NumberType for f64 {
     fn atan2(self, other: Self) -> Self {
         self.atan2(other)
     }
+
+    fn floor(self) -> Self {
+        self.floor()
+    }
 }
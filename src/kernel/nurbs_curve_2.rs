@@ -0,0 +1,725 @@
+use super::{
+    arc_segment_2::ArcSegment2,
+    circle_segment_2::CircleSegment2,
+    number_type::NumberType,
+    point_2::Point2,
+    segment_2::Segment2,
+    util_enum::{GeometryError, Orientation},
+};
+
+#[derive(Debug, Clone, Copy)]
+struct WeightedPoint2<T: NumberType> {
+    x: T,
+    y: T,
+    w: T,
+}
+
+impl<T: NumberType> WeightedPoint2<T> {
+    fn new(point: &Point2<T>, weight: T) -> Self {
+        Self {
+            x: point.x() * weight,
+            y: point.y() * weight,
+            w: weight,
+        }
+    }
+
+    fn zero() -> Self {
+        Self {
+            x: T::zero(),
+            y: T::zero(),
+            w: T::zero(),
+        }
+    }
+
+    fn scale(&self, factor: T) -> Self {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+            w: self.w * factor,
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            w: self.w + other.w,
+        }
+    }
+
+    /// (1 - alpha) * self + alpha * other
+    fn blend(&self, other: &Self, alpha: T) -> Self {
+        self.scale(T::from_f64(1.0) - alpha)
+            .add(&other.scale(alpha))
+    }
+
+    fn point(&self) -> Point2<T> {
+        Point2::new(self.x / self.w, self.y / self.w)
+    }
+}
+
+/// Clamped NURBS curve, the first and last `degree + 1` knots are equal.
+#[derive(Debug, Clone)]
+pub struct NurbsCurve2<T: NumberType> {
+    degree: usize,
+    control_points: Vec<Point2<T>>,
+    weights: Vec<T>,
+    knots: Vec<T>,
+}
+
+impl<T: NumberType> NurbsCurve2<T> {
+    pub fn new(
+        degree: usize,
+        control_points: Vec<Point2<T>>,
+        weights: Vec<T>,
+        knots: Vec<T>,
+    ) -> Result<Self, GeometryError> {
+        if degree == 0 || control_points.len() <= degree {
+            return Err(GeometryError::InvalidDegree);
+        }
+        if weights.len() != control_points.len() || weights.iter().any(|w| *w <= T::zero()) {
+            return Err(GeometryError::InvalidWeights);
+        }
+        if knots.len() != control_points.len() + degree + 1 {
+            return Err(GeometryError::InvalidKnotVector);
+        }
+        if knots.windows(2).any(|pair| pair[1] < pair[0]) {
+            return Err(GeometryError::InvalidKnotVector);
+        }
+        let start = knots[0];
+        let end = knots[knots.len() - 1];
+        if start.equals(end)
+            || knots[..=degree].iter().any(|k| !k.equals(start))
+            || knots[knots.len() - degree - 1..]
+                .iter()
+                .any(|k| !k.equals(end))
+        {
+            return Err(GeometryError::InvalidKnotVector);
+        }
+        let interior = &knots[degree + 1..knots.len() - degree - 1];
+        for (index, knot) in interior.iter().enumerate() {
+            if knot.equals(start) || knot.equals(end) {
+                return Err(GeometryError::InvalidKnotVector);
+            }
+            let multiplicity = interior[index..]
+                .iter()
+                .take_while(|k| k.equals(*knot))
+                .count();
+            if multiplicity > degree {
+                return Err(GeometryError::InvalidKnotVector);
+            }
+        }
+        Ok(Self {
+            degree,
+            control_points,
+            weights,
+            knots,
+        })
+    }
+
+    pub fn bspline(
+        degree: usize,
+        control_points: Vec<Point2<T>>,
+        knots: Vec<T>,
+    ) -> Result<Self, GeometryError> {
+        let weights = vec![T::from_f64(1.0); control_points.len()];
+        Self::new(degree, control_points, weights, knots)
+    }
+
+    /// Exact rational quadratic representation, parameterized over [0, 1] from source to target.
+    pub fn from_arc_segment(arc_segment: &ArcSegment2<T>) -> Self {
+        let start = arc_segment.source_radian();
+        let sweep = match arc_segment.orientation() {
+            Orientation::CounterClockwise => arc_segment.sweep_radian(),
+            Orientation::Clockwise => -arc_segment.sweep_radian(),
+        };
+        Self::from_circular_arc(&arc_segment.center(), arc_segment.radius(), start, sweep)
+    }
+
+    pub fn from_circle_segment(circle_segment: &CircleSegment2<T>) -> Self {
+        Self::from_circular_arc(
+            &circle_segment.center(),
+            circle_segment.radius(),
+            T::zero(),
+            T::pi() * T::from_f64(2.0),
+        )
+    }
+
+    fn from_circular_arc(center: &Point2<T>, radius: T, start: T, sweep: T) -> Self {
+        let half_pi = T::pi() / T::from_f64(2.0);
+        let mut arcs = 1;
+        while arcs < 4 && sweep.abs() > half_pi * T::from_f64(arcs as f64) + T::default_eps() {
+            arcs += 1;
+        }
+        let step = sweep / T::from_f64(arcs as f64);
+        let half_step = step / T::from_f64(2.0);
+        let middle_weight = half_step.cos();
+        let point_at_radian = |radian: T, distance: T| {
+            Point2::new(
+                center.x() + distance * radian.cos(),
+                center.y() + distance * radian.sin(),
+            )
+        };
+        let mut control_points = vec![point_at_radian(start, radius)];
+        let mut weights = vec![T::from_f64(1.0)];
+        let mut knots = vec![T::zero(); 3];
+        for i in 0..arcs {
+            let radian = start + step * T::from_f64(i as f64);
+            control_points.push(point_at_radian(radian + half_step, radius / middle_weight));
+            weights.push(middle_weight);
+            control_points.push(point_at_radian(radian + step, radius));
+            weights.push(T::from_f64(1.0));
+            if i + 1 < arcs {
+                let knot = T::from_f64((i + 1) as f64 / arcs as f64);
+                knots.push(knot);
+                knots.push(knot);
+            }
+        }
+        knots.extend([T::from_f64(1.0); 3]);
+        Self {
+            degree: 2,
+            control_points,
+            weights,
+            knots,
+        }
+    }
+
+    fn from_weighted_points(
+        degree: usize,
+        weighted_points: &[WeightedPoint2<T>],
+        knots: Vec<T>,
+    ) -> Self {
+        Self {
+            degree,
+            control_points: weighted_points.iter().map(|p| p.point()).collect(),
+            weights: weighted_points.iter().map(|p| p.w).collect(),
+            knots,
+        }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn control_points(&self) -> Vec<Point2<T>> {
+        self.control_points.clone()
+    }
+
+    pub fn weights(&self) -> Vec<T> {
+        self.weights.clone()
+    }
+
+    pub fn knots(&self) -> Vec<T> {
+        self.knots.clone()
+    }
+
+    pub fn domain(&self) -> (T, T) {
+        (self.knots[0], self.knots[self.knots.len() - 1])
+    }
+
+    pub fn source(&self) -> Point2<T> {
+        self.control_points[0]
+    }
+
+    pub fn target(&self) -> Point2<T> {
+        self.control_points[self.control_points.len() - 1]
+    }
+
+    /// Lower left and upper right corners of the control polygon, which contains the curve.
+    pub fn control_bounds(&self) -> (Point2<T>, Point2<T>) {
+        let mut min = self.control_points[0];
+        let mut max = self.control_points[0];
+        for point in &self.control_points {
+            if point.x() < min.x() {
+                min = Point2::new(point.x(), min.y());
+            }
+            if point.y() < min.y() {
+                min = Point2::new(min.x(), point.y());
+            }
+            if point.x() > max.x() {
+                max = Point2::new(point.x(), max.y());
+            }
+            if point.y() > max.y() {
+                max = Point2::new(max.x(), point.y());
+            }
+        }
+        (min, max)
+    }
+
+    /// Whether every control point lies within `tolerance` of the chord from source to target.
+    pub fn is_flat(&self, tolerance: T) -> bool {
+        let source = self.source();
+        let chord = self.target() - source;
+        let length = chord.length();
+        self.control_points.iter().all(|point| {
+            let distance = if length.equals(T::zero()) {
+                point.distance(&source)
+            } else {
+                chord.cross(&(*point - source)).abs() / length
+            };
+            distance <= tolerance
+        })
+    }
+
+    fn weighted_points(&self) -> Vec<WeightedPoint2<T>> {
+        self.control_points
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(point, weight)| WeightedPoint2::new(point, *weight))
+            .collect()
+    }
+
+    fn find_span(&self, u: T) -> usize {
+        let n = self.control_points.len() - 1;
+        if u >= self.knots[n + 1] {
+            return n;
+        }
+        if u <= self.knots[self.degree] {
+            return self.degree;
+        }
+        let mut low = self.degree;
+        let mut high = n + 1;
+        let mut mid = (low + high) / 2;
+        while u < self.knots[mid] || u >= self.knots[mid + 1] {
+            if u < self.knots[mid] {
+                high = mid;
+            } else {
+                low = mid;
+            }
+            mid = (low + high) / 2;
+        }
+        mid
+    }
+
+    fn snap_to_knot(&self, u: T) -> T {
+        match self.knots.iter().find(|knot| knot.equals(u)) {
+            Some(knot) => *knot,
+            None => u,
+        }
+    }
+
+    fn multiplicity(&self, u: T) -> usize {
+        self.knots.iter().filter(|knot| knot.equals(u)).count()
+    }
+
+    /// de Boor evaluation, `u` is clamped to the domain.
+    pub fn point_at(&self, u: T) -> Point2<T> {
+        let (start, end) = self.domain();
+        let u = if u < start {
+            start
+        } else if u > end {
+            end
+        } else {
+            u
+        };
+        let p = self.degree;
+        let span = self.find_span(u);
+        let weighted_points = self.weighted_points();
+        let mut d: Vec<WeightedPoint2<T>> =
+            (0..=p).map(|j| weighted_points[span - p + j]).collect();
+        for r in 1..=p {
+            for j in (r..=p).rev() {
+                let left = self.knots[j + span - p];
+                let right = self.knots[j + 1 + span - r];
+                let alpha = (u - left) / (right - left);
+                d[j] = d[j - 1].blend(&d[j], alpha);
+            }
+        }
+        d[p].point()
+    }
+
+    /// Boehm knot insertion, the multiplicity of `u` is capped at the degree.
+    pub fn insert_knot(&self, u: T, times: usize) -> Result<Self, GeometryError> {
+        let (start, end) = self.domain();
+        if u < start || u > end {
+            return Err(GeometryError::ParameterOutOfRange);
+        }
+        let u = self.snap_to_knot(u);
+        let p = self.degree;
+        let mut knots = self.knots.clone();
+        let mut weighted_points = self.weighted_points();
+        let mut multiplicity = self.multiplicity(u);
+        let mut inserted = 0;
+        while inserted < times && multiplicity < p {
+            let curve = Self::from_weighted_points(p, &weighted_points, knots.clone());
+            let span = curve.find_span(u);
+            let n = weighted_points.len() - 1;
+            let mut new_points = vec![WeightedPoint2::zero(); n + 2];
+            new_points[..=span - p].copy_from_slice(&weighted_points[..=span - p]);
+            new_points[span - multiplicity + 1..=n + 1]
+                .copy_from_slice(&weighted_points[span - multiplicity..=n]);
+            for i in span - p + 1..=span - multiplicity {
+                let alpha = (u - knots[i]) / (knots[i + p] - knots[i]);
+                new_points[i] = weighted_points[i - 1].blend(&weighted_points[i], alpha);
+            }
+            knots.insert(span + 1, u);
+            weighted_points = new_points;
+            multiplicity += 1;
+            inserted += 1;
+        }
+        Ok(Self::from_weighted_points(p, &weighted_points, knots))
+    }
+
+    pub fn split_at(&self, u: T) -> Result<(Self, Self), GeometryError> {
+        let (start, end) = self.domain();
+        if u < start || u > end || u.equals(start) || u.equals(end) {
+            return Err(GeometryError::ParameterOutOfRange);
+        }
+        let u = self.snap_to_knot(u);
+        let p = self.degree;
+        let refined = self.insert_knot(u, p)?;
+        let first = refined.knots.iter().position(|knot| *knot == u).unwrap();
+        let weighted_points = refined.weighted_points();
+        let mut left_knots = refined.knots[..first + p].to_vec();
+        left_knots.push(u);
+        let mut right_knots = vec![u];
+        right_knots.extend_from_slice(&refined.knots[first..]);
+        Ok((
+            Self::from_weighted_points(p, &weighted_points[..first], left_knots),
+            Self::from_weighted_points(p, &weighted_points[first - 1..], right_knots),
+        ))
+    }
+
+    /// Decomposes the curve into rational Bezier pieces, one per non-empty knot span.
+    pub fn bezier_segments(&self) -> Vec<Self> {
+        let (start, end) = self.domain();
+        let mut interior: Vec<T> = Vec::new();
+        for knot in &self.knots {
+            if knot.equals(start) || knot.equals(end) {
+                continue;
+            }
+            if interior.last().is_none_or(|last| !last.equals(*knot)) {
+                interior.push(*knot);
+            }
+        }
+        let mut segments = Vec::new();
+        let mut rest = self.clone();
+        for knot in interior {
+            let (left, right) = rest.split_at(knot).unwrap();
+            segments.push(left);
+            rest = right;
+        }
+        segments.push(rest);
+        segments
+    }
+
+    /// Degree elevation by `times`, the NURBS Book algorithm A5.9.
+    pub fn elevate_degree(&self, times: usize) -> Self {
+        if times == 0 {
+            return self.clone();
+        }
+        let one = T::from_f64(1.0);
+        let t = times;
+        let p = self.degree;
+        let n = self.control_points.len() - 1;
+        let m = n + p + 1;
+        let ph = p + t;
+        let ph2 = ph / 2;
+        let knots = &self.knots;
+        let weighted_points = self.weighted_points();
+
+        let mut bezalfs = vec![vec![T::zero(); p + 1]; ph + 1];
+        bezalfs[0][0] = one;
+        bezalfs[ph][p] = one;
+        for (i, row) in bezalfs.iter_mut().enumerate().take(ph2 + 1).skip(1) {
+            let inverse = one / binomial::<T>(ph, i);
+            for (j, value) in row
+                .iter_mut()
+                .enumerate()
+                .take(p.min(i) + 1)
+                .skip(i.saturating_sub(t))
+            {
+                *value = inverse * binomial::<T>(p, j) * binomial::<T>(t, i - j);
+            }
+        }
+        for i in ph2 + 1..ph {
+            for j in i.saturating_sub(t)..=p.min(i) {
+                bezalfs[i][j] = bezalfs[ph - i][p - j];
+            }
+        }
+
+        let capacity = n + 1 + t * (m + 1);
+        let mut new_points = vec![WeightedPoint2::zero(); capacity];
+        let mut new_knots = vec![T::zero(); capacity + ph + 1];
+        let mut bpts = weighted_points[..=p].to_vec();
+        let mut next_bpts = vec![WeightedPoint2::zero(); p];
+        let mut ebpts = vec![WeightedPoint2::zero(); ph + 1];
+        let mut alfs = vec![T::zero(); p];
+
+        let mut mh = ph;
+        let mut kind = ph + 1;
+        let mut r: isize = -1;
+        let mut a = p;
+        let mut b = p + 1;
+        let mut cind = 1;
+        let mut ua = knots[0];
+        new_points[0] = weighted_points[0];
+        for knot in new_knots.iter_mut().take(ph + 1) {
+            *knot = ua;
+        }
+        while b < m {
+            let i = b;
+            while b < m && knots[b].equals(knots[b + 1]) {
+                b += 1;
+            }
+            let mul = b - i + 1;
+            mh += mul + t;
+            let ub = knots[b];
+            let oldr = r;
+            r = p as isize - mul as isize;
+            let lbz = if oldr > 0 {
+                (oldr as usize + 1).div_ceil(2)
+            } else {
+                1
+            };
+            let rbz = if r > 0 {
+                ph - (r as usize).div_ceil(2)
+            } else {
+                ph
+            };
+            if r > 0 {
+                let numer = ub - ua;
+                for k in (mul + 1..=p).rev() {
+                    alfs[k - mul - 1] = numer / (knots[a + k] - ua);
+                }
+                for j in 1..=r as usize {
+                    let save = r as usize - j;
+                    let s = mul + j;
+                    for k in (s..=p).rev() {
+                        bpts[k] = bpts[k - 1].blend(&bpts[k], alfs[k - s]);
+                    }
+                    next_bpts[save] = bpts[p];
+                }
+            }
+            for (i, ebpt) in ebpts.iter_mut().enumerate().skip(lbz) {
+                *ebpt = WeightedPoint2::zero();
+                for j in i.saturating_sub(t)..=p.min(i) {
+                    *ebpt = ebpt.add(&bpts[j].scale(bezalfs[i][j]));
+                }
+            }
+            if oldr > 1 {
+                let den = ub - ua;
+                let bet = (ub - new_knots[kind - 1]) / den;
+                for tr in 1..oldr {
+                    let mut i = kind as isize - 1 - tr;
+                    let mut j = kind as isize - 1 + tr;
+                    let mut kj = j - kind as isize + 1;
+                    while j - i > tr {
+                        if i < cind as isize {
+                            let iu = i as usize;
+                            let alf = (ub - new_knots[iu]) / (ua - new_knots[iu]);
+                            new_points[iu] = new_points[iu - 1].blend(&new_points[iu], alf);
+                        }
+                        if j >= lbz as isize {
+                            let ku = kj as usize;
+                            if j - tr <= kind as isize - ph as isize + oldr {
+                                let gam = (ub - new_knots[(j - tr) as usize]) / den;
+                                ebpts[ku] = ebpts[ku + 1].blend(&ebpts[ku], gam);
+                            } else {
+                                ebpts[ku] = ebpts[ku + 1].blend(&ebpts[ku], bet);
+                            }
+                        }
+                        i += 1;
+                        j -= 1;
+                        kj -= 1;
+                    }
+                }
+            }
+            if a != p {
+                for _ in 0..(ph as isize - oldr) {
+                    new_knots[kind] = ua;
+                    kind += 1;
+                }
+            }
+            for ebpt in ebpts.iter().take(rbz + 1).skip(lbz) {
+                new_points[cind] = *ebpt;
+                cind += 1;
+            }
+            if b < m {
+                let r = r.max(0) as usize;
+                bpts[..r].copy_from_slice(&next_bpts[..r]);
+                for j in r..=p {
+                    bpts[j] = weighted_points[b - p + j];
+                }
+                a = b;
+                b += 1;
+                ua = ub;
+            } else {
+                for i in 0..=ph {
+                    new_knots[kind + i] = ub;
+                }
+            }
+        }
+        let nh = mh - ph - 1;
+        new_points.truncate(nh + 1);
+        new_knots.truncate(mh + 1);
+        Self::from_weighted_points(ph, &new_points, new_knots)
+    }
+}
+
+fn binomial<T: NumberType>(n: usize, k: usize) -> T {
+    let mut result = 1.0;
+    for i in 0..k {
+        result = result * (n - i) as f64 / (i + 1) as f64;
+    }
+    T::from_f64(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::kernel::curve_2::Curve2;
+
+    use super::*;
+
+    fn assert_same_curve(a: &NurbsCurve2<f64>, b: &NurbsCurve2<f64>) {
+        for i in 0..=50 {
+            let u = i as f64 / 50.0;
+            assert_eq!(a.point_at(u), b.point_at(u));
+        }
+    }
+
+    #[test]
+    fn test_nurbs_curve_2_new() {
+        let points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(2.0, 0.0),
+        ];
+        let knots = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        assert!(NurbsCurve2::bspline(2, points.clone(), knots.clone()).is_ok());
+        assert_eq!(
+            NurbsCurve2::bspline(3, points.clone(), knots.clone()).err(),
+            Some(GeometryError::InvalidDegree)
+        );
+        assert_eq!(
+            NurbsCurve2::new(2, points.clone(), vec![1.0, -1.0, 1.0], knots.clone()).err(),
+            Some(GeometryError::InvalidWeights)
+        );
+        assert_eq!(
+            NurbsCurve2::bspline(2, points.clone(), vec![0.0, 0.0, 1.0, 0.5, 1.0, 1.0]).err(),
+            Some(GeometryError::InvalidKnotVector)
+        );
+        assert_eq!(
+            NurbsCurve2::bspline(2, points, vec![0.0, 0.0, 0.5, 1.0, 1.0, 1.0]).err(),
+            Some(GeometryError::InvalidKnotVector)
+        );
+    }
+
+    #[test]
+    fn test_nurbs_curve_2_point_at() {
+        let curve = NurbsCurve2::bspline(
+            2,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 2.0),
+                Point2::new(2.0, 0.0),
+            ],
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        assert_eq!(curve.point_at(0.0), Point2::new(0.0, 0.0));
+        assert_eq!(curve.point_at(0.5), Point2::new(1.0, 1.0));
+        assert_eq!(curve.point_at(1.0), Point2::new(2.0, 0.0));
+
+        let circle =
+            NurbsCurve2::from_circle_segment(&CircleSegment2::new(Point2::new(1.0, 2.0), 3.0));
+        assert_eq!(circle.control_points().len(), 9);
+        for i in 0..=100 {
+            let point = circle.point_at(i as f64 / 100.0);
+            assert!(point.distance(&Point2::new(1.0, 2.0)).equals(3.0));
+        }
+    }
+
+    #[test]
+    fn test_nurbs_curve_2_from_arc_segment() {
+        let arc = ArcSegment2::new(
+            CircleSegment2::new(Point2::new(0.0, 0.0), 2.0),
+            PI / 4.0,
+            PI * 1.5,
+        );
+        let curve = NurbsCurve2::from_arc_segment(&arc);
+        assert_eq!(curve.source(), arc.source());
+        assert_eq!(curve.target(), arc.target());
+        assert_eq!(
+            curve.point_at(0.5),
+            Point2::new(2.0 * (PI * 0.875).cos(), 2.0 * (PI * 0.875).sin())
+        );
+        for i in 0..=100 {
+            let point = curve.point_at(i as f64 / 100.0);
+            assert!(point.distance(&Point2::new(0.0, 0.0)).equals(2.0));
+        }
+
+        let curve = NurbsCurve2::from_arc_segment(&arc.reverse());
+        assert_eq!(curve.source(), arc.target());
+        assert_eq!(curve.target(), arc.source());
+        assert_eq!(
+            curve.point_at(0.5),
+            Point2::new(2.0 * (PI * 0.875).cos(), 2.0 * (PI * 0.875).sin())
+        );
+    }
+
+    #[test]
+    fn test_nurbs_curve_2_insert_knot() {
+        let circle =
+            NurbsCurve2::from_circle_segment(&CircleSegment2::new(Point2::new(0.0, 0.0), 1.0));
+        let refined = circle.insert_knot(0.3, 1).unwrap();
+        assert_eq!(refined.control_points().len(), 10);
+        assert_same_curve(&circle, &refined);
+
+        let refined = circle.insert_knot(0.5, 3).unwrap();
+        assert_eq!(refined.control_points().len(), 9);
+        assert_eq!(
+            circle.insert_knot(2.0, 1).err(),
+            Some(GeometryError::ParameterOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_nurbs_curve_2_split_at() {
+        let circle =
+            NurbsCurve2::from_circle_segment(&CircleSegment2::new(Point2::new(0.0, 0.0), 1.0));
+        let (left, right) = circle.split_at(0.4).unwrap();
+        assert_eq!(left.domain(), (0.0, 0.4));
+        assert_eq!(right.domain(), (0.4, 1.0));
+        assert_eq!(left.target(), circle.point_at(0.4));
+        assert_eq!(right.source(), circle.point_at(0.4));
+        for i in 0..=20 {
+            let u = 0.4 * i as f64 / 20.0;
+            assert_eq!(left.point_at(u), circle.point_at(u));
+            let u = 0.4 + 0.6 * i as f64 / 20.0;
+            assert_eq!(right.point_at(u), circle.point_at(u));
+        }
+        assert_eq!(circle.bezier_segments().len(), 4);
+        assert!(circle.split_at(1.0).is_err());
+    }
+
+    #[test]
+    fn test_nurbs_curve_2_elevate_degree() {
+        let circle =
+            NurbsCurve2::from_circle_segment(&CircleSegment2::new(Point2::new(0.0, 0.0), 1.0));
+        let elevated = circle.elevate_degree(1);
+        assert_eq!(elevated.degree(), 3);
+        assert_eq!(elevated.control_points().len(), 13);
+        assert_same_curve(&circle, &elevated);
+
+        let curve = NurbsCurve2::bspline(
+            3,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 3.0),
+                Point2::new(2.0, -1.0),
+                Point2::new(4.0, 2.0),
+                Point2::new(5.0, 0.0),
+                Point2::new(6.0, 1.0),
+            ],
+            vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.6, 1.0, 1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let elevated = curve.elevate_degree(2);
+        assert_eq!(elevated.degree(), 5);
+        assert_eq!(elevated.control_points().len(), 12);
+        assert_same_curve(&curve, &elevated);
+    }
+}
@@ -1,8 +1,12 @@
 use crate::algorithm::intersection::sweep_segment_2_intersection::SweepSegment2Intersection;
 
 use super::{
-    line_segment_2::LineSegment2, number_type::NumberType, point_2::Point2, triangle_2::Triangle2,
-    util_enum::TurnDirection,
+    line_segment_2::LineSegment2,
+    number_type::NumberType,
+    point_2::Point2,
+    polyline_2::{Corner2, Polyline2},
+    triangle_2::Triangle2,
+    util_enum::{CornerLimit, GeometryError, TurnDirection},
 };
 
 pub struct Polygon2<T: NumberType> {
@@ -82,6 +86,32 @@ impl<T: NumberType> Polygon2<T> {
         }
         true
     }
+
+    /// Closed polyline along the edges, starting at the first vertex.
+    pub fn to_polyline(&self) -> Polyline2<T> {
+        let mut vertices = self.vertices.clone();
+        if let Some(first) = self.vertices.first() {
+            vertices.push(*first);
+        }
+        Polyline2::new(vertices)
+    }
+
+    pub fn fillet(&self, radius: T, limit: CornerLimit) -> Result<Polyline2<T>, GeometryError> {
+        self.to_polyline().fillet(radius, limit)
+    }
+
+    pub fn chamfer(&self, distance: T, limit: CornerLimit) -> Result<Polyline2<T>, GeometryError> {
+        self.to_polyline().chamfer(distance, limit)
+    }
+
+    /// One treatment per vertex, see [`Polyline2::apply_corners`].
+    pub fn apply_corners(
+        &self,
+        corners: &[Corner2<T>],
+        limit: CornerLimit,
+    ) -> Result<Polyline2<T>, GeometryError> {
+        self.to_polyline().apply_corners(corners, limit)
+    }
 }
 
 #[cfg(test)]
@@ -115,4 +145,46 @@ mod tests {
         assert_eq!(polygon.is_simple(), true);
         assert_eq!(polygon.is_convex(), true);
     }
+
+    #[test]
+    fn test_polygon_2_corners() {
+        let polygon = Polygon2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 10.0),
+            Point2::new(0.0, 10.0),
+        ]);
+        let filleted = polygon.fillet(2.0, CornerLimit::Reject).unwrap();
+        assert!(filleted.is_closed());
+        assert_eq!(filleted.segments().len(), 8);
+        assert_eq!(filleted.vertices()[0], Point2::new(2.0, 0.0));
+        assert!(filleted.length().equals(24.0 + 4.0 * std::f64::consts::PI));
+
+        let chamfered = polygon.chamfer(1.0, CornerLimit::Reject).unwrap();
+        assert!(chamfered.length().equals(32.0 + 4.0 * 2.0_f64.sqrt()));
+
+        assert_eq!(
+            polygon.fillet(6.0, CornerLimit::Reject).err(),
+            Some(GeometryError::CornerTooLarge)
+        );
+        let circle = polygon.fillet(6.0, CornerLimit::Clamp).unwrap();
+        assert!(circle.is_closed());
+        assert_eq!(circle.segments().len(), 4);
+        assert!(circle.length().equals(10.0 * std::f64::consts::PI));
+
+        let mixed = polygon
+            .apply_corners(
+                &[
+                    Corner2::Fillet(2.0),
+                    Corner2::Sharp,
+                    Corner2::Chamfer(1.0),
+                    Corner2::Sharp,
+                ],
+                CornerLimit::Reject,
+            )
+            .unwrap();
+        assert_eq!(mixed.segments().len(), 6);
+        assert_eq!(mixed.vertices()[0], Point2::new(2.0, 0.0));
+        assert_eq!(mixed.vertices()[6], Point2::new(2.0, 0.0));
+    }
 }
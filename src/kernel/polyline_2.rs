@@ -0,0 +1,840 @@
+use crate::algorithm::intersection::sweep_segment_2_intersection::SweepSegment2Intersection;
+
+use super::{
+    arc_segment_2::ArcSegment2,
+    curve_2::Curve2,
+    line_arc_segment_2::LineArcSegment2,
+    line_segment_2::LineSegment2,
+    number_type::NumberType,
+    point_2::Point2,
+    polygon_2::Polygon2,
+    segment_2::Segment2,
+    tessellation_2::ChordTolerance,
+    util_enum::{ApproximationSide, CornerLimit, GeometryError, Orientation},
+    vector_2::Vector2,
+};
+
+/// Treatment of one corner by [`Polyline2::apply_corners`].
+///
+/// Fillets carry their radius and chamfers their distance from the corner along both segments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Corner2<T: NumberType> {
+    Sharp,
+    Fillet(T),
+    Chamfer(T),
+}
+
+/// Open or closed path of connected line and arc pieces.
+///
+/// Distances along the path are called stations, offsets are positive on the left.
+#[derive(Debug, Clone)]
+pub struct Polyline2<T: NumberType> {
+    segments: Vec<LineArcSegment2<T>>,
+}
+
+impl<T: NumberType> Polyline2<T> {
+    pub fn new(vertices: Vec<Point2<T>>) -> Self {
+        let segments = vertices
+            .windows(2)
+            .map(|pair| LineArcSegment2::LineSegment2(LineSegment2::new(pair[0], pair[1])))
+            .collect();
+        Self { segments }
+    }
+
+    pub fn from_segments(segments: Vec<LineArcSegment2<T>>) -> Result<Self, GeometryError> {
+        if segments
+            .windows(2)
+            .any(|pair| pair[0].target() != pair[1].source())
+        {
+            return Err(GeometryError::NotConnected);
+        }
+        Ok(Self { segments })
+    }
+
+    pub fn segments(&self) -> Vec<LineArcSegment2<T>> {
+        self.segments.clone()
+    }
+
+    pub fn vertices(&self) -> Vec<Point2<T>> {
+        let mut vertices: Vec<Point2<T>> = self
+            .segments
+            .iter()
+            .map(|segment| segment.source())
+            .collect();
+        if let Some(segment) = self.segments.last() {
+            vertices.push(segment.target());
+        }
+        vertices
+    }
+
+    pub fn is_closed(&self) -> bool {
+        match (self.segments.first(), self.segments.last()) {
+            (Some(first), Some(last)) => first.source() == last.target(),
+            _ => false,
+        }
+    }
+
+    pub fn length(&self) -> T {
+        self.segments
+            .iter()
+            .fold(T::zero(), |length, segment| length + segment.length())
+    }
+
+    pub fn point_at_distance(&self, distance: T) -> Result<Point2<T>, GeometryError> {
+        let length = self.length();
+        if self.segments.is_empty() || !is_in_range(distance, length) {
+            return Err(GeometryError::ParameterOutOfRange);
+        }
+        let mut start = T::zero();
+        for segment in &self.segments {
+            let segment_length = segment.length();
+            if distance < start + segment_length || (distance - start).equals(segment_length) {
+                return Ok(segment.point_at(parameter_of(segment, distance - start)));
+            }
+            start = start + segment_length;
+        }
+        Ok(self.segments[self.segments.len() - 1].target())
+    }
+
+    /// Returns (station, offset) of the closest point on the polyline.
+    pub fn locate_point(&self, point: &Point2<T>) -> Result<(T, T), GeometryError> {
+        if self.segments.is_empty() {
+            return Err(GeometryError::InvalidLength);
+        }
+        let mut start = T::zero();
+        let mut best: Option<(T, T, T)> = None;
+        for segment in &self.segments {
+            let t = segment.closest_parameter(point);
+            let closest = segment.point_at(t);
+            let distance = closest.distance(point);
+            if best.is_none_or(|(_, _, best_distance)| distance < best_distance) {
+                let offset = segment.tangent_at(t).cross(&(*point - closest));
+                best = Some((start + t * segment.length(), offset, distance));
+            }
+            start = start + segment.length();
+        }
+        let (station, offset, _) = best.unwrap();
+        Ok((station, offset))
+    }
+
+    pub fn substring(&self, start: T, end: T) -> Result<Self, GeometryError> {
+        let length = self.length();
+        if !is_in_range(start, length) || !is_in_range(end, length) || end < start {
+            return Err(GeometryError::ParameterOutOfRange);
+        }
+        let mut segments = Vec::new();
+        let mut segment_start = T::zero();
+        for segment in &self.segments {
+            let segment_end = segment_start + segment.length();
+            let low = if start > segment_start {
+                start
+            } else {
+                segment_start
+            };
+            let high = if end < segment_end { end } else { segment_end };
+            if low < high && !low.equals(high) {
+                segments.push(sub_segment(
+                    segment,
+                    low - segment_start,
+                    high - segment_start,
+                ));
+            }
+            segment_start = segment_end;
+        }
+        Ok(Self { segments })
+    }
+
+    pub fn reverse(&self) -> Self {
+        let segments = self
+            .segments
+            .iter()
+            .rev()
+            .map(|segment| segment.reverse())
+            .collect();
+        Self { segments }
+    }
+
+    /// Splits every piece evenly so that no piece is longer than `max_distance`.
+    pub fn densify(&self, max_distance: T) -> Self {
+        if max_distance < T::zero() || max_distance.equals(T::zero()) {
+            return self.clone();
+        }
+        let mut segments = Vec::new();
+        for segment in &self.segments {
+            let length = segment.length();
+            let mut pieces = 1;
+            while max_distance * T::from_f64(pieces as f64) < length {
+                pieces += 1;
+            }
+            let step = length / T::from_f64(pieces as f64);
+            for piece in 0..pieces {
+                let from = step * T::from_f64(piece as f64);
+                let to = if piece + 1 == pieces {
+                    length
+                } else {
+                    step * T::from_f64((piece + 1) as f64)
+                };
+                segments.push(sub_segment(segment, from, to));
+            }
+        }
+        Self { segments }
+    }
+
+    /// Splits at the closest points on the polyline to `points`.
+    pub fn split_at_points(&self, points: &[Point2<T>]) -> Vec<Self> {
+        let length = self.length();
+        let mut stations = Vec::new();
+        for point in points {
+            if let Ok((station, _)) = self.locate_point(point) {
+                if !station.equals(T::zero()) && !station.equals(length) {
+                    stations.push(station);
+                }
+            }
+        }
+        stations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        stations.dedup_by(|a, b| a.equals(*b));
+        let mut polylines = Vec::new();
+        let mut start = T::zero();
+        for station in stations {
+            polylines.push(self.substring(start, station).unwrap());
+            start = station;
+        }
+        polylines.push(self.substring(start, length).unwrap());
+        polylines
+    }
+
+    /// Points where pieces meet other than the shared vertex of consecutive pieces.
+    pub fn self_intersections(&self) -> Vec<Point2<T>> {
+        let mut sweep = SweepSegment2Intersection::new();
+        for segment in &self.segments {
+            sweep.push_segment(segment);
+        }
+        let count = self.segments.len();
+        let is_closed = self.is_closed();
+        sweep
+            .intersection_with_segments()
+            .into_iter()
+            .filter(|intersection| {
+                let point = intersection.point();
+                let containing = intersection.segment_indices();
+                let is_shared_vertex = containing.len() == 2
+                    && ((containing[1] == containing[0] + 1
+                        && point == self.segments[containing[0]].target())
+                        || (is_closed
+                            && containing[0] == 0
+                            && containing[1] == count - 1
+                            && point == self.segments[0].source()));
+                !is_shared_vertex
+            })
+            .map(|intersection| intersection.point())
+            .collect()
+    }
+
+    pub fn is_simple(&self) -> bool {
+        self.self_intersections().is_empty()
+    }
+
+    /// Shoelace area including the circular segments of arcs, positive when counter-clockwise.
+    ///
+    /// Open polylines are closed by a chord from the last vertex to the first.
+    pub fn signed_area(&self) -> T {
+        let two = T::from_f64(2.0);
+        let mut area = T::zero();
+        for segment in &self.segments {
+            let source = segment.source();
+            let target = segment.target();
+            area = area + (source.x() * target.y() - target.x() * source.y()) / two;
+            if let LineArcSegment2::ArcSegment2(arc_segment) = segment {
+                let sweep = arc_segment.sweep_angle();
+                area = area
+                    + arc_segment.radius() * arc_segment.radius() * (sweep - sweep.sin()) / two;
+            }
+        }
+        if let (Some(first), Some(last)) = (self.segments.first(), self.segments.last()) {
+            let source = last.target();
+            let target = first.source();
+            area = area + (source.x() * target.y() - target.x() * source.y()) / two;
+        }
+        area
+    }
+
+    /// Flattens a closed boundary, where `side` refers to the enclosed region:
+    /// inner polygons stay inside it and outer ones contain it.
+    pub fn to_polygon(
+        &self,
+        tolerance: ChordTolerance<T>,
+        side: ApproximationSide,
+    ) -> Result<Polygon2<T>, GeometryError> {
+        if !self.is_closed() {
+            return Err(GeometryError::NotClosed);
+        }
+        let counter_clockwise = self.signed_area() > T::zero();
+        let mut vertices = Vec::new();
+        for segment in &self.segments {
+            match segment {
+                LineArcSegment2::LineSegment2(line_segment) => vertices.push(line_segment.source()),
+                LineArcSegment2::ArcSegment2(arc_segment) => {
+                    let region_at_center = (arc_segment.orientation()
+                        == Orientation::CounterClockwise)
+                        == counter_clockwise;
+                    let arc_side = match (side, region_at_center) {
+                        (ApproximationSide::Inner, true) | (ApproximationSide::Outer, false) => {
+                            ApproximationSide::Inner
+                        }
+                        _ => ApproximationSide::Outer,
+                    };
+                    let mut points = arc_segment.tessellate(tolerance, arc_side)?;
+                    points.pop();
+                    vertices.extend(points);
+                }
+            }
+        }
+        Ok(Polygon2::new(vertices))
+    }
+
+    /// Corners are the interior vertices of open polylines and every vertex of closed ones, starting at the first.
+    pub fn corner_count(&self) -> usize {
+        if self.is_closed() {
+            self.segments.len()
+        } else {
+            self.segments.len().saturating_sub(1)
+        }
+    }
+
+    /// Fillets every corner between two line segments with `radius`, corners next to arcs are kept.
+    pub fn fillet(&self, radius: T, limit: CornerLimit) -> Result<Self, GeometryError> {
+        self.apply_corners(&self.uniform_corners(Corner2::Fillet(radius)), limit)
+    }
+
+    /// Chamfers every corner between two line segments at `distance`, corners next to arcs are kept.
+    pub fn chamfer(&self, distance: T, limit: CornerLimit) -> Result<Self, GeometryError> {
+        self.apply_corners(&self.uniform_corners(Corner2::Chamfer(distance)), limit)
+    }
+
+    /// Replaces each corner by a tangent arc or a chamfer line, see [`Polyline2::corner_count`] for the order.
+    ///
+    /// Treatments that do not fit on the adjacent segments are rejected or shrunk to fit depending on `limit`.
+    pub fn apply_corners(
+        &self,
+        corners: &[Corner2<T>],
+        limit: CornerLimit,
+    ) -> Result<Self, GeometryError> {
+        if corners.len() != self.corner_count() {
+            return Err(GeometryError::CornerCountMismatch);
+        }
+        let mut cuts = vec![T::zero(); corners.len()];
+        let mut directions = vec![None; corners.len()];
+        for (index, corner) in corners.iter().enumerate() {
+            let value = match corner {
+                Corner2::Sharp => continue,
+                Corner2::Fillet(value) | Corner2::Chamfer(value) => *value,
+            };
+            if value < T::zero() && !value.equals(T::zero()) {
+                return Err(GeometryError::InvalidLength);
+            }
+            let (incoming, outgoing) = self.corner_segments(index);
+            let (backward, forward) = match (incoming, outgoing) {
+                (
+                    LineArcSegment2::LineSegment2(incoming),
+                    LineArcSegment2::LineSegment2(outgoing),
+                ) => (
+                    (incoming.source() - incoming.target()).normalize(),
+                    (outgoing.target() - outgoing.source()).normalize(),
+                ),
+                _ => return Err(GeometryError::UnsupportedCorner),
+            };
+            cuts[index] = match corner {
+                Corner2::Fillet(radius) => {
+                    let sine = backward.cross(&forward).abs();
+                    let cosine = backward.dot(&forward);
+                    if !sine.equals(T::zero()) {
+                        *radius * (T::from_f64(1.0) + cosine) / sine
+                    } else if cosine < T::zero() {
+                        T::zero()
+                    } else {
+                        return Err(GeometryError::CornerTooLarge);
+                    }
+                }
+                _ => value,
+            };
+            directions[index] = Some((backward, forward));
+        }
+
+        let mut scales = vec![T::from_f64(1.0); corners.len()];
+        for index in 0..self.segments.len() {
+            let (source_corner, target_corner) = self.segment_corners(index);
+            let cut_of = |corner: Option<usize>| corner.map_or(T::zero(), |corner| cuts[corner]);
+            let used = cut_of(source_corner) + cut_of(target_corner);
+            let length = self.segments[index].length();
+            if used < length || used.equals(length) {
+                continue;
+            }
+            if limit == CornerLimit::Reject {
+                return Err(GeometryError::CornerTooLarge);
+            }
+            let scale = length / used;
+            for corner in [source_corner, target_corner].into_iter().flatten() {
+                if scale < scales[corner] {
+                    scales[corner] = scale;
+                }
+            }
+        }
+        for (cut, scale) in cuts.iter_mut().zip(scales) {
+            *cut = *cut * scale;
+        }
+
+        let mut segments = Vec::new();
+        for (index, segment) in self.segments.iter().enumerate() {
+            let (source_corner, target_corner) = self.segment_corners(index);
+            let source_cut = source_corner.map_or(T::zero(), |corner| cuts[corner]);
+            let target_cut = target_corner.map_or(T::zero(), |corner| cuts[corner]);
+            if source_cut.equals(T::zero()) && target_cut.equals(T::zero()) {
+                segments.push(*segment);
+            } else {
+                let direction = (segment.target() - segment.source()).normalize();
+                let trimmed = LineSegment2::new(
+                    offset_point(&segment.source(), &direction, source_cut),
+                    offset_point(&segment.target(), &direction, -target_cut),
+                );
+                if !trimmed.length().equals(T::zero()) {
+                    segments.push(trimmed.into());
+                }
+            }
+            let Some(corner) = target_corner else {
+                continue;
+            };
+            let Some((backward, forward)) = directions[corner] else {
+                continue;
+            };
+            if cuts[corner].equals(T::zero()) {
+                continue;
+            }
+            let vertex = segment.target();
+            let source = offset_point(&vertex, &backward, cuts[corner]);
+            let target = offset_point(&vertex, &forward, cuts[corner]);
+            match corners[corner] {
+                Corner2::Fillet(_) => segments.push(
+                    ArcSegment2::from_source_tangent_target(
+                        &source,
+                        &(backward * T::from_f64(-1.0)),
+                        &target,
+                    )?
+                    .into(),
+                ),
+                _ => segments.push(LineSegment2::new(source, target).into()),
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    fn uniform_corners(&self, corner: Corner2<T>) -> Vec<Corner2<T>> {
+        (0..self.corner_count())
+            .map(|index| {
+                let (incoming, outgoing) = self.corner_segments(index);
+                match (incoming, outgoing) {
+                    (LineArcSegment2::LineSegment2(_), LineArcSegment2::LineSegment2(_)) => corner,
+                    _ => Corner2::Sharp,
+                }
+            })
+            .collect()
+    }
+
+    /// Segments before and after the corner.
+    fn corner_segments(&self, corner: usize) -> (LineArcSegment2<T>, LineArcSegment2<T>) {
+        let count = self.segments.len();
+        if self.is_closed() {
+            (
+                self.segments[(corner + count - 1) % count],
+                self.segments[corner],
+            )
+        } else {
+            (self.segments[corner], self.segments[corner + 1])
+        }
+    }
+
+    /// Corners at the source and the target of the segment.
+    fn segment_corners(&self, index: usize) -> (Option<usize>, Option<usize>) {
+        let count = self.segments.len();
+        if self.is_closed() {
+            (Some(index), Some((index + 1) % count))
+        } else {
+            (
+                index.checked_sub(1),
+                if index + 1 < count { Some(index) } else { None },
+            )
+        }
+    }
+}
+
+fn is_in_range<T: NumberType>(value: T, length: T) -> bool {
+    (value > T::zero() || value.equals(T::zero())) && (value < length || value.equals(length))
+}
+
+fn parameter_of<T: NumberType>(segment: &LineArcSegment2<T>, station: T) -> T {
+    let length = segment.length();
+    if length.equals(T::zero()) {
+        T::zero()
+    } else {
+        station / length
+    }
+}
+
+fn offset_point<T: NumberType>(
+    point: &Point2<T>,
+    direction: &Vector2<T>,
+    distance: T,
+) -> Point2<T> {
+    Point2::new(
+        point.x() + direction.x() * distance,
+        point.y() + direction.y() * distance,
+    )
+}
+
+/// Piece of the segment between the stations `from` and `to`.
+fn sub_segment<T: NumberType>(segment: &LineArcSegment2<T>, from: T, to: T) -> LineArcSegment2<T> {
+    let mut piece = *segment;
+    if !to.equals(segment.length()) {
+        piece = piece.split_at(parameter_of(&piece, to)).0;
+    }
+    if !from.equals(T::zero()) {
+        piece = piece.split_at(parameter_of(&piece, from)).1;
+    }
+    piece
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::kernel::circle_segment_2::CircleSegment2;
+
+    use super::*;
+
+    fn stadium() -> Polyline2<f64> {
+        Polyline2::from_segments(vec![
+            LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(10.0, 0.0)).into(),
+            ArcSegment2::new(
+                CircleSegment2::new(Point2::new(10.0, 5.0), 5.0),
+                1.5 * PI,
+                2.5 * PI,
+            )
+            .into(),
+            LineSegment2::new(Point2::new(10.0, 10.0), Point2::new(0.0, 10.0)).into(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_polyline_2_new() {
+        let polyline = Polyline2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(3.0, 4.0),
+            Point2::new(3.0, 0.0),
+        ]);
+        assert_eq!(polyline.segments().len(), 2);
+        assert_eq!(polyline.length(), 9.0);
+        assert!(!polyline.is_closed());
+
+        let result = Polyline2::from_segments(vec![
+            LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)).into(),
+            LineSegment2::new(Point2::new(2.0, 0.0), Point2::new(3.0, 0.0)).into(),
+        ]);
+        assert_eq!(result.unwrap_err(), GeometryError::NotConnected);
+
+        let polyline = stadium();
+        assert_eq!(
+            polyline.vertices(),
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(10.0, 0.0),
+                Point2::new(10.0, 10.0),
+                Point2::new(0.0, 10.0)
+            ]
+        );
+        assert!(polyline.length().equals(20.0 + 5.0 * PI));
+    }
+
+    #[test]
+    fn test_polyline_2_linear_referencing() {
+        let polyline = stadium();
+        assert_eq!(
+            polyline.point_at_distance(5.0).unwrap(),
+            Point2::new(5.0, 0.0)
+        );
+        assert_eq!(
+            polyline.point_at_distance(10.0 + 2.5 * PI).unwrap(),
+            Point2::new(15.0, 5.0)
+        );
+        assert_eq!(
+            polyline.point_at_distance(polyline.length()).unwrap(),
+            Point2::new(0.0, 10.0)
+        );
+        assert_eq!(
+            polyline.point_at_distance(-1.0).unwrap_err(),
+            GeometryError::ParameterOutOfRange
+        );
+
+        let (station, offset) = polyline.locate_point(&Point2::new(5.0, 2.0)).unwrap();
+        assert!(station.equals(5.0));
+        assert!(offset.equals(2.0));
+        let (station, offset) = polyline.locate_point(&Point2::new(16.0, 5.0)).unwrap();
+        assert!(station.equals(10.0 + 2.5 * PI));
+        assert!(offset.equals(-1.0));
+        let (station, offset) = polyline.locate_point(&Point2::new(4.0, 12.0)).unwrap();
+        assert!(station.equals(16.0 + 5.0 * PI));
+        assert!(offset.equals(-2.0));
+    }
+
+    #[test]
+    fn test_polyline_2_substring() {
+        let polyline = stadium();
+        let substring = polyline.substring(5.0, 15.0 + 5.0 * PI).unwrap();
+        assert_eq!(substring.segments().len(), 3);
+        assert_eq!(
+            substring.vertices(),
+            vec![
+                Point2::new(5.0, 0.0),
+                Point2::new(10.0, 0.0),
+                Point2::new(10.0, 10.0),
+                Point2::new(5.0, 10.0)
+            ]
+        );
+        assert!(substring.length().equals(10.0 + 5.0 * PI));
+
+        let substring = polyline
+            .substring(10.0 + 2.5 * PI, 10.0 + 5.0 * PI)
+            .unwrap();
+        assert_eq!(substring.segments().len(), 1);
+        assert_eq!(substring.vertices()[0], Point2::new(15.0, 5.0));
+        assert_eq!(substring.vertices()[1], Point2::new(10.0, 10.0));
+
+        assert_eq!(
+            polyline.substring(5.0, 100.0).unwrap_err(),
+            GeometryError::ParameterOutOfRange
+        );
+
+        let parts = polyline.split_at_points(&[Point2::new(5.0, 10.0), Point2::new(5.0, 0.0)]);
+        assert_eq!(parts.len(), 3);
+        assert!(parts[0].length().equals(5.0));
+        assert!(parts[1].length().equals(10.0 + 5.0 * PI));
+        assert!(parts[2].length().equals(5.0));
+    }
+
+    #[test]
+    fn test_polyline_2_reverse_densify() {
+        let polyline = Polyline2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 3.0),
+        ]);
+        assert_eq!(
+            polyline.reverse().vertices(),
+            vec![
+                Point2::new(10.0, 3.0),
+                Point2::new(10.0, 0.0),
+                Point2::new(0.0, 0.0)
+            ]
+        );
+        let reversed = stadium().reverse();
+        let mut vertices = stadium().vertices();
+        vertices.reverse();
+        assert_eq!(reversed.vertices(), vertices);
+        assert!(reversed.length().equals(stadium().length()));
+        assert_eq!(
+            reversed.point_at_distance(10.0 + 2.5 * PI).unwrap(),
+            Point2::new(15.0, 5.0)
+        );
+
+        let densified = polyline.densify(3.0);
+        assert_eq!(
+            densified.vertices(),
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(2.5, 0.0),
+                Point2::new(5.0, 0.0),
+                Point2::new(7.5, 0.0),
+                Point2::new(10.0, 0.0),
+                Point2::new(10.0, 3.0)
+            ]
+        );
+
+        let densified = stadium().densify(5.0);
+        assert_eq!(densified.segments().len(), 2 + 4 + 2);
+        assert!(densified.length().equals(20.0 + 5.0 * PI));
+        assert_eq!(densified.vertices()[4], Point2::new(15.0, 5.0));
+    }
+
+    #[test]
+    fn test_polyline_2_self_intersections() {
+        assert!(stadium().is_simple());
+
+        let polyline = Polyline2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 10.0),
+            Point2::new(0.0, 10.0),
+            Point2::new(0.0, 0.0),
+        ]);
+        assert!(polyline.is_closed());
+        assert!(polyline.is_simple());
+
+        let polyline = Polyline2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 10.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(0.0, 10.0),
+        ]);
+        assert_eq!(polyline.self_intersections(), vec![Point2::new(5.0, 5.0)]);
+
+        let polyline = Polyline2::from_segments(vec![
+            LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(10.0, 0.0)).into(),
+            ArcSegment2::new(
+                CircleSegment2::new(Point2::new(10.0, 5.0), 5.0),
+                1.5 * PI,
+                2.5 * PI,
+            )
+            .into(),
+            LineSegment2::new(Point2::new(10.0, 10.0), Point2::new(12.0, -2.0)).into(),
+        ])
+        .unwrap();
+        let result = polyline.self_intersections();
+        assert_eq!(result.len(), 1);
+        assert!(result[0].distance(&Point2::new(10.0, 5.0)).equals(5.0));
+    }
+
+    #[test]
+    fn test_polyline_2_corners() {
+        let polyline = Polyline2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 10.0),
+        ]);
+        let filleted = polyline.fillet(3.0, CornerLimit::Reject).unwrap();
+        assert_eq!(
+            filleted.vertices(),
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(7.0, 0.0),
+                Point2::new(10.0, 3.0),
+                Point2::new(10.0, 10.0)
+            ]
+        );
+        let segments = filleted.segments();
+        assert_eq!(segments[1].center(), Point2::new(7.0, 3.0));
+        assert!(segments[1].radius().equals(3.0));
+        assert_eq!(segments[1].orientation(), Orientation::CounterClockwise);
+        assert!(filleted.length().equals(14.0 + 1.5 * PI));
+
+        let right_turn = Polyline2::new(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, -10.0),
+        ]);
+        let filleted = right_turn.fillet(3.0, CornerLimit::Reject).unwrap();
+        assert_eq!(filleted.segments()[1].center(), Point2::new(7.0, -3.0));
+        assert_eq!(filleted.segments()[1].orientation(), Orientation::Clockwise);
+
+        let chamfered = polyline.chamfer(2.0, CornerLimit::Reject).unwrap();
+        assert_eq!(
+            chamfered.vertices(),
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(8.0, 0.0),
+                Point2::new(10.0, 2.0),
+                Point2::new(10.0, 10.0)
+            ]
+        );
+
+        assert_eq!(
+            polyline.fillet(12.0, CornerLimit::Reject).err(),
+            Some(GeometryError::CornerTooLarge)
+        );
+        let clamped = polyline.fillet(12.0, CornerLimit::Clamp).unwrap();
+        assert_eq!(clamped.segments().len(), 1);
+        assert!(clamped.segments()[0].radius().equals(10.0));
+        assert_eq!(
+            polyline
+                .apply_corners(&[Corner2::Sharp, Corner2::Sharp], CornerLimit::Reject)
+                .err(),
+            Some(GeometryError::CornerCountMismatch)
+        );
+        assert_eq!(
+            polyline
+                .apply_corners(&[Corner2::Chamfer(-1.0)], CornerLimit::Reject)
+                .err(),
+            Some(GeometryError::InvalidLength)
+        );
+
+        let stadium = stadium();
+        assert_eq!(
+            stadium
+                .apply_corners(&[Corner2::Fillet(1.0), Corner2::Sharp], CornerLimit::Reject)
+                .err(),
+            Some(GeometryError::UnsupportedCorner)
+        );
+        assert_eq!(
+            stadium.fillet(1.0, CornerLimit::Reject).unwrap().vertices(),
+            stadium.vertices()
+        );
+    }
+
+    #[test]
+    fn test_polyline_2_to_polygon() {
+        let mut segments = stadium().segments();
+        segments.push(
+            ArcSegment2::new(
+                CircleSegment2::new(Point2::new(0.0, 5.0), 5.0),
+                0.5 * PI,
+                1.5 * PI,
+            )
+            .into(),
+        );
+        let boundary = Polyline2::from_segments(segments).unwrap();
+        let area = 100.0 + 25.0 * PI;
+        assert!(boundary.signed_area().equals(area));
+        assert!(boundary.reverse().signed_area().equals(-area));
+
+        let tolerance = ChordTolerance::MaxDeviation(0.01);
+        let inner = boundary
+            .to_polygon(tolerance, ApproximationSide::Inner)
+            .unwrap();
+        let outer = boundary
+            .to_polygon(tolerance, ApproximationSide::Outer)
+            .unwrap();
+        assert!(inner.area() < area && inner.area() > area - 0.5);
+        assert!(outer.area() > area && outer.area() < area + 0.5);
+        let reversed = boundary
+            .reverse()
+            .to_polygon(tolerance, ApproximationSide::Inner)
+            .unwrap();
+        assert!(reversed.area().abs().equals(inner.area()));
+
+        let notch = Polyline2::from_segments(vec![
+            LineSegment2::new(Point2::new(0.0, 0.0), Point2::new(10.0, 0.0)).into(),
+            LineSegment2::new(Point2::new(10.0, 0.0), Point2::new(10.0, 10.0)).into(),
+            ArcSegment2::with_orientation(
+                CircleSegment2::new(Point2::new(5.0, 10.0), 5.0),
+                0.0,
+                PI,
+                Orientation::Clockwise,
+            )
+            .unwrap()
+            .into(),
+            LineSegment2::new(Point2::new(0.0, 10.0), Point2::new(0.0, 0.0)).into(),
+        ])
+        .unwrap();
+        let area = 100.0 - 12.5 * PI;
+        assert!(notch.signed_area().equals(area));
+        let inner = notch
+            .to_polygon(tolerance, ApproximationSide::Inner)
+            .unwrap();
+        assert!(inner.area() < area && inner.area() > area - 0.5);
+
+        assert_eq!(
+            stadium()
+                .to_polygon(tolerance, ApproximationSide::Inner)
+                .err(),
+            Some(GeometryError::NotClosed)
+        );
+    }
+}
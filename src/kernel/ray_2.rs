@@ -1,5 +1,6 @@
 use super::{number_type::NumberType, point_2::Point2, vector_2::Vector2};
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Ray2<T: NumberType> {
     origin: Point2<T>,
     direction: Vector2<T>,
@@ -14,10 +15,10 @@ impl<T: NumberType> Ray2<T> {
     }
 
     pub fn origin(&self) -> Point2<T> {
-        self.origin.clone()
+        self.origin
     }
 
     pub fn direction(&self) -> Vector2<T> {
-        self.direction.clone()
+        self.direction
     }
 }
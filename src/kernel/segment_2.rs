@@ -1,11 +1,15 @@
 use core::panic;
 use std::fmt::Debug;
 
-use super::{number_type::NumberType, point_2::Point2, util_enum::Segment2Type};
+use super::{
+    number_type::NumberType,
+    point_2::Point2,
+    util_enum::{Orientation, Segment2Type},
+};
 
 /** Segment2 trait
  *
- * - LineSegment2 has implemented this trait except for the **source_radian**, **target_radian**, **center** and **radius**, methods.
+ * - LineSegment2 has implemented this trait except for the **source_radian**, **target_radian**, **sweep_radian**, **orientation**, **center** and **radius**, methods.
  *
  * - CircleSegment2 has implemented this trait except for the **source**, **source_radian**, **target** and **target_radian** methods.
  *
@@ -33,6 +37,14 @@ pub trait Segment2<T: NumberType>: Debug + Clone + Copy {
         panic!("Not implemented");
     }
 
+    fn sweep_radian(&self) -> T {
+        panic!("Not implemented");
+    }
+
+    fn orientation(&self) -> Orientation {
+        panic!("Not implemented");
+    }
+
     fn center(&self) -> Point2<T> {
         panic!("Not implemented");
     }
@@ -0,0 +1,72 @@
+use super::{
+    number_type::NumberType,
+    point_2::Point2,
+    util_enum::{ApproximationSide, GeometryError},
+};
+
+/// Limit on the chords that replace circular geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChordTolerance<T: NumberType> {
+    /// Maximum distance between a chord and the curve.
+    MaxDeviation(T),
+    /// Maximum turning of the curve along one chord.
+    MaxRadian(T),
+}
+
+/// Number of equal pieces, at least `min_pieces`, for `sweep_radian` on a circle of `radius`.
+///
+/// Outer pieces turn at most pi / 2 each, whatever the tolerance allows.
+pub(crate) fn circular_pieces<T: NumberType>(
+    radius: T,
+    sweep_radian: T,
+    tolerance: ChordTolerance<T>,
+    side: ApproximationSide,
+    min_pieces: usize,
+) -> Result<usize, GeometryError> {
+    let one = T::from_f64(1.0);
+    let max_step = match tolerance {
+        ChordTolerance::MaxDeviation(deviation) => {
+            if deviation < T::zero() || deviation.equals(T::zero()) {
+                return Err(GeometryError::InvalidTolerance);
+            }
+            let ratio = match side {
+                ApproximationSide::Inner => one - deviation / radius,
+                ApproximationSide::Outer => radius / (radius + deviation),
+            };
+            if ratio < -one {
+                T::pi() * T::from_f64(2.0)
+            } else {
+                T::from_f64(2.0) * ratio.acos()
+            }
+        }
+        ChordTolerance::MaxRadian(radian) => {
+            if radian < T::zero() || radian.equals(T::zero()) {
+                return Err(GeometryError::InvalidTolerance);
+            }
+            // Outer vertices sit at radius / cos(step / 2), which runs away as the step nears pi.
+            let max_outer_step = T::pi() / T::from_f64(2.0);
+            match side {
+                ApproximationSide::Outer if radian > max_outer_step => max_outer_step,
+                _ => radian,
+            }
+        }
+    };
+    let mut pieces = min_pieces.max(1);
+    while max_step * T::from_f64(pieces as f64) < sweep_radian
+        && !(max_step * T::from_f64(pieces as f64)).equals(sweep_radian)
+    {
+        pieces += 1;
+    }
+    Ok(pieces)
+}
+
+pub(crate) fn point_on_circle<T: NumberType>(
+    center: &Point2<T>,
+    radius: T,
+    radian: T,
+) -> Point2<T> {
+    Point2::new(
+        center.x() + radius * radian.cos(),
+        center.y() + radius * radian.sin(),
+    )
+}
@@ -1,5 +1,10 @@
 use super::{
-    line_segment_2::LineSegment2, number_type::NumberType, point_2::Point2, util_enum::Orientation,
+    circle_segment_2::CircleSegment2,
+    line_segment_2::LineSegment2,
+    number_type::NumberType,
+    point_2::Point2,
+    segment_2::Segment2,
+    util_enum::{GeometryError, Orientation},
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -76,6 +81,119 @@ impl<T: NumberType> Triangle2<T> {
             Orientation::CounterClockwise => area,
         }
     }
+
+    pub fn centroid(&self) -> Point2<T> {
+        let three = T::from_f64(3.0);
+        Point2::new(
+            (self.a.x() + self.b.x() + self.c.x()) / three,
+            (self.a.y() + self.b.y() + self.c.y()) / three,
+        )
+    }
+
+    pub fn circumcircle(&self) -> Result<CircleSegment2<T>, GeometryError> {
+        let cross = self.checked_cross()?;
+        let (bx, by) = (self.b.x() - self.a.x(), self.b.y() - self.a.y());
+        let (cx, cy) = (self.c.x() - self.a.x(), self.c.y() - self.a.y());
+        let b_squared = bx * bx + by * by;
+        let c_squared = cx * cx + cy * cy;
+        let denominator = T::from_f64(2.0) * cross;
+        let x = (cy * b_squared - by * c_squared) / denominator;
+        let y = (bx * c_squared - cx * b_squared) / denominator;
+        Ok(CircleSegment2::new(
+            Point2::new(self.a.x() + x, self.a.y() + y),
+            (x * x + y * y).sqrt(),
+        ))
+    }
+
+    pub fn incircle(&self) -> Result<CircleSegment2<T>, GeometryError> {
+        let cross = self.checked_cross()?;
+        let [ab, bc, ca] = self.edge_lengths();
+        let perimeter = ab + bc + ca;
+        Ok(CircleSegment2::new(
+            Point2::new(
+                (bc * self.a.x() + ca * self.b.x() + ab * self.c.x()) / perimeter,
+                (bc * self.a.y() + ca * self.b.y() + ab * self.c.y()) / perimeter,
+            ),
+            cross.abs() / perimeter,
+        ))
+    }
+
+    pub fn orthocenter(&self) -> Result<Point2<T>, GeometryError> {
+        let center = self.circumcircle()?.center();
+        let two = T::from_f64(2.0);
+        Ok(Point2::new(
+            self.a.x() + self.b.x() + self.c.x() - two * center.x(),
+            self.a.y() + self.b.y() + self.c.y() - two * center.y(),
+        ))
+    }
+
+    /// Weights of a, b and c summing to one, all non-negative for points inside the triangle.
+    pub fn barycentric(&self, point: &Point2<T>) -> Result<[T; 3], GeometryError> {
+        let area = self.checked_cross()?;
+        let weight_a = cross(point, &self.b, &self.c) / area;
+        let weight_b = cross(&self.a, point, &self.c) / area;
+        Ok([weight_a, weight_b, T::from_f64(1.0) - weight_a - weight_b])
+    }
+
+    /// Linear interpolation of the values given at a, b and c.
+    pub fn interpolate(&self, point: &Point2<T>, values: [T; 3]) -> Result<T, GeometryError> {
+        let [weight_a, weight_b, weight_c] = self.barycentric(point)?;
+        Ok(weight_a * values[0] + weight_b * values[1] + weight_c * values[2])
+    }
+
+    /// Smallest interior angle in radians.
+    pub fn min_angle(&self) -> Result<T, GeometryError> {
+        let cross = self.checked_cross()?.abs();
+        let angle = |apex: &Point2<T>, b: &Point2<T>, c: &Point2<T>| {
+            cross.atan2((*b - *apex).dot(&(*c - *apex)))
+        };
+        let angles = [
+            angle(&self.a, &self.b, &self.c),
+            angle(&self.b, &self.c, &self.a),
+            angle(&self.c, &self.a, &self.b),
+        ];
+        Ok(min(&angles))
+    }
+
+    /// Circumradius over twice the inradius, 1 for the equilateral triangle.
+    pub fn aspect_ratio(&self) -> Result<T, GeometryError> {
+        let circumradius = self.circumcircle()?.radius();
+        let inradius = self.incircle()?.radius();
+        Ok(circumradius / (T::from_f64(2.0) * inradius))
+    }
+
+    /// Circumradius over the shortest edge, 1/sqrt(3) for the equilateral triangle.
+    pub fn radius_edge_ratio(&self) -> Result<T, GeometryError> {
+        let circumradius = self.circumcircle()?.radius();
+        Ok(circumradius / min(&self.edge_lengths()))
+    }
+
+    fn checked_cross(&self) -> Result<T, GeometryError> {
+        let cross = cross(&self.a, &self.b, &self.c);
+        if cross.equals(T::zero()) {
+            return Err(GeometryError::CollinearPoints);
+        }
+        Ok(cross)
+    }
+
+    fn edge_lengths(&self) -> [T; 3] {
+        [
+            self.a.distance(&self.b),
+            self.b.distance(&self.c),
+            self.c.distance(&self.a),
+        ]
+    }
+}
+
+fn cross<T: NumberType>(a: &Point2<T>, b: &Point2<T>, c: &Point2<T>) -> T {
+    (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x())
+}
+
+fn min<T: NumberType>(values: &[T]) -> T {
+    values[1..].iter().fold(
+        values[0],
+        |min, value| if *value < min { *value } else { min },
+    )
 }
 
 #[cfg(test)]
@@ -103,6 +221,142 @@ mod tests {
         assert_eq!(triangle.vertices(), [a, b, c]);
     }
 
+    #[test]
+    fn test_triangle2_constructions() {
+        let triangle = Triangle2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 3.0),
+        );
+        assert_eq!(triangle.centroid(), Point2::new(4.0 / 3.0, 1.0));
+        assert_eq!(
+            triangle.circumcircle().unwrap(),
+            CircleSegment2::new(Point2::new(2.0, 1.5), 2.5)
+        );
+        assert_eq!(
+            triangle.incircle().unwrap(),
+            CircleSegment2::new(Point2::new(1.0, 1.0), 1.0)
+        );
+        assert_eq!(triangle.orthocenter().unwrap(), Point2::new(0.0, 0.0));
+
+        let weights = triangle.barycentric(&Point2::new(1.0, 1.0)).unwrap();
+        assert!(weights[0].equals(5.0 / 12.0));
+        assert!(weights[1].equals(0.25));
+        assert!(weights[2].equals(1.0 / 3.0));
+        let weights = triangle.barycentric(&Point2::new(4.0, 3.0)).unwrap();
+        assert!(weights[0].equals(-1.0));
+        assert!(triangle
+            .interpolate(&Point2::new(2.0, 0.75), [1.0, 5.0, 9.0])
+            .unwrap()
+            .equals(5.0));
+
+        assert!(triangle.min_angle().unwrap().equals(0.8_f64.acos()));
+        assert!(triangle.aspect_ratio().unwrap().equals(1.25));
+        assert!(triangle.radius_edge_ratio().unwrap().equals(2.5 / 3.0));
+
+        let equilateral = Triangle2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(1.0, 3.0_f64.sqrt()),
+        );
+        assert!(equilateral
+            .min_angle()
+            .unwrap()
+            .equals(std::f64::consts::FRAC_PI_3));
+        assert!(equilateral.aspect_ratio().unwrap().equals(1.0));
+        assert!(equilateral
+            .radius_edge_ratio()
+            .unwrap()
+            .equals(1.0 / 3.0_f64.sqrt()));
+
+        let degenerate = Triangle2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(2.0, 2.0),
+        );
+        assert_eq!(
+            degenerate.circumcircle().unwrap_err(),
+            GeometryError::CollinearPoints
+        );
+        assert_eq!(
+            degenerate.barycentric(&Point2::new(0.0, 0.0)).unwrap_err(),
+            GeometryError::CollinearPoints
+        );
+    }
+
+    #[test]
+    fn test_triangle2_constructions_degenerate() {
+        for degenerate in [
+            Triangle2::new(
+                Point2::new(1.0, 1.0),
+                Point2::new(1.0, 1.0),
+                Point2::new(2.0, 3.0),
+            ),
+            Triangle2::new(
+                Point2::new(1.0, 1.0),
+                Point2::new(1.0, 1.0),
+                Point2::new(1.0, 1.0),
+            ),
+        ] {
+            assert!(degenerate.area().equals(0.0));
+            let point = Point2::new(0.0, 0.0);
+            let collinear = Some(GeometryError::CollinearPoints);
+            assert_eq!(degenerate.circumcircle().err(), collinear);
+            assert_eq!(degenerate.incircle().err(), collinear);
+            assert_eq!(degenerate.orthocenter().err(), collinear);
+            assert_eq!(degenerate.barycentric(&point).err(), collinear);
+            assert_eq!(
+                degenerate.interpolate(&point, [1.0, 2.0, 3.0]).err(),
+                collinear
+            );
+            assert_eq!(degenerate.min_angle().err(), collinear);
+            assert_eq!(degenerate.aspect_ratio().err(), collinear);
+            assert_eq!(degenerate.radius_edge_ratio().err(), collinear);
+        }
+
+        // Obtuse triangles have their circumcenter and orthocenter outside.
+        let obtuse = Triangle2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(1.0, 1.0),
+        );
+        assert_eq!(
+            obtuse.circumcircle().unwrap(),
+            CircleSegment2::new(Point2::new(2.0, -1.0), 5.0_f64.sqrt())
+        );
+        assert_eq!(obtuse.orthocenter().unwrap(), Point2::new(1.0, 3.0));
+
+        // Clockwise vertices give the same circles and weights.
+        let clockwise = Triangle2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(0.0, 3.0),
+            Point2::new(4.0, 0.0),
+        );
+        assert!(clockwise.area().equals(-6.0));
+        assert_eq!(
+            clockwise.incircle().unwrap(),
+            CircleSegment2::new(Point2::new(1.0, 1.0), 1.0)
+        );
+        let weights = clockwise.barycentric(&Point2::new(1.0, 1.0)).unwrap();
+        assert!(weights[0].equals(5.0 / 12.0));
+        assert!(weights[1].equals(1.0 / 3.0));
+        assert!(weights[2].equals(0.25));
+        let weights = clockwise.barycentric(&Point2::new(0.0, 3.0)).unwrap();
+        assert!(weights[0].equals(0.0) && weights[1].equals(1.0) && weights[2].equals(0.0));
+        let weights = clockwise.barycentric(&Point2::new(2.0, 1.5)).unwrap();
+        assert!(weights[0].equals(0.0) && weights[1].equals(0.5) && weights[2].equals(0.5));
+
+        // Needles keep their tiny angle instead of rounding it to zero.
+        let needle = Triangle2::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1001.0, 1e-3),
+        );
+        let expected = (1e-3_f64 / 1000.0).atan() - (1e-3_f64 / 1001.0).atan();
+        assert!((needle.min_angle().unwrap() / expected - 1.0).abs() < 1e-6);
+        assert!(needle.aspect_ratio().unwrap() > 1e6);
+    }
+
     #[test]
     fn test_triangle2_edges() {
         let a = Point2::new(0.0, 0.0);
@@ -30,3 +30,78 @@ pub enum Segment2Type {
     CircleSegment2,
     ArcSegment2,
 }
+
+/// Inner chords stay inside the curve and outer chords stay outside, touching it.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApproximationSide {
+    Inner,
+    Outer,
+}
+
+/// Handling of fillets and chamfers that do not fit on the adjacent segments.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CornerLimit {
+    Reject,
+    Clamp,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryError {
+    InvalidDegree,
+    InvalidKnotVector,
+    InvalidWeights,
+    InvalidLength,
+    ParameterOutOfRange,
+    NoSolution,
+    NotConnected,
+    InvalidSweep,
+    InvalidRadius,
+    CollinearPoints,
+    CoincidentPoints,
+    CornerCountMismatch,
+    CornerTooLarge,
+    UnsupportedCorner,
+    InvalidTolerance,
+    NotClosed,
+    TooFewPoints,
+}
+
+impl std::fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            GeometryError::InvalidDegree => {
+                "degree must be at least 1 and below the number of control points"
+            }
+            GeometryError::InvalidKnotVector => {
+                "knot vector must be non-decreasing with degree + control points + 1 entries"
+            }
+            GeometryError::InvalidWeights => {
+                "weights must be positive with one weight per control point"
+            }
+            GeometryError::InvalidLength => "length must be positive",
+            GeometryError::ParameterOutOfRange => "parameter is outside the domain",
+            GeometryError::NoSolution => "no solution exists for the given input",
+            GeometryError::NotConnected => "segments must be connected end to end",
+            GeometryError::InvalidSweep => "arc sweep must not exceed 2pi",
+            GeometryError::InvalidRadius => "points must lie on a circle of positive radius",
+            GeometryError::CollinearPoints => "points must not be collinear",
+            GeometryError::CoincidentPoints => "points must be distinct",
+            GeometryError::CornerCountMismatch => "one corner treatment per corner is required",
+            GeometryError::CornerTooLarge => {
+                "fillet or chamfer does not fit on the adjacent segments"
+            }
+            GeometryError::UnsupportedCorner => {
+                "only corners between two line segments can be filleted or chamfered"
+            }
+            GeometryError::InvalidTolerance => "tolerance must be positive",
+            GeometryError::NotClosed => "boundary must be closed",
+            GeometryError::TooFewPoints => "not enough points for the fit",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for GeometryError {}
@@ -1,4 +1,7 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Sub},
+};
 
 use super::number_type::NumberType;
 
@@ -54,6 +57,48 @@ impl<T: NumberType> Vector2<T> {
         }
         radian
     }
+
+    /// Orders by counterclockwise angle from the positive x axis in [0, 2pi), the zero vector first.
+    pub fn compare_radian(&self, other: &Self) -> Ordering {
+        self.compare_radian_from(other, &Self::new(T::from_f64(1.0), T::zero()))
+    }
+
+    /// Orders by counterclockwise angle from `start` in [0, 2pi), the zero vector first.
+    ///
+    /// Uses half planes and the sign of cross products instead of trigonometry, without tolerance.
+    pub fn compare_radian_from(&self, other: &Self, start: &Self) -> Ordering {
+        let half_a = self.half_plane(start);
+        let half_b = other.half_plane(start);
+        if half_a != half_b {
+            return half_a.cmp(&half_b);
+        }
+        cross_sign(other, self)
+    }
+
+    /// 0 for the zero vector, 1 from `start` up to its opposite, 2 from the opposite back to `start`.
+    fn half_plane(&self, start: &Self) -> u8 {
+        if self.x == T::zero() && self.y == T::zero() {
+            return 0;
+        }
+        match cross_sign(start, self) {
+            Ordering::Greater => 1,
+            Ordering::Less => 2,
+            Ordering::Equal => {
+                if start.x * self.x + start.y * self.y > T::zero() {
+                    1
+                } else {
+                    2
+                }
+            }
+        }
+    }
+}
+
+/// Sign of a.x * b.y - a.y * b.x by comparing the products, avoiding cancellation in the difference.
+fn cross_sign<T: NumberType>(a: &Vector2<T>, b: &Vector2<T>) -> Ordering {
+    (a.x * b.y)
+        .partial_cmp(&(a.y * b.x))
+        .unwrap_or(Ordering::Equal)
 }
 
 impl<T: NumberType> Add for Vector2<T> {
@@ -117,4 +162,54 @@ mod tests {
         assert_eq!(v_a.radian_to(&v_b), 0.0);
         assert_eq!(v_b.radian_to(&v_a), 0.0);
     }
+
+    #[test]
+    fn test_compare_radian() {
+        let mut vectors = vec![
+            Vector2::new(1.0, -1e-300),
+            Vector2::new(0.0, -1.0),
+            Vector2::new(-1.0, 0.0),
+            Vector2::new(1.0, 1.0),
+            Vector2::new(1.0, 0.0),
+            Vector2::new(0.0, 0.0),
+            Vector2::new(-1.0, 1e-300),
+            Vector2::new(1.0, 1.0 + 1e-15),
+        ];
+        vectors.sort_by(|a, b| a.compare_radian(b));
+        assert_eq!(
+            vectors,
+            vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(1.0, 0.0),
+                Vector2::new(1.0, 1.0),
+                Vector2::new(1.0, 1.0 + 1e-15),
+                Vector2::new(-1.0, 1e-300),
+                Vector2::new(-1.0, 0.0),
+                Vector2::new(0.0, -1.0),
+                Vector2::new(1.0, -1e-300),
+            ]
+        );
+        assert_eq!(
+            Vector2::new(2.0, 2.0).compare_radian(&Vector2::new(1.0, 1.0)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            Vector2::new(1.0, 1.0).compare_radian(&Vector2::new(1.0, 1.0 + 1e-15)),
+            Ordering::Less
+        );
+
+        let start = Vector2::new(0.0, 1.0);
+        assert_eq!(
+            Vector2::new(-1.0, 0.0).compare_radian_from(&Vector2::new(1.0, 0.0), &start),
+            Ordering::Less
+        );
+        assert_eq!(
+            Vector2::new(0.0, 1.0).compare_radian_from(&Vector2::new(-1.0, 0.0), &start),
+            Ordering::Less
+        );
+        assert_eq!(
+            Vector2::new(0.0, -1.0).compare_radian_from(&Vector2::new(1.0, 0.0), &start),
+            Ordering::Less
+        );
+    }
 }
@@ -34,8 +34,9 @@ impl<T: NumberType> Vertex2<T> {
         self.edges.push(edge);
     }
 
+    /// Removes this very edge, not every edge equal to it.
     pub fn remove_edge(&mut self, edge: Rc<RefCell<Edge2<T>>>) {
-        self.edges.retain(|e| e != &edge);
+        self.edges.retain(|e| !Rc::ptr_eq(e, &edge));
     }
 
     pub fn equals(&self, other: &Self) -> bool {
@@ -1,7 +1,13 @@
 use crate::{
-    algorithm::intersection::segment_2_segment_2::{
-        is_segment_2_segment_2_intersected as KernelIsSegment2Segment2Intersected,
-        segment_2_segment_2_intersection as KernelSegment2Segment2Intersection,
+    algorithm::intersection::{
+        intersection_enum::{
+            IntersectionContact,
+            Segment2Segment2Intersection as KernelSegment2Segment2IntersectionResult,
+        },
+        segment_2_segment_2::{
+            is_segment_2_segment_2_intersected as KernelIsSegment2Segment2Intersected,
+            segment_2_segment_2_intersection as KernelSegment2Segment2Intersection,
+        },
     },
     wasm::kernel::{
         point_2::Point2,
@@ -62,9 +68,32 @@ pub fn is_segment_2_segment_2_intersected(segment_a: &Segment2, segment_b: &Segm
 
 #[wasm_bindgen]
 pub fn segment_2_segment_2_intersection(segment_a: &Segment2, segment_b: &Segment2) -> Vec<Point2> {
+    kernel_intersection(segment_a, segment_b)
+        .points()
+        .into_iter()
+        .map(|p| Point2 { kernel_point_2: p })
+        .collect()
+}
+
+/// How the segments meet when they share a single isolated point, otherwise undefined.
+#[wasm_bindgen]
+pub fn segment_2_segment_2_intersection_contact(
+    segment_a: &Segment2,
+    segment_b: &Segment2,
+) -> Option<IntersectionContact> {
+    match kernel_intersection(segment_a, segment_b) {
+        KernelSegment2Segment2IntersectionResult::Point(_, contact) => Some(contact),
+        _ => None,
+    }
+}
+
+fn kernel_intersection(
+    segment_a: &Segment2,
+    segment_b: &Segment2,
+) -> KernelSegment2Segment2IntersectionResult<f64> {
     let segment_a = get_segment_value(segment_a);
     let segment_b = get_segment_value(segment_b);
-    let result = match segment_a {
+    match segment_a {
         Segment2Value::LineSegment2(segment_a) => match segment_b {
             Segment2Value::LineSegment2(segment2_b) => KernelSegment2Segment2Intersection(
                 &segment_a.kernel_line_segment_2,
@@ -107,9 +136,5 @@ pub fn segment_2_segment_2_intersection(segment_a: &Segment2, segment_b: &Segmen
                 &segment2_b.kernel_arc_segment_2,
             ),
         },
-    };
-    result
-        .into_iter()
-        .map(|p| Point2 { kernel_point_2: p })
-        .collect()
+    }
 }
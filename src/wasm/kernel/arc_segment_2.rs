@@ -1,7 +1,8 @@
 use crate::{
     kernel::{
-        arc_segment_2::ArcSegment2 as KernelArcSegment2, segment_2::Segment2,
-        util_enum::Segment2Type,
+        arc_segment_2::ArcSegment2 as KernelArcSegment2,
+        segment_2::Segment2,
+        util_enum::{Orientation, Segment2Type},
     },
     wasm::kernel::{circle_segment_2::CircleSegment2, point_2::Point2},
 };
@@ -16,15 +17,42 @@ pub struct ArcSegment2 {
 
 #[wasm_bindgen]
 impl ArcSegment2 {
+    /// Throws when the counter-clockwise span exceeds 2pi.
     #[wasm_bindgen(constructor)]
-    pub fn new(support: CircleSegment2, source_radian: f64, target_radian: f64) -> Self {
-        Self {
-            kernel_arc_segment_2: KernelArcSegment2::new(
-                support.kernel_circle_segment_2,
-                source_radian,
-                target_radian,
-            ),
-        }
+    pub fn new(
+        support: CircleSegment2,
+        source_radian: f64,
+        target_radian: f64,
+    ) -> Result<ArcSegment2, JsError> {
+        KernelArcSegment2::with_orientation(
+            support.kernel_circle_segment_2,
+            source_radian,
+            target_radian,
+            Orientation::CounterClockwise,
+        )
+        .map(|arc_segment| Self {
+            kernel_arc_segment_2: arc_segment,
+        })
+        .map_err(|error| JsError::new(&error.to_string()))
+    }
+
+    /// None when the span along `orientation` exceeds 2pi.
+    pub fn with_orientation(
+        support: CircleSegment2,
+        source_radian: f64,
+        target_radian: f64,
+        orientation: Orientation,
+    ) -> Option<ArcSegment2> {
+        KernelArcSegment2::with_orientation(
+            support.kernel_circle_segment_2,
+            source_radian,
+            target_radian,
+            orientation,
+        )
+        .ok()
+        .map(|arc_segment| Self {
+            kernel_arc_segment_2: arc_segment,
+        })
     }
 
     pub fn segment_type(&self) -> Segment2Type {
@@ -51,6 +79,14 @@ impl ArcSegment2 {
         self.kernel_arc_segment_2.target_radian()
     }
 
+    pub fn sweep_radian(&self) -> f64 {
+        self.kernel_arc_segment_2.sweep_radian()
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.kernel_arc_segment_2.orientation()
+    }
+
     pub fn center(&self) -> Point2 {
         Point2 {
             kernel_point_2: self.kernel_arc_segment_2.center(),
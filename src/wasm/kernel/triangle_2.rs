@@ -1,6 +1,8 @@
 use crate::{
     kernel::{triangle_2::Triangle2 as KernelTriangle2, util_enum::Orientation},
-    wasm::kernel::{line_segment_2::LineSegment2, point_2::Point2},
+    wasm::kernel::{
+        circle_segment_2::CircleSegment2, line_segment_2::LineSegment2, point_2::Point2,
+    },
 };
 use wasm_bindgen::prelude::*;
 
@@ -72,4 +74,56 @@ impl Triangle2 {
     pub fn area(&self) -> f64 {
         self.kernel_triangle_2.area()
     }
+
+    pub fn centroid(&self) -> Point2 {
+        Point2 {
+            kernel_point_2: self.kernel_triangle_2.centroid(),
+        }
+    }
+
+    pub fn circumcircle(&self) -> Option<CircleSegment2> {
+        self.kernel_triangle_2
+            .circumcircle()
+            .ok()
+            .map(|circle_segment| CircleSegment2 {
+                kernel_circle_segment_2: circle_segment,
+            })
+    }
+
+    pub fn incircle(&self) -> Option<CircleSegment2> {
+        self.kernel_triangle_2
+            .incircle()
+            .ok()
+            .map(|circle_segment| CircleSegment2 {
+                kernel_circle_segment_2: circle_segment,
+            })
+    }
+
+    pub fn orthocenter(&self) -> Option<Point2> {
+        self.kernel_triangle_2
+            .orthocenter()
+            .ok()
+            .map(|point| Point2 {
+                kernel_point_2: point,
+            })
+    }
+
+    pub fn barycentric(&self, point: &Point2) -> Option<Vec<f64>> {
+        self.kernel_triangle_2
+            .barycentric(&point.kernel_point_2)
+            .ok()
+            .map(|weights| weights.to_vec())
+    }
+
+    pub fn min_angle(&self) -> Option<f64> {
+        self.kernel_triangle_2.min_angle().ok()
+    }
+
+    pub fn aspect_ratio(&self) -> Option<f64> {
+        self.kernel_triangle_2.aspect_ratio().ok()
+    }
+
+    pub fn radius_edge_ratio(&self) -> Option<f64> {
+        self.kernel_triangle_2.radius_edge_ratio().ok()
+    }
 }